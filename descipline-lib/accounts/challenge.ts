@@ -19,6 +19,10 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
@@ -49,10 +53,26 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
-  getTokenAllowedDecoder,
-  getTokenAllowedEncoder,
-  type TokenAllowed,
-  type TokenAllowedArgs,
+  getChallengeStatusDecoder,
+  getChallengeStatusEncoder,
+  getPayoutModeDecoder,
+  getPayoutModeEncoder,
+  getResolutionFieldsDecoder,
+  getResolutionFieldsEncoder,
+  getSweepModeDecoder,
+  getSweepModeEncoder,
+  getZeroWinnerPolicyDecoder,
+  getZeroWinnerPolicyEncoder,
+  type ChallengeStatus,
+  type ChallengeStatusArgs,
+  type PayoutMode,
+  type PayoutModeArgs,
+  type ResolutionFields,
+  type ResolutionFieldsArgs,
+  type SweepMode,
+  type SweepModeArgs,
+  type ZeroWinnerPolicy,
+  type ZeroWinnerPolicyArgs,
 } from '../types';
 
 export const CHALLENGE_DISCRIMINATOR = new Uint8Array([
@@ -67,26 +87,58 @@ export type Challenge = {
   discriminator: ReadonlyUint8Array;
   name: string;
   initiator: Address;
-  tokenAllowed: TokenAllowed;
+  id: bigint;
+  stakeMint: Address;
   stakeAmount: bigint;
+  allowTopUp: boolean;
+  payoutMode: PayoutMode;
   fee: number;
   stakeEndAt: bigint;
   claimStartFrom: bigint;
+  claimEndAt: bigint;
+  sweepMode: SweepMode;
+  zeroWinnerPolicy: ZeroWinnerPolicy;
+  resolveDeadline: bigint;
+  disputeWindow: bigint;
+  disputeBond: bigint;
+  maxRelayerTip: bigint;
+  participantCount: number;
   schema: Address;
-  attestor: Address;
+  credential: Address;
+  resolutionFields: ResolutionFields;
+  attestors: Array<Address>;
+  threshold: number;
+  status: ChallengeStatus;
+  vaultBump: number;
   bump: number;
 };
 
 export type ChallengeArgs = {
   name: string;
   initiator: Address;
-  tokenAllowed: TokenAllowedArgs;
+  id: number | bigint;
+  stakeMint: Address;
   stakeAmount: number | bigint;
+  allowTopUp: boolean;
+  payoutMode: PayoutModeArgs;
   fee: number;
   stakeEndAt: number | bigint;
   claimStartFrom: number | bigint;
+  claimEndAt: number | bigint;
+  sweepMode: SweepModeArgs;
+  zeroWinnerPolicy: ZeroWinnerPolicyArgs;
+  resolveDeadline: number | bigint;
+  disputeWindow: number | bigint;
+  disputeBond: number | bigint;
+  maxRelayerTip: number | bigint;
+  participantCount: number;
   schema: Address;
-  attestor: Address;
+  credential: Address;
+  resolutionFields: ResolutionFieldsArgs;
+  attestors: Array<Address>;
+  threshold: number;
+  status: ChallengeStatusArgs;
+  vaultBump: number;
   bump: number;
 };
 
@@ -96,13 +148,29 @@ export function getChallengeEncoder(): Encoder<ChallengeArgs> {
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['initiator', getAddressEncoder()],
-      ['tokenAllowed', getTokenAllowedEncoder()],
+      ['id', getU64Encoder()],
+      ['stakeMint', getAddressEncoder()],
       ['stakeAmount', getU64Encoder()],
+      ['allowTopUp', getBooleanEncoder()],
+      ['payoutMode', getPayoutModeEncoder()],
       ['fee', getU16Encoder()],
       ['stakeEndAt', getI64Encoder()],
       ['claimStartFrom', getI64Encoder()],
+      ['claimEndAt', getI64Encoder()],
+      ['sweepMode', getSweepModeEncoder()],
+      ['zeroWinnerPolicy', getZeroWinnerPolicyEncoder()],
+      ['resolveDeadline', getI64Encoder()],
+      ['disputeWindow', getI64Encoder()],
+      ['disputeBond', getU64Encoder()],
+      ['maxRelayerTip', getU64Encoder()],
+      ['participantCount', getU32Encoder()],
       ['schema', getAddressEncoder()],
-      ['attestor', getAddressEncoder()],
+      ['credential', getAddressEncoder()],
+      ['resolutionFields', getResolutionFieldsEncoder()],
+      ['attestors', getArrayEncoder(getAddressEncoder())],
+      ['threshold', getU8Encoder()],
+      ['status', getChallengeStatusEncoder()],
+      ['vaultBump', getU8Encoder()],
       ['bump', getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: CHALLENGE_DISCRIMINATOR })
@@ -114,13 +182,29 @@ export function getChallengeDecoder(): Decoder<Challenge> {
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['initiator', getAddressDecoder()],
-    ['tokenAllowed', getTokenAllowedDecoder()],
+    ['id', getU64Decoder()],
+    ['stakeMint', getAddressDecoder()],
     ['stakeAmount', getU64Decoder()],
+    ['allowTopUp', getBooleanDecoder()],
+    ['payoutMode', getPayoutModeDecoder()],
     ['fee', getU16Decoder()],
     ['stakeEndAt', getI64Decoder()],
     ['claimStartFrom', getI64Decoder()],
+    ['claimEndAt', getI64Decoder()],
+    ['sweepMode', getSweepModeDecoder()],
+    ['zeroWinnerPolicy', getZeroWinnerPolicyDecoder()],
+    ['resolveDeadline', getI64Decoder()],
+    ['disputeWindow', getI64Decoder()],
+    ['disputeBond', getU64Decoder()],
+    ['maxRelayerTip', getU64Decoder()],
+    ['participantCount', getU32Decoder()],
     ['schema', getAddressDecoder()],
-    ['attestor', getAddressDecoder()],
+    ['credential', getAddressDecoder()],
+    ['resolutionFields', getResolutionFieldsDecoder()],
+    ['attestors', getArrayDecoder(getAddressDecoder())],
+    ['threshold', getU8Decoder()],
+    ['status', getChallengeStatusDecoder()],
+    ['vaultBump', getU8Decoder()],
     ['bump', getU8Decoder()],
   ]);
 }
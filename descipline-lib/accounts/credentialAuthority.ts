@@ -21,6 +21,10 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -50,16 +54,28 @@ export function getCredentialAuthorityDiscriminatorBytes() {
 export type CredentialAuthority = {
   discriminator: ReadonlyUint8Array;
   signer: Address;
+  treasury: Address;
+  protocolFee: number;
+  challengeCount: bigint;
   bump: number;
 };
 
-export type CredentialAuthorityArgs = { signer: Address; bump: number };
+export type CredentialAuthorityArgs = {
+  signer: Address;
+  treasury: Address;
+  protocolFee: number;
+  challengeCount: number | bigint;
+  bump: number;
+};
 
 export function getCredentialAuthorityEncoder(): FixedSizeEncoder<CredentialAuthorityArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['signer', getAddressEncoder()],
+      ['treasury', getAddressEncoder()],
+      ['protocolFee', getU16Encoder()],
+      ['challengeCount', getU64Encoder()],
       ['bump', getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: CREDENTIAL_AUTHORITY_DISCRIMINATOR })
@@ -70,6 +86,9 @@ export function getCredentialAuthorityDecoder(): FixedSizeDecoder<CredentialAuth
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['signer', getAddressDecoder()],
+    ['treasury', getAddressDecoder()],
+    ['protocolFee', getU16Decoder()],
+    ['challengeCount', getU64Decoder()],
     ['bump', getU8Decoder()],
   ]);
 }
@@ -154,5 +173,5 @@ export async function fetchAllMaybeCredentialAuthority(
 }
 
 export function getCredentialAuthoritySize(): number {
-  return 41;
+  return 83;
 }
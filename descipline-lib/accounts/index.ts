@@ -6,7 +6,10 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './allowedMint';
 export * from './challenge';
+export * from './claimedBitmap';
 export * from './credentialAuthority';
+export * from './dispute';
 export * from './receipt';
 export * from './resolution';
@@ -15,10 +15,16 @@ import {
   fetchEncodedAccounts,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -34,6 +40,12 @@ import {
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getReceiptStatusDecoder,
+  getReceiptStatusEncoder,
+  type ReceiptStatus,
+  type ReceiptStatusArgs,
+} from '../types';
 
 export const RECEIPT_DISCRIMINATOR = new Uint8Array([
   39, 154, 73, 106, 80, 102, 145, 153,
@@ -43,14 +55,37 @@ export function getReceiptDiscriminatorBytes() {
   return fixEncoderSize(getBytesEncoder(), 8).encode(RECEIPT_DISCRIMINATOR);
 }
 
-export type Receipt = { discriminator: ReadonlyUint8Array; bump: number };
+export type Receipt = {
+  discriminator: ReadonlyUint8Array;
+  staker: Address;
+  challengeId: bigint;
+  amount: bigint;
+  stakedAt: bigint;
+  status: ReceiptStatus;
+  prize: bigint;
+  bump: number;
+};
 
-export type ReceiptArgs = { bump: number };
+export type ReceiptArgs = {
+  staker: Address;
+  challengeId: number | bigint;
+  amount: number | bigint;
+  stakedAt: number | bigint;
+  status: ReceiptStatusArgs;
+  prize: number | bigint;
+  bump: number;
+};
 
 export function getReceiptEncoder(): FixedSizeEncoder<ReceiptArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['staker', getAddressEncoder()],
+      ['challengeId', getU64Encoder()],
+      ['amount', getU64Encoder()],
+      ['stakedAt', getI64Encoder()],
+      ['status', getReceiptStatusEncoder()],
+      ['prize', getU64Encoder()],
       ['bump', getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: RECEIPT_DISCRIMINATOR })
@@ -60,6 +95,12 @@ export function getReceiptEncoder(): FixedSizeEncoder<ReceiptArgs> {
 export function getReceiptDecoder(): FixedSizeDecoder<Receipt> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['staker', getAddressDecoder()],
+    ['challengeId', getU64Decoder()],
+    ['amount', getU64Decoder()],
+    ['stakedAt', getI64Decoder()],
+    ['status', getReceiptStatusDecoder()],
+    ['prize', getU64Decoder()],
     ['bump', getU8Decoder()],
   ]);
 }
@@ -122,5 +163,5 @@ export async function fetchAllMaybeReceipt(
 }
 
 export function getReceiptSize(): number {
-  return 9;
+  return 74;
 }
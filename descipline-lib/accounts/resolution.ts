@@ -17,12 +17,20 @@ import {
   fetchEncodedAccounts,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -53,6 +61,16 @@ export type Resolution = {
   winnerCount: number;
   winnerNotclaimCount: number;
   winnerListUri: ReadonlyUint8Array;
+  prizePool: bigint;
+  prizeAllocated: bigint;
+  prizeShare: bigint;
+  prizePaid: bigint;
+  feeAccrued: bigint;
+  attestor: Address;
+  attestations: Array<Address>;
+  resolvedAt: bigint;
+  openDisputes: number;
+  sweptCount: number;
   bump: number;
 };
 
@@ -61,6 +79,16 @@ export type ResolutionArgs = {
   winnerCount: number;
   winnerNotclaimCount: number;
   winnerListUri: ReadonlyUint8Array;
+  prizePool: number | bigint;
+  prizeAllocated: number | bigint;
+  prizeShare: number | bigint;
+  prizePaid: number | bigint;
+  feeAccrued: number | bigint;
+  attestor: Address;
+  attestations: Array<Address>;
+  resolvedAt: number | bigint;
+  openDisputes: number;
+  sweptCount: number;
   bump: number;
 };
 
@@ -69,12 +97,22 @@ export function getResolutionEncoder(): Encoder<ResolutionArgs> {
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['rootHash', fixEncoderSize(getBytesEncoder(), 32)],
-      ['winnerCount', getU8Encoder()],
-      ['winnerNotclaimCount', getU8Encoder()],
+      ['winnerCount', getU32Encoder()],
+      ['winnerNotclaimCount', getU32Encoder()],
       [
         'winnerListUri',
         addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
       ],
+      ['prizePool', getU64Encoder()],
+      ['prizeAllocated', getU64Encoder()],
+      ['prizeShare', getU64Encoder()],
+      ['prizePaid', getU64Encoder()],
+      ['feeAccrued', getU64Encoder()],
+      ['attestor', getAddressEncoder()],
+      ['attestations', getArrayEncoder(getAddressEncoder())],
+      ['resolvedAt', getI64Encoder()],
+      ['openDisputes', getU32Encoder()],
+      ['sweptCount', getU32Encoder()],
       ['bump', getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: RESOLUTION_DISCRIMINATOR })
@@ -85,9 +123,19 @@ export function getResolutionDecoder(): Decoder<Resolution> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['rootHash', fixDecoderSize(getBytesDecoder(), 32)],
-    ['winnerCount', getU8Decoder()],
-    ['winnerNotclaimCount', getU8Decoder()],
+    ['winnerCount', getU32Decoder()],
+    ['winnerNotclaimCount', getU32Decoder()],
     ['winnerListUri', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    ['prizePool', getU64Decoder()],
+    ['prizeAllocated', getU64Decoder()],
+    ['prizeShare', getU64Decoder()],
+    ['prizePaid', getU64Decoder()],
+    ['feeAccrued', getU64Decoder()],
+    ['attestor', getAddressDecoder()],
+    ['attestations', getArrayDecoder(getAddressDecoder())],
+    ['resolvedAt', getI64Decoder()],
+    ['openDisputes', getU32Decoder()],
+    ['sweptCount', getU32Decoder()],
     ['bump', getU8Decoder()],
   ]);
 }
@@ -14,7 +14,7 @@ import {
 } from '@solana/kit';
 import { DESCIPLINE_PROGRAM_ADDRESS } from '../programs';
 
-/** NotAllowedToken: Only WSOL or USDC allowed */
+/** NotAllowedToken: Mint is not allowed */
 export const DESCIPLINE_ERROR__NOT_ALLOWED_TOKEN = 0x1770; // 6000
 /** InvalidCredentialAuthority: Invalid credential authority */
 export const DESCIPLINE_ERROR__INVALID_CREDENTIAL_AUTHORITY = 0x1771; // 6001
@@ -26,7 +26,7 @@ export const DESCIPLINE_ERROR__INVALID_CREDENTIAL_DATA = 0x1773; // 6003
 export const DESCIPLINE_ERROR__INVALID_AUTHORITY = 0x1774; // 6004
 /** UnauthorizedSigner: Unauthorized signer */
 export const DESCIPLINE_ERROR__UNAUTHORIZED_SIGNER = 0x1775; // 6005
-/** TooManySigners: Too many signers (only one allowed) */
+/** TooManySigners: Too many signers */
 export const DESCIPLINE_ERROR__TOO_MANY_SIGNERS = 0x1776; // 6006
 /** SchemaPaused: Schema is paused */
 export const DESCIPLINE_ERROR__SCHEMA_PAUSED = 0x1777; // 6007
@@ -86,84 +86,292 @@ export const DESCIPLINE_ERROR__SHOULD_CLOSE_CHALLENGE = 0x1791; // 6033
 export const DESCIPLINE_ERROR__INVALID_CLOSE_CHALLENGE = 0x1792; // 6034
 /** InvalidDiscriminator: Invalid discriminator */
 export const DESCIPLINE_ERROR__INVALID_DISCRIMINATOR = 0x1793; // 6035
+/** UnsupportedMintExtension: Mint extension is not supported */
+export const DESCIPLINE_ERROR__UNSUPPORTED_MINT_EXTENSION = 0x1794; // 6036
+/** InvalidVault: Vault does not match challenge mint */
+export const DESCIPLINE_ERROR__INVALID_VAULT = 0x1795; // 6037
+/** MissingTokenAccount: Token account not provided */
+export const DESCIPLINE_ERROR__MISSING_TOKEN_ACCOUNT = 0x1796; // 6038
+/** InvalidInitiator: Not the challenge initiator */
+export const DESCIPLINE_ERROR__INVALID_INITIATOR = 0x1797; // 6039
+/** InvalidThreshold: Invalid attestation threshold */
+export const DESCIPLINE_ERROR__INVALID_THRESHOLD = 0x1798; // 6040
+/** InvalidResolutionFields: Resolution fields do not match schema layout */
+export const DESCIPLINE_ERROR__INVALID_RESOLUTION_FIELDS = 0x1799; // 6041
+/** DuplicateAttestation: Attestor has already attested */
+export const DESCIPLINE_ERROR__DUPLICATE_ATTESTATION = 0x179a; // 6042
+/** ResolutionMismatch: Attestations do not commit to the same resolution */
+export const DESCIPLINE_ERROR__RESOLUTION_MISMATCH = 0x179b; // 6043
+/** ThresholdNotMet: Not enough attestations */
+export const DESCIPLINE_ERROR__THRESHOLD_NOT_MET = 0x179c; // 6044
+/** InvalidWinnerCount: Winner count exceeds the maximum */
+export const DESCIPLINE_ERROR__INVALID_WINNER_COUNT = 0x179d; // 6045
+/** TooManyWinners: Winner count exceeds participant count */
+export const DESCIPLINE_ERROR__TOO_MANY_WINNERS = 0x179e; // 6046
+/** AttestationExpired: Attestation has expired */
+export const DESCIPLINE_ERROR__ATTESTATION_EXPIRED = 0x179f; // 6047
+/** AttestationNotFound: Attestation is not part of the resolution */
+export const DESCIPLINE_ERROR__ATTESTATION_NOT_FOUND = 0x17a0; // 6048
+/** AttestationNotRevoked: Attestation has not been revoked */
+export const DESCIPLINE_ERROR__ATTESTATION_NOT_REVOKED = 0x17a1; // 6049
+/** InvalidAccountOwner: Account is not owned by the attestation service */
+export const DESCIPLINE_ERROR__INVALID_ACCOUNT_OWNER = 0x17a2; // 6050
+/** InvalidChallengeStatus: Invalid challenge status for this instruction */
+export const DESCIPLINE_ERROR__INVALID_CHALLENGE_STATUS = 0x17a3; // 6051
+/** InvalidResolveDeadline: Resolve deadline must be after stake end */
+export const DESCIPLINE_ERROR__INVALID_RESOLVE_DEADLINE = 0x17a4; // 6052
+/** ResolveDeadlinePassed: Resolve deadline has passed */
+export const DESCIPLINE_ERROR__RESOLVE_DEADLINE_PASSED = 0x17a5; // 6053
+/** InvalidClaimDeadline: Claim deadline must be after claim start and the dispute window of the resolve deadline */
+export const DESCIPLINE_ERROR__INVALID_CLAIM_DEADLINE = 0x17a6; // 6054
+/** AlreadyStaked: Already staked in this challenge */
+export const DESCIPLINE_ERROR__ALREADY_STAKED = 0x17a7; // 6055
+/** ClaimEnded: Claim period has ended */
+export const DESCIPLINE_ERROR__CLAIM_ENDED = 0x17a8; // 6056
+/** SweepNotStarted: Claim period has not ended */
+export const DESCIPLINE_ERROR__SWEEP_NOT_STARTED = 0x17a9; // 6057
+/** InvalidReceipt: Invalid receipt */
+export const DESCIPLINE_ERROR__INVALID_RECEIPT = 0x17aa; // 6058
+/** ChallengeNotClosed: Challenge has not been closed */
+export const DESCIPLINE_ERROR__CHALLENGE_NOT_CLOSED = 0x17ab; // 6059
+/** NoWinners: Resolution has no winners */
+export const DESCIPLINE_ERROR__NO_WINNERS = 0x17ac; // 6060
+/** HasWinners: Resolution has winners */
+export const DESCIPLINE_ERROR__HAS_WINNERS = 0x17ad; // 6061
+/** InvalidBeneficiary: Not the configured beneficiary */
+export const DESCIPLINE_ERROR__INVALID_BENEFICIARY = 0x17ae; // 6062
+/** DonationFailed: Donation failed */
+export const DESCIPLINE_ERROR__DONATION_FAILED = 0x17af; // 6063
+/** InvalidProof: Malformed merkle proof */
+export const DESCIPLINE_ERROR__INVALID_PROOF = 0x17b0; // 6064
+/** InvalidProofIndex: Invalid merkle proof index */
+export const DESCIPLINE_ERROR__INVALID_PROOF_INDEX = 0x17b1; // 6065
+/** MerkleRootMismatch: Merkle root mismatch */
+export const DESCIPLINE_ERROR__MERKLE_ROOT_MISMATCH = 0x17b2; // 6066
+/** AlreadyClaimed: Prize has already been claimed */
+export const DESCIPLINE_ERROR__ALREADY_CLAIMED = 0x17b3; // 6067
+/** InvalidPayoutAmount: Payout amount does not match payout mode */
+export const DESCIPLINE_ERROR__INVALID_PAYOUT_AMOUNT = 0x17b4; // 6068
+/** PrizePoolExceeded: Payout exceeds the prize pool */
+export const DESCIPLINE_ERROR__PRIZE_POOL_EXCEEDED = 0x17b5; // 6069
+/** MissingRentDestination: Initiator and attestor are required for the last claim */
+export const DESCIPLINE_ERROR__MISSING_RENT_DESTINATION = 0x17b6; // 6070
+/** InvalidRelayerTip: Relayer tip exceeds the cap or the prize */
+export const DESCIPLINE_ERROR__INVALID_RELAYER_TIP = 0x17b7; // 6071
+/** MissingTipConsent: Relayer tip needs the winner's ed25519 signature in the preceding instruction */
+export const DESCIPLINE_ERROR__MISSING_TIP_CONSENT = 0x17b8; // 6072
+/** InvalidTipConsent: Winner did not sign this relayer tip */
+export const DESCIPLINE_ERROR__INVALID_TIP_CONSENT = 0x17b9; // 6073
+/** ClaimStarted: Prizes have already been claimed */
+export const DESCIPLINE_ERROR__CLAIM_STARTED = 0x17ba; // 6074
+/** RefundNotStarted: Resolve deadline has not passed */
+export const DESCIPLINE_ERROR__REFUND_NOT_STARTED = 0x17bb; // 6075
+/** ChallengeAlreadyResolved: Challenge has already been resolved */
+export const DESCIPLINE_ERROR__CHALLENGE_ALREADY_RESOLVED = 0x17bc; // 6076
+/** RefundFailed: Refund failed */
+export const DESCIPLINE_ERROR__REFUND_FAILED = 0x17bd; // 6077
+/** InvalidDisputeConfig: Dispute window requires a non-zero bond */
+export const DESCIPLINE_ERROR__INVALID_DISPUTE_CONFIG = 0x17be; // 6078
+/** DisputeWindowClosed: Dispute window has closed */
+export const DESCIPLINE_ERROR__DISPUTE_WINDOW_CLOSED = 0x17bf; // 6079
+/** DisputeWindowOpen: Dispute window has not closed */
+export const DESCIPLINE_ERROR__DISPUTE_WINDOW_OPEN = 0x17c0; // 6080
+/** DisputeOpen: Claims are frozen while a dispute is open */
+export const DESCIPLINE_ERROR__DISPUTE_OPEN = 0x17c1; // 6081
+/** DisputeBondFailed: Dispute bond transfer failed */
+export const DESCIPLINE_ERROR__DISPUTE_BOND_FAILED = 0x17c2; // 6082
+/** InvalidDispute: Invalid dispute account */
+export const DESCIPLINE_ERROR__INVALID_DISPUTE = 0x17c3; // 6083
+/** DisputesNotSettled: Every open dispute must be settled when the resolution is replaced */
+export const DESCIPLINE_ERROR__DISPUTES_NOT_SETTLED = 0x17c4; // 6084
+/** MissingFeeAccount: Fee account not provided */
+export const DESCIPLINE_ERROR__MISSING_FEE_ACCOUNT = 0x17c5; // 6085
+/** FeeTransferFailed: Fee transfer failed */
+export const DESCIPLINE_ERROR__FEE_TRANSFER_FAILED = 0x17c6; // 6086
+/** InvalidWinnerListUri: Winner list uri is too long */
+export const DESCIPLINE_ERROR__INVALID_WINNER_LIST_URI = 0x17c7; // 6087
 
 export type DesciplineError =
+  | typeof DESCIPLINE_ERROR__ALREADY_CLAIMED
+  | typeof DESCIPLINE_ERROR__ALREADY_STAKED
+  | typeof DESCIPLINE_ERROR__ATTESTATION_EXPIRED
+  | typeof DESCIPLINE_ERROR__ATTESTATION_NOT_FOUND
+  | typeof DESCIPLINE_ERROR__ATTESTATION_NOT_REVOKED
+  | typeof DESCIPLINE_ERROR__CHALLENGE_ALREADY_RESOLVED
   | typeof DESCIPLINE_ERROR__CHALLENGE_ENDED
   | typeof DESCIPLINE_ERROR__CHALLENGE_MISMATCH
+  | typeof DESCIPLINE_ERROR__CHALLENGE_NOT_CLOSED
   | typeof DESCIPLINE_ERROR__CHALLENGE_NOT_FOUND
   | typeof DESCIPLINE_ERROR__CHALLENGE_NOT_STARTED
+  | typeof DESCIPLINE_ERROR__CLAIM_ENDED
   | typeof DESCIPLINE_ERROR__CLAIM_FAILED
   | typeof DESCIPLINE_ERROR__CLAIM_NOT_STARTED
+  | typeof DESCIPLINE_ERROR__CLAIM_STARTED
   | typeof DESCIPLINE_ERROR__CREDENTIAL_MISMATCH
+  | typeof DESCIPLINE_ERROR__DISPUTES_NOT_SETTLED
+  | typeof DESCIPLINE_ERROR__DISPUTE_BOND_FAILED
+  | typeof DESCIPLINE_ERROR__DISPUTE_OPEN
+  | typeof DESCIPLINE_ERROR__DISPUTE_WINDOW_CLOSED
+  | typeof DESCIPLINE_ERROR__DISPUTE_WINDOW_OPEN
+  | typeof DESCIPLINE_ERROR__DONATION_FAILED
+  | typeof DESCIPLINE_ERROR__DUPLICATE_ATTESTATION
+  | typeof DESCIPLINE_ERROR__FEE_TRANSFER_FAILED
+  | typeof DESCIPLINE_ERROR__HAS_WINNERS
   | typeof DESCIPLINE_ERROR__INSUFFICIENT_TOKEN
   | typeof DESCIPLINE_ERROR__INVALID_ACCOUNT_DATA
+  | typeof DESCIPLINE_ERROR__INVALID_ACCOUNT_OWNER
   | typeof DESCIPLINE_ERROR__INVALID_ATTESTATION_DATA
   | typeof DESCIPLINE_ERROR__INVALID_ATTESTOR
   | typeof DESCIPLINE_ERROR__INVALID_AUTHORITY
+  | typeof DESCIPLINE_ERROR__INVALID_BENEFICIARY
+  | typeof DESCIPLINE_ERROR__INVALID_CHALLENGE_STATUS
+  | typeof DESCIPLINE_ERROR__INVALID_CLAIM_DEADLINE
   | typeof DESCIPLINE_ERROR__INVALID_CLOSE_CHALLENGE
   | typeof DESCIPLINE_ERROR__INVALID_CREDENTIAL
   | typeof DESCIPLINE_ERROR__INVALID_CREDENTIAL_AUTHORITY
   | typeof DESCIPLINE_ERROR__INVALID_CREDENTIAL_DATA
   | typeof DESCIPLINE_ERROR__INVALID_DATA_LAYOUT
   | typeof DESCIPLINE_ERROR__INVALID_DISCRIMINATOR
+  | typeof DESCIPLINE_ERROR__INVALID_DISPUTE
+  | typeof DESCIPLINE_ERROR__INVALID_DISPUTE_CONFIG
   | typeof DESCIPLINE_ERROR__INVALID_FEE_PERCENTAGE
+  | typeof DESCIPLINE_ERROR__INVALID_INITIATOR
   | typeof DESCIPLINE_ERROR__INVALID_LAYOUT
+  | typeof DESCIPLINE_ERROR__INVALID_PAYOUT_AMOUNT
+  | typeof DESCIPLINE_ERROR__INVALID_PROOF
+  | typeof DESCIPLINE_ERROR__INVALID_PROOF_INDEX
+  | typeof DESCIPLINE_ERROR__INVALID_RECEIPT
+  | typeof DESCIPLINE_ERROR__INVALID_RELAYER_TIP
+  | typeof DESCIPLINE_ERROR__INVALID_RESOLUTION_FIELDS
+  | typeof DESCIPLINE_ERROR__INVALID_RESOLVE_DEADLINE
   | typeof DESCIPLINE_ERROR__INVALID_SCHEMA
   | typeof DESCIPLINE_ERROR__INVALID_SCHEMA_DATA
   | typeof DESCIPLINE_ERROR__INVALID_SCHEMA_HASH
   | typeof DESCIPLINE_ERROR__INVALID_STAKE_AMOUNT
+  | typeof DESCIPLINE_ERROR__INVALID_THRESHOLD
+  | typeof DESCIPLINE_ERROR__INVALID_TIP_CONSENT
+  | typeof DESCIPLINE_ERROR__INVALID_VAULT
   | typeof DESCIPLINE_ERROR__INVALID_VERIFICATION_KEY_HASH
+  | typeof DESCIPLINE_ERROR__INVALID_WINNER_COUNT
+  | typeof DESCIPLINE_ERROR__INVALID_WINNER_LIST_URI
+  | typeof DESCIPLINE_ERROR__MERKLE_ROOT_MISMATCH
+  | typeof DESCIPLINE_ERROR__MISSING_FEE_ACCOUNT
+  | typeof DESCIPLINE_ERROR__MISSING_RENT_DESTINATION
+  | typeof DESCIPLINE_ERROR__MISSING_TIP_CONSENT
+  | typeof DESCIPLINE_ERROR__MISSING_TOKEN_ACCOUNT
   | typeof DESCIPLINE_ERROR__NAME_MISMATCH
   | typeof DESCIPLINE_ERROR__NOT_ALLOWED_TOKEN
   | typeof DESCIPLINE_ERROR__NOT_IN_WHITELIST
+  | typeof DESCIPLINE_ERROR__NO_WINNERS
+  | typeof DESCIPLINE_ERROR__PRIZE_POOL_EXCEEDED
+  | typeof DESCIPLINE_ERROR__REFUND_FAILED
+  | typeof DESCIPLINE_ERROR__REFUND_NOT_STARTED
+  | typeof DESCIPLINE_ERROR__RESOLUTION_MISMATCH
+  | typeof DESCIPLINE_ERROR__RESOLVE_DEADLINE_PASSED
   | typeof DESCIPLINE_ERROR__SCHEMA_MISMATCH
   | typeof DESCIPLINE_ERROR__SCHEMA_PAUSED
   | typeof DESCIPLINE_ERROR__SHOULD_CLOSE_CHALLENGE
   | typeof DESCIPLINE_ERROR__STAKE_ENDED
   | typeof DESCIPLINE_ERROR__STAKE_FAILED
+  | typeof DESCIPLINE_ERROR__SWEEP_NOT_STARTED
+  | typeof DESCIPLINE_ERROR__THRESHOLD_NOT_MET
   | typeof DESCIPLINE_ERROR__TOO_MANY_SIGNERS
+  | typeof DESCIPLINE_ERROR__TOO_MANY_WINNERS
   | typeof DESCIPLINE_ERROR__UNAUTHORIZED_SIGNER
-  | typeof DESCIPLINE_ERROR__UNAUTHORIZED_SIGNERS;
+  | typeof DESCIPLINE_ERROR__UNAUTHORIZED_SIGNERS
+  | typeof DESCIPLINE_ERROR__UNSUPPORTED_MINT_EXTENSION;
 
 let desciplineErrorMessages: Record<DesciplineError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   desciplineErrorMessages = {
+    [DESCIPLINE_ERROR__ALREADY_CLAIMED]: `Prize has already been claimed`,
+    [DESCIPLINE_ERROR__ALREADY_STAKED]: `Already staked in this challenge`,
+    [DESCIPLINE_ERROR__ATTESTATION_EXPIRED]: `Attestation has expired`,
+    [DESCIPLINE_ERROR__ATTESTATION_NOT_FOUND]: `Attestation is not part of the resolution`,
+    [DESCIPLINE_ERROR__ATTESTATION_NOT_REVOKED]: `Attestation has not been revoked`,
+    [DESCIPLINE_ERROR__CHALLENGE_ALREADY_RESOLVED]: `Challenge has already been resolved`,
     [DESCIPLINE_ERROR__CHALLENGE_ENDED]: `Challenge has already ended`,
     [DESCIPLINE_ERROR__CHALLENGE_MISMATCH]: `Challenge mismatch`,
+    [DESCIPLINE_ERROR__CHALLENGE_NOT_CLOSED]: `Challenge has not been closed`,
     [DESCIPLINE_ERROR__CHALLENGE_NOT_FOUND]: `Challenge not found`,
     [DESCIPLINE_ERROR__CHALLENGE_NOT_STARTED]: `Challenge has not started yet`,
+    [DESCIPLINE_ERROR__CLAIM_ENDED]: `Claim period has ended`,
     [DESCIPLINE_ERROR__CLAIM_FAILED]: `Claim failed`,
     [DESCIPLINE_ERROR__CLAIM_NOT_STARTED]: `Claim period has not started`,
+    [DESCIPLINE_ERROR__CLAIM_STARTED]: `Prizes have already been claimed`,
     [DESCIPLINE_ERROR__CREDENTIAL_MISMATCH]: `Credential mismatch`,
+    [DESCIPLINE_ERROR__DISPUTES_NOT_SETTLED]: `Every open dispute must be settled when the resolution is replaced`,
+    [DESCIPLINE_ERROR__DISPUTE_BOND_FAILED]: `Dispute bond transfer failed`,
+    [DESCIPLINE_ERROR__DISPUTE_OPEN]: `Claims are frozen while a dispute is open`,
+    [DESCIPLINE_ERROR__DISPUTE_WINDOW_CLOSED]: `Dispute window has closed`,
+    [DESCIPLINE_ERROR__DISPUTE_WINDOW_OPEN]: `Dispute window has not closed`,
+    [DESCIPLINE_ERROR__DONATION_FAILED]: `Donation failed`,
+    [DESCIPLINE_ERROR__DUPLICATE_ATTESTATION]: `Attestor has already attested`,
+    [DESCIPLINE_ERROR__FEE_TRANSFER_FAILED]: `Fee transfer failed`,
+    [DESCIPLINE_ERROR__HAS_WINNERS]: `Resolution has winners`,
     [DESCIPLINE_ERROR__INSUFFICIENT_TOKEN]: `Insufficient token balance`,
     [DESCIPLINE_ERROR__INVALID_ACCOUNT_DATA]: `Invalid Discriminator`,
+    [DESCIPLINE_ERROR__INVALID_ACCOUNT_OWNER]: `Account is not owned by the attestation service`,
     [DESCIPLINE_ERROR__INVALID_ATTESTATION_DATA]: `Invalid attestation data`,
     [DESCIPLINE_ERROR__INVALID_ATTESTOR]: `Not allowed attestor`,
     [DESCIPLINE_ERROR__INVALID_AUTHORITY]: `Invalid authority`,
+    [DESCIPLINE_ERROR__INVALID_BENEFICIARY]: `Not the configured beneficiary`,
+    [DESCIPLINE_ERROR__INVALID_CHALLENGE_STATUS]: `Invalid challenge status for this instruction`,
+    [DESCIPLINE_ERROR__INVALID_CLAIM_DEADLINE]: `Claim deadline must be after claim start and the dispute window of the resolve deadline`,
     [DESCIPLINE_ERROR__INVALID_CLOSE_CHALLENGE]: `Challenge should not be closed`,
     [DESCIPLINE_ERROR__INVALID_CREDENTIAL]: `Invalid credential authority`,
     [DESCIPLINE_ERROR__INVALID_CREDENTIAL_AUTHORITY]: `Invalid credential authority`,
     [DESCIPLINE_ERROR__INVALID_CREDENTIAL_DATA]: `Invalid credential data`,
     [DESCIPLINE_ERROR__INVALID_DATA_LAYOUT]: `Invalid data layout`,
     [DESCIPLINE_ERROR__INVALID_DISCRIMINATOR]: `Invalid discriminator`,
+    [DESCIPLINE_ERROR__INVALID_DISPUTE]: `Invalid dispute account`,
+    [DESCIPLINE_ERROR__INVALID_DISPUTE_CONFIG]: `Dispute window requires a non-zero bond`,
     [DESCIPLINE_ERROR__INVALID_FEE_PERCENTAGE]: `Invalid fee percentage`,
+    [DESCIPLINE_ERROR__INVALID_INITIATOR]: `Not the challenge initiator`,
     [DESCIPLINE_ERROR__INVALID_LAYOUT]: `Invalid layout`,
+    [DESCIPLINE_ERROR__INVALID_PAYOUT_AMOUNT]: `Payout amount does not match payout mode`,
+    [DESCIPLINE_ERROR__INVALID_PROOF]: `Malformed merkle proof`,
+    [DESCIPLINE_ERROR__INVALID_PROOF_INDEX]: `Invalid merkle proof index`,
+    [DESCIPLINE_ERROR__INVALID_RECEIPT]: `Invalid receipt`,
+    [DESCIPLINE_ERROR__INVALID_RELAYER_TIP]: `Relayer tip exceeds the cap or the prize`,
+    [DESCIPLINE_ERROR__INVALID_RESOLUTION_FIELDS]: `Resolution fields do not match schema layout`,
+    [DESCIPLINE_ERROR__INVALID_RESOLVE_DEADLINE]: `Resolve deadline must be after stake end`,
     [DESCIPLINE_ERROR__INVALID_SCHEMA]: `Invalid schema structure`,
     [DESCIPLINE_ERROR__INVALID_SCHEMA_DATA]: `Invalid schema data`,
     [DESCIPLINE_ERROR__INVALID_SCHEMA_HASH]: `Invalid schema hash`,
     [DESCIPLINE_ERROR__INVALID_STAKE_AMOUNT]: `Invalid stake amount`,
+    [DESCIPLINE_ERROR__INVALID_THRESHOLD]: `Invalid attestation threshold`,
+    [DESCIPLINE_ERROR__INVALID_TIP_CONSENT]: `Winner did not sign this relayer tip`,
+    [DESCIPLINE_ERROR__INVALID_VAULT]: `Vault does not match challenge mint`,
     [DESCIPLINE_ERROR__INVALID_VERIFICATION_KEY_HASH]: `Invalid verification key hash`,
+    [DESCIPLINE_ERROR__INVALID_WINNER_COUNT]: `Winner count exceeds the maximum`,
+    [DESCIPLINE_ERROR__INVALID_WINNER_LIST_URI]: `Winner list uri is too long`,
+    [DESCIPLINE_ERROR__MERKLE_ROOT_MISMATCH]: `Merkle root mismatch`,
+    [DESCIPLINE_ERROR__MISSING_FEE_ACCOUNT]: `Fee account not provided`,
+    [DESCIPLINE_ERROR__MISSING_RENT_DESTINATION]: `Initiator and attestor are required for the last claim`,
+    [DESCIPLINE_ERROR__MISSING_TIP_CONSENT]: `Relayer tip needs the winner's ed25519 signature in the preceding instruction`,
+    [DESCIPLINE_ERROR__MISSING_TOKEN_ACCOUNT]: `Token account not provided`,
     [DESCIPLINE_ERROR__NAME_MISMATCH]: `Schema name does not match challenge name`,
-    [DESCIPLINE_ERROR__NOT_ALLOWED_TOKEN]: `Only WSOL or USDC allowed`,
+    [DESCIPLINE_ERROR__NOT_ALLOWED_TOKEN]: `Mint is not allowed`,
     [DESCIPLINE_ERROR__NOT_IN_WHITELIST]: `Not in whitelist`,
+    [DESCIPLINE_ERROR__NO_WINNERS]: `Resolution has no winners`,
+    [DESCIPLINE_ERROR__PRIZE_POOL_EXCEEDED]: `Payout exceeds the prize pool`,
+    [DESCIPLINE_ERROR__REFUND_FAILED]: `Refund failed`,
+    [DESCIPLINE_ERROR__REFUND_NOT_STARTED]: `Resolve deadline has not passed`,
+    [DESCIPLINE_ERROR__RESOLUTION_MISMATCH]: `Attestations do not commit to the same resolution`,
+    [DESCIPLINE_ERROR__RESOLVE_DEADLINE_PASSED]: `Resolve deadline has passed`,
     [DESCIPLINE_ERROR__SCHEMA_MISMATCH]: `Schema mismatch`,
     [DESCIPLINE_ERROR__SCHEMA_PAUSED]: `Schema is paused`,
     [DESCIPLINE_ERROR__SHOULD_CLOSE_CHALLENGE]: `Last claim should close challenge`,
     [DESCIPLINE_ERROR__STAKE_ENDED]: `Stake has ended`,
     [DESCIPLINE_ERROR__STAKE_FAILED]: `Stake failed`,
-    [DESCIPLINE_ERROR__TOO_MANY_SIGNERS]: `Too many signers (only one allowed)`,
+    [DESCIPLINE_ERROR__SWEEP_NOT_STARTED]: `Claim period has not ended`,
+    [DESCIPLINE_ERROR__THRESHOLD_NOT_MET]: `Not enough attestations`,
+    [DESCIPLINE_ERROR__TOO_MANY_SIGNERS]: `Too many signers`,
+    [DESCIPLINE_ERROR__TOO_MANY_WINNERS]: `Winner count exceeds participant count`,
     [DESCIPLINE_ERROR__UNAUTHORIZED_SIGNER]: `Unauthorized signer`,
     [DESCIPLINE_ERROR__UNAUTHORIZED_SIGNERS]: `Unauthorized signers`,
+    [DESCIPLINE_ERROR__UNSUPPORTED_MINT_EXTENSION]: `Mint extension is not supported`,
   };
 }
 
@@ -15,13 +15,15 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
-  getU8Decoder,
-  getU8Encoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -32,6 +34,8 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -58,16 +62,22 @@ export type ClaimInstruction<
   TAccountWinner extends string | AccountMeta<string> = string,
   TAccountWinnerAta extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
+  TAccountSolVault extends string | AccountMeta<string> = string,
   TAccountChallenge extends string | AccountMeta<string> = string,
   TAccountResolution extends string | AccountMeta<string> = string,
+  TAccountClaimed extends string | AccountMeta<string> = string,
   TAccountReceipt extends string | AccountMeta<string> = string,
+  TAccountCredentialAuthority extends string | AccountMeta<string> = string,
+  TAccountInitiatorFeeAta extends string | AccountMeta<string> = string,
+  TAccountTreasuryAta extends string | AccountMeta<string> = string,
+  TAccountTreasury extends string | AccountMeta<string> = string,
+  TAccountInitiator extends string | AccountMeta<string> = string,
+  TAccountAttestor extends string | AccountMeta<string> = string,
   TAccountStakeMint extends string | AccountMeta<string> = string,
   TAccountAssociatedTokenProgram extends
     | string
     | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
-  TAccountTokenProgram extends
-    | string
-    | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -86,17 +96,41 @@ export type ClaimInstruction<
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
+      TAccountSolVault extends string
+        ? WritableAccount<TAccountSolVault>
+        : TAccountSolVault,
       TAccountChallenge extends string
-        ? ReadonlyAccount<TAccountChallenge>
+        ? WritableAccount<TAccountChallenge>
         : TAccountChallenge,
       TAccountResolution extends string
         ? WritableAccount<TAccountResolution>
         : TAccountResolution,
+      TAccountClaimed extends string
+        ? WritableAccount<TAccountClaimed>
+        : TAccountClaimed,
       TAccountReceipt extends string
         ? WritableAccount<TAccountReceipt>
         : TAccountReceipt,
+      TAccountCredentialAuthority extends string
+        ? ReadonlyAccount<TAccountCredentialAuthority>
+        : TAccountCredentialAuthority,
+      TAccountInitiatorFeeAta extends string
+        ? WritableAccount<TAccountInitiatorFeeAta>
+        : TAccountInitiatorFeeAta,
+      TAccountTreasuryAta extends string
+        ? WritableAccount<TAccountTreasuryAta>
+        : TAccountTreasuryAta,
+      TAccountTreasury extends string
+        ? WritableAccount<TAccountTreasury>
+        : TAccountTreasury,
+      TAccountInitiator extends string
+        ? WritableAccount<TAccountInitiator>
+        : TAccountInitiator,
+      TAccountAttestor extends string
+        ? WritableAccount<TAccountAttestor>
+        : TAccountAttestor,
       TAccountStakeMint extends string
-        ? ReadonlyAccount<TAccountStakeMint>
+        ? WritableAccount<TAccountStakeMint>
         : TAccountStakeMint,
       TAccountAssociatedTokenProgram extends string
         ? ReadonlyAccount<TAccountAssociatedTokenProgram>
@@ -115,11 +149,13 @@ export type ClaimInstructionData = {
   discriminator: ReadonlyUint8Array;
   proof: ReadonlyUint8Array;
   index: number;
+  amount: Option<bigint>;
 };
 
 export type ClaimInstructionDataArgs = {
   proof: ReadonlyUint8Array;
   index: number;
+  amount: OptionOrNullable<number | bigint>;
 };
 
 export function getClaimInstructionDataEncoder(): Encoder<ClaimInstructionDataArgs> {
@@ -127,7 +163,8 @@ export function getClaimInstructionDataEncoder(): Encoder<ClaimInstructionDataAr
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['proof', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
-      ['index', getU8Encoder()],
+      ['index', getU32Encoder()],
+      ['amount', getOptionEncoder(getU64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: CLAIM_DISCRIMINATOR })
   );
@@ -137,7 +174,8 @@ export function getClaimInstructionDataDecoder(): Decoder<ClaimInstructionData>
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['proof', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
-    ['index', getU8Decoder()],
+    ['index', getU32Decoder()],
+    ['amount', getOptionDecoder(getU64Decoder())],
   ]);
 }
 
@@ -155,9 +193,17 @@ export type ClaimAsyncInput<
   TAccountWinner extends string = string,
   TAccountWinnerAta extends string = string,
   TAccountVault extends string = string,
+  TAccountSolVault extends string = string,
   TAccountChallenge extends string = string,
   TAccountResolution extends string = string,
+  TAccountClaimed extends string = string,
   TAccountReceipt extends string = string,
+  TAccountCredentialAuthority extends string = string,
+  TAccountInitiatorFeeAta extends string = string,
+  TAccountTreasuryAta extends string = string,
+  TAccountTreasury extends string = string,
+  TAccountInitiator extends string = string,
+  TAccountAttestor extends string = string,
   TAccountStakeMint extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountTokenProgram extends string = string,
@@ -166,24 +212,41 @@ export type ClaimAsyncInput<
   winner: TransactionSigner<TAccountWinner>;
   winnerAta?: Address<TAccountWinnerAta>;
   vault?: Address<TAccountVault>;
+  solVault?: Address<TAccountSolVault>;
   challenge: Address<TAccountChallenge>;
   resolution?: Address<TAccountResolution>;
+  claimed?: Address<TAccountClaimed>;
   receipt?: Address<TAccountReceipt>;
+  credentialAuthority?: Address<TAccountCredentialAuthority>;
+  initiatorFeeAta?: Address<TAccountInitiatorFeeAta>;
+  treasuryAta?: Address<TAccountTreasuryAta>;
+  treasury?: Address<TAccountTreasury>;
+  initiator?: Address<TAccountInitiator>;
+  attestor?: Address<TAccountAttestor>;
   stakeMint: Address<TAccountStakeMint>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
-  tokenProgram?: Address<TAccountTokenProgram>;
+  tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   proof: ClaimInstructionDataArgs['proof'];
   index: ClaimInstructionDataArgs['index'];
+  amount: ClaimInstructionDataArgs['amount'];
 };
 
 export async function getClaimInstructionAsync<
   TAccountWinner extends string,
   TAccountWinnerAta extends string,
   TAccountVault extends string,
+  TAccountSolVault extends string,
   TAccountChallenge extends string,
   TAccountResolution extends string,
+  TAccountClaimed extends string,
   TAccountReceipt extends string,
+  TAccountCredentialAuthority extends string,
+  TAccountInitiatorFeeAta extends string,
+  TAccountTreasuryAta extends string,
+  TAccountTreasury extends string,
+  TAccountInitiator extends string,
+  TAccountAttestor extends string,
   TAccountStakeMint extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountTokenProgram extends string,
@@ -194,9 +257,17 @@ export async function getClaimInstructionAsync<
     TAccountWinner,
     TAccountWinnerAta,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountResolution,
+    TAccountClaimed,
     TAccountReceipt,
+    TAccountCredentialAuthority,
+    TAccountInitiatorFeeAta,
+    TAccountTreasuryAta,
+    TAccountTreasury,
+    TAccountInitiator,
+    TAccountAttestor,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -209,9 +280,17 @@ export async function getClaimInstructionAsync<
     TAccountWinner,
     TAccountWinnerAta,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountResolution,
+    TAccountClaimed,
     TAccountReceipt,
+    TAccountCredentialAuthority,
+    TAccountInitiatorFeeAta,
+    TAccountTreasuryAta,
+    TAccountTreasury,
+    TAccountInitiator,
+    TAccountAttestor,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -226,10 +305,21 @@ export async function getClaimInstructionAsync<
     winner: { value: input.winner ?? null, isWritable: true },
     winnerAta: { value: input.winnerAta ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
-    challenge: { value: input.challenge ?? null, isWritable: false },
+    solVault: { value: input.solVault ?? null, isWritable: true },
+    challenge: { value: input.challenge ?? null, isWritable: true },
     resolution: { value: input.resolution ?? null, isWritable: true },
+    claimed: { value: input.claimed ?? null, isWritable: true },
     receipt: { value: input.receipt ?? null, isWritable: true },
-    stakeMint: { value: input.stakeMint ?? null, isWritable: false },
+    credentialAuthority: {
+      value: input.credentialAuthority ?? null,
+      isWritable: false,
+    },
+    initiatorFeeAta: { value: input.initiatorFeeAta ?? null, isWritable: true },
+    treasuryAta: { value: input.treasuryAta ?? null, isWritable: true },
+    treasury: { value: input.treasury ?? null, isWritable: true },
+    initiator: { value: input.initiator ?? null, isWritable: true },
+    attestor: { value: input.attestor ?? null, isWritable: true },
+    stakeMint: { value: input.stakeMint ?? null, isWritable: true },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
@@ -252,13 +342,7 @@ export async function getClaimInstructionAsync<
         'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
       seeds: [
         getAddressEncoder().encode(expectAddress(accounts.winner.value)),
-        getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ])
-        ),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
         getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
       ],
     });
@@ -269,14 +353,19 @@ export async function getClaimInstructionAsync<
         'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
       seeds: [
         getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
+      ],
+    });
+  }
+  if (!accounts.solVault.value) {
+    accounts.solVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ])
+          new Uint8Array([115, 111, 108, 95, 118, 97, 117, 108, 116])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
+        getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
       ],
     });
   }
@@ -291,6 +380,17 @@ export async function getClaimInstructionAsync<
       ],
     });
   }
+  if (!accounts.claimed.value) {
+    accounts.claimed.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([99, 108, 97, 105, 109, 101, 100])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
+      ],
+    });
+  }
   if (!accounts.receipt.value) {
     accounts.receipt.value = await getProgramDerivedAddress({
       programAddress,
@@ -303,14 +403,20 @@ export async function getClaimInstructionAsync<
       ],
     });
   }
+  if (!accounts.credentialAuthority.value) {
+    accounts.credentialAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+        ),
+      ],
+    });
+  }
   if (!accounts.associatedTokenProgram.value) {
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
   }
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -322,9 +428,17 @@ export async function getClaimInstructionAsync<
       getAccountMeta(accounts.winner),
       getAccountMeta(accounts.winnerAta),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.solVault),
       getAccountMeta(accounts.challenge),
       getAccountMeta(accounts.resolution),
+      getAccountMeta(accounts.claimed),
       getAccountMeta(accounts.receipt),
+      getAccountMeta(accounts.credentialAuthority),
+      getAccountMeta(accounts.initiatorFeeAta),
+      getAccountMeta(accounts.treasuryAta),
+      getAccountMeta(accounts.treasury),
+      getAccountMeta(accounts.initiator),
+      getAccountMeta(accounts.attestor),
       getAccountMeta(accounts.stakeMint),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.tokenProgram),
@@ -339,9 +453,17 @@ export async function getClaimInstructionAsync<
     TAccountWinner,
     TAccountWinnerAta,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountResolution,
+    TAccountClaimed,
     TAccountReceipt,
+    TAccountCredentialAuthority,
+    TAccountInitiatorFeeAta,
+    TAccountTreasuryAta,
+    TAccountTreasury,
+    TAccountInitiator,
+    TAccountAttestor,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -355,35 +477,60 @@ export type ClaimInput<
   TAccountWinner extends string = string,
   TAccountWinnerAta extends string = string,
   TAccountVault extends string = string,
+  TAccountSolVault extends string = string,
   TAccountChallenge extends string = string,
   TAccountResolution extends string = string,
+  TAccountClaimed extends string = string,
   TAccountReceipt extends string = string,
+  TAccountCredentialAuthority extends string = string,
+  TAccountInitiatorFeeAta extends string = string,
+  TAccountTreasuryAta extends string = string,
+  TAccountTreasury extends string = string,
+  TAccountInitiator extends string = string,
+  TAccountAttestor extends string = string,
   TAccountStakeMint extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   winner: TransactionSigner<TAccountWinner>;
-  winnerAta: Address<TAccountWinnerAta>;
-  vault: Address<TAccountVault>;
+  winnerAta?: Address<TAccountWinnerAta>;
+  vault?: Address<TAccountVault>;
+  solVault?: Address<TAccountSolVault>;
   challenge: Address<TAccountChallenge>;
   resolution: Address<TAccountResolution>;
+  claimed: Address<TAccountClaimed>;
   receipt: Address<TAccountReceipt>;
+  credentialAuthority: Address<TAccountCredentialAuthority>;
+  initiatorFeeAta?: Address<TAccountInitiatorFeeAta>;
+  treasuryAta?: Address<TAccountTreasuryAta>;
+  treasury?: Address<TAccountTreasury>;
+  initiator?: Address<TAccountInitiator>;
+  attestor?: Address<TAccountAttestor>;
   stakeMint: Address<TAccountStakeMint>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
-  tokenProgram?: Address<TAccountTokenProgram>;
+  tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   proof: ClaimInstructionDataArgs['proof'];
   index: ClaimInstructionDataArgs['index'];
+  amount: ClaimInstructionDataArgs['amount'];
 };
 
 export function getClaimInstruction<
   TAccountWinner extends string,
   TAccountWinnerAta extends string,
   TAccountVault extends string,
+  TAccountSolVault extends string,
   TAccountChallenge extends string,
   TAccountResolution extends string,
+  TAccountClaimed extends string,
   TAccountReceipt extends string,
+  TAccountCredentialAuthority extends string,
+  TAccountInitiatorFeeAta extends string,
+  TAccountTreasuryAta extends string,
+  TAccountTreasury extends string,
+  TAccountInitiator extends string,
+  TAccountAttestor extends string,
   TAccountStakeMint extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountTokenProgram extends string,
@@ -394,9 +541,17 @@ export function getClaimInstruction<
     TAccountWinner,
     TAccountWinnerAta,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountResolution,
+    TAccountClaimed,
     TAccountReceipt,
+    TAccountCredentialAuthority,
+    TAccountInitiatorFeeAta,
+    TAccountTreasuryAta,
+    TAccountTreasury,
+    TAccountInitiator,
+    TAccountAttestor,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -408,9 +563,17 @@ export function getClaimInstruction<
   TAccountWinner,
   TAccountWinnerAta,
   TAccountVault,
+  TAccountSolVault,
   TAccountChallenge,
   TAccountResolution,
+  TAccountClaimed,
   TAccountReceipt,
+  TAccountCredentialAuthority,
+  TAccountInitiatorFeeAta,
+  TAccountTreasuryAta,
+  TAccountTreasury,
+  TAccountInitiator,
+  TAccountAttestor,
   TAccountStakeMint,
   TAccountAssociatedTokenProgram,
   TAccountTokenProgram,
@@ -424,10 +587,21 @@ export function getClaimInstruction<
     winner: { value: input.winner ?? null, isWritable: true },
     winnerAta: { value: input.winnerAta ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
-    challenge: { value: input.challenge ?? null, isWritable: false },
+    solVault: { value: input.solVault ?? null, isWritable: true },
+    challenge: { value: input.challenge ?? null, isWritable: true },
     resolution: { value: input.resolution ?? null, isWritable: true },
+    claimed: { value: input.claimed ?? null, isWritable: true },
     receipt: { value: input.receipt ?? null, isWritable: true },
-    stakeMint: { value: input.stakeMint ?? null, isWritable: false },
+    credentialAuthority: {
+      value: input.credentialAuthority ?? null,
+      isWritable: false,
+    },
+    initiatorFeeAta: { value: input.initiatorFeeAta ?? null, isWritable: true },
+    treasuryAta: { value: input.treasuryAta ?? null, isWritable: true },
+    treasury: { value: input.treasury ?? null, isWritable: true },
+    initiator: { value: input.initiator ?? null, isWritable: true },
+    attestor: { value: input.attestor ?? null, isWritable: true },
+    stakeMint: { value: input.stakeMint ?? null, isWritable: true },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
@@ -448,10 +622,6 @@ export function getClaimInstruction<
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
   }
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -463,9 +633,17 @@ export function getClaimInstruction<
       getAccountMeta(accounts.winner),
       getAccountMeta(accounts.winnerAta),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.solVault),
       getAccountMeta(accounts.challenge),
       getAccountMeta(accounts.resolution),
+      getAccountMeta(accounts.claimed),
       getAccountMeta(accounts.receipt),
+      getAccountMeta(accounts.credentialAuthority),
+      getAccountMeta(accounts.initiatorFeeAta),
+      getAccountMeta(accounts.treasuryAta),
+      getAccountMeta(accounts.treasury),
+      getAccountMeta(accounts.initiator),
+      getAccountMeta(accounts.attestor),
       getAccountMeta(accounts.stakeMint),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.tokenProgram),
@@ -480,9 +658,17 @@ export function getClaimInstruction<
     TAccountWinner,
     TAccountWinnerAta,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountResolution,
+    TAccountClaimed,
     TAccountReceipt,
+    TAccountCredentialAuthority,
+    TAccountInitiatorFeeAta,
+    TAccountTreasuryAta,
+    TAccountTreasury,
+    TAccountInitiator,
+    TAccountAttestor,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -499,15 +685,23 @@ export type ParsedClaimInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     winner: TAccountMetas[0];
-    winnerAta: TAccountMetas[1];
-    vault: TAccountMetas[2];
-    challenge: TAccountMetas[3];
-    resolution: TAccountMetas[4];
-    receipt: TAccountMetas[5];
-    stakeMint: TAccountMetas[6];
-    associatedTokenProgram: TAccountMetas[7];
-    tokenProgram: TAccountMetas[8];
-    systemProgram: TAccountMetas[9];
+    winnerAta?: TAccountMetas[1] | undefined;
+    vault?: TAccountMetas[2] | undefined;
+    solVault?: TAccountMetas[3] | undefined;
+    challenge: TAccountMetas[4];
+    resolution: TAccountMetas[5];
+    claimed: TAccountMetas[6];
+    receipt: TAccountMetas[7];
+    credentialAuthority: TAccountMetas[8];
+    initiatorFeeAta?: TAccountMetas[9] | undefined;
+    treasuryAta?: TAccountMetas[10] | undefined;
+    treasury?: TAccountMetas[11] | undefined;
+    initiator?: TAccountMetas[12] | undefined;
+    attestor?: TAccountMetas[13] | undefined;
+    stakeMint: TAccountMetas[14];
+    associatedTokenProgram: TAccountMetas[15];
+    tokenProgram: TAccountMetas[16];
+    systemProgram: TAccountMetas[17];
   };
   data: ClaimInstructionData;
 };
@@ -520,7 +714,7 @@ export function parseClaimInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedClaimInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 18) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -530,15 +724,29 @@ export function parseClaimInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === DESCIPLINE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       winner: getNextAccount(),
-      winnerAta: getNextAccount(),
-      vault: getNextAccount(),
+      winnerAta: getNextOptionalAccount(),
+      vault: getNextOptionalAccount(),
+      solVault: getNextOptionalAccount(),
       challenge: getNextAccount(),
       resolution: getNextAccount(),
+      claimed: getNextAccount(),
       receipt: getNextAccount(),
+      credentialAuthority: getNextAccount(),
+      initiatorFeeAta: getNextOptionalAccount(),
+      treasuryAta: getNextOptionalAccount(),
+      treasury: getNextOptionalAccount(),
+      initiator: getNextOptionalAccount(),
+      attestor: getNextOptionalAccount(),
       stakeMint: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
@@ -13,6 +13,8 @@ import {
   fixDecoderSize,
   fixEncoderSize,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
@@ -26,6 +28,8 @@ import {
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   getUtf8Decoder,
   getUtf8Encoder,
   transformEncoder,
@@ -52,10 +56,22 @@ import {
   type ResolvedAccount,
 } from '../shared';
 import {
-  getTokenAllowedDecoder,
-  getTokenAllowedEncoder,
-  type TokenAllowed,
-  type TokenAllowedArgs,
+  getPayoutModeDecoder,
+  getPayoutModeEncoder,
+  getResolutionFieldsDecoder,
+  getResolutionFieldsEncoder,
+  getSweepModeDecoder,
+  getSweepModeEncoder,
+  getZeroWinnerPolicyDecoder,
+  getZeroWinnerPolicyEncoder,
+  type PayoutMode,
+  type PayoutModeArgs,
+  type ResolutionFields,
+  type ResolutionFieldsArgs,
+  type SweepMode,
+  type SweepModeArgs,
+  type ZeroWinnerPolicy,
+  type ZeroWinnerPolicyArgs,
 } from '../types';
 
 export const CREATE_CHALLENGE_DISCRIMINATOR = new Uint8Array([
@@ -72,17 +88,17 @@ export type CreateChallengeInstruction<
   TProgram extends string = typeof DESCIPLINE_PROGRAM_ADDRESS,
   TAccountInitiator extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
+  TAccountSolVault extends string | AccountMeta<string> = string,
   TAccountChallenge extends string | AccountMeta<string> = string,
   TAccountSchema extends string | AccountMeta<string> = string,
   TAccountCredential extends string | AccountMeta<string> = string,
   TAccountCredentialAuthority extends string | AccountMeta<string> = string,
+  TAccountAllowedMint extends string | AccountMeta<string> = string,
   TAccountStakeMint extends string | AccountMeta<string> = string,
   TAccountAssociatedTokenProgram extends
     | string
     | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
-  TAccountTokenProgram extends
-    | string
-    | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -98,6 +114,9 @@ export type CreateChallengeInstruction<
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
+      TAccountSolVault extends string
+        ? WritableAccount<TAccountSolVault>
+        : TAccountSolVault,
       TAccountChallenge extends string
         ? WritableAccount<TAccountChallenge>
         : TAccountChallenge,
@@ -108,8 +127,11 @@ export type CreateChallengeInstruction<
         ? ReadonlyAccount<TAccountCredential>
         : TAccountCredential,
       TAccountCredentialAuthority extends string
-        ? ReadonlyAccount<TAccountCredentialAuthority>
+        ? WritableAccount<TAccountCredentialAuthority>
         : TAccountCredentialAuthority,
+      TAccountAllowedMint extends string
+        ? ReadonlyAccount<TAccountAllowedMint>
+        : TAccountAllowedMint,
       TAccountStakeMint extends string
         ? ReadonlyAccount<TAccountStakeMint>
         : TAccountStakeMint,
@@ -129,20 +151,40 @@ export type CreateChallengeInstruction<
 export type CreateChallengeInstructionData = {
   discriminator: ReadonlyUint8Array;
   name: string;
-  tokenAllowed: TokenAllowed;
   stakeAmount: bigint;
+  allowTopUp: boolean;
+  payoutMode: PayoutMode;
   fee: number;
   stakeEndAt: bigint;
   claimStartFrom: bigint;
+  claimEndAt: bigint;
+  sweepMode: SweepMode;
+  zeroWinnerPolicy: ZeroWinnerPolicy;
+  resolveDeadline: bigint;
+  disputeWindow: bigint;
+  disputeBond: bigint;
+  maxRelayerTip: bigint;
+  threshold: number;
+  resolutionFields: ResolutionFields;
 };
 
 export type CreateChallengeInstructionDataArgs = {
   name: string;
-  tokenAllowed: TokenAllowedArgs;
   stakeAmount: number | bigint;
+  allowTopUp: boolean;
+  payoutMode: PayoutModeArgs;
   fee: number;
   stakeEndAt: number | bigint;
   claimStartFrom: number | bigint;
+  claimEndAt: number | bigint;
+  sweepMode: SweepModeArgs;
+  zeroWinnerPolicy: ZeroWinnerPolicyArgs;
+  resolveDeadline: number | bigint;
+  disputeWindow: number | bigint;
+  disputeBond: number | bigint;
+  maxRelayerTip: number | bigint;
+  threshold: number;
+  resolutionFields: ResolutionFieldsArgs;
 };
 
 export function getCreateChallengeInstructionDataEncoder(): Encoder<CreateChallengeInstructionDataArgs> {
@@ -150,11 +192,21 @@ export function getCreateChallengeInstructionDataEncoder(): Encoder<CreateChalle
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
-      ['tokenAllowed', getTokenAllowedEncoder()],
       ['stakeAmount', getU64Encoder()],
+      ['allowTopUp', getBooleanEncoder()],
+      ['payoutMode', getPayoutModeEncoder()],
       ['fee', getU16Encoder()],
       ['stakeEndAt', getI64Encoder()],
       ['claimStartFrom', getI64Encoder()],
+      ['claimEndAt', getI64Encoder()],
+      ['sweepMode', getSweepModeEncoder()],
+      ['zeroWinnerPolicy', getZeroWinnerPolicyEncoder()],
+      ['resolveDeadline', getI64Encoder()],
+      ['disputeWindow', getI64Encoder()],
+      ['disputeBond', getU64Encoder()],
+      ['maxRelayerTip', getU64Encoder()],
+      ['threshold', getU8Encoder()],
+      ['resolutionFields', getResolutionFieldsEncoder()],
     ]),
     (value) => ({ ...value, discriminator: CREATE_CHALLENGE_DISCRIMINATOR })
   );
@@ -164,11 +216,21 @@ export function getCreateChallengeInstructionDataDecoder(): Decoder<CreateChalle
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
-    ['tokenAllowed', getTokenAllowedDecoder()],
     ['stakeAmount', getU64Decoder()],
+    ['allowTopUp', getBooleanDecoder()],
+    ['payoutMode', getPayoutModeDecoder()],
     ['fee', getU16Decoder()],
     ['stakeEndAt', getI64Decoder()],
     ['claimStartFrom', getI64Decoder()],
+    ['claimEndAt', getI64Decoder()],
+    ['sweepMode', getSweepModeDecoder()],
+    ['zeroWinnerPolicy', getZeroWinnerPolicyDecoder()],
+    ['resolveDeadline', getI64Decoder()],
+    ['disputeWindow', getI64Decoder()],
+    ['disputeBond', getU64Decoder()],
+    ['maxRelayerTip', getU64Decoder()],
+    ['threshold', getU8Decoder()],
+    ['resolutionFields', getResolutionFieldsDecoder()],
   ]);
 }
 
@@ -185,10 +247,12 @@ export function getCreateChallengeInstructionDataCodec(): Codec<
 export type CreateChallengeAsyncInput<
   TAccountInitiator extends string = string,
   TAccountVault extends string = string,
+  TAccountSolVault extends string = string,
   TAccountChallenge extends string = string,
   TAccountSchema extends string = string,
   TAccountCredential extends string = string,
   TAccountCredentialAuthority extends string = string,
+  TAccountAllowedMint extends string = string,
   TAccountStakeMint extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountTokenProgram extends string = string,
@@ -196,29 +260,43 @@ export type CreateChallengeAsyncInput<
 > = {
   initiator: TransactionSigner<TAccountInitiator>;
   vault?: Address<TAccountVault>;
+  solVault?: Address<TAccountSolVault>;
   challenge?: Address<TAccountChallenge>;
   schema: Address<TAccountSchema>;
   credential: Address<TAccountCredential>;
   credentialAuthority?: Address<TAccountCredentialAuthority>;
+  allowedMint?: Address<TAccountAllowedMint>;
   stakeMint: Address<TAccountStakeMint>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
-  tokenProgram?: Address<TAccountTokenProgram>;
+  tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   name: CreateChallengeInstructionDataArgs['name'];
-  tokenAllowed: CreateChallengeInstructionDataArgs['tokenAllowed'];
   stakeAmount: CreateChallengeInstructionDataArgs['stakeAmount'];
+  allowTopUp: CreateChallengeInstructionDataArgs['allowTopUp'];
+  payoutMode: CreateChallengeInstructionDataArgs['payoutMode'];
   fee: CreateChallengeInstructionDataArgs['fee'];
   stakeEndAt: CreateChallengeInstructionDataArgs['stakeEndAt'];
   claimStartFrom: CreateChallengeInstructionDataArgs['claimStartFrom'];
+  claimEndAt: CreateChallengeInstructionDataArgs['claimEndAt'];
+  sweepMode: CreateChallengeInstructionDataArgs['sweepMode'];
+  zeroWinnerPolicy: CreateChallengeInstructionDataArgs['zeroWinnerPolicy'];
+  resolveDeadline: CreateChallengeInstructionDataArgs['resolveDeadline'];
+  disputeWindow: CreateChallengeInstructionDataArgs['disputeWindow'];
+  disputeBond: CreateChallengeInstructionDataArgs['disputeBond'];
+  maxRelayerTip: CreateChallengeInstructionDataArgs['maxRelayerTip'];
+  threshold: CreateChallengeInstructionDataArgs['threshold'];
+  resolutionFields: CreateChallengeInstructionDataArgs['resolutionFields'];
 };
 
 export async function getCreateChallengeInstructionAsync<
   TAccountInitiator extends string,
   TAccountVault extends string,
+  TAccountSolVault extends string,
   TAccountChallenge extends string,
   TAccountSchema extends string,
   TAccountCredential extends string,
   TAccountCredentialAuthority extends string,
+  TAccountAllowedMint extends string,
   TAccountStakeMint extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountTokenProgram extends string,
@@ -228,10 +306,12 @@ export async function getCreateChallengeInstructionAsync<
   input: CreateChallengeAsyncInput<
     TAccountInitiator,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountSchema,
     TAccountCredential,
     TAccountCredentialAuthority,
+    TAccountAllowedMint,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -243,10 +323,12 @@ export async function getCreateChallengeInstructionAsync<
     TProgramAddress,
     TAccountInitiator,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountSchema,
     TAccountCredential,
     TAccountCredentialAuthority,
+    TAccountAllowedMint,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -260,13 +342,15 @@ export async function getCreateChallengeInstructionAsync<
   const originalAccounts = {
     initiator: { value: input.initiator ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    solVault: { value: input.solVault ?? null, isWritable: true },
     challenge: { value: input.challenge ?? null, isWritable: true },
     schema: { value: input.schema ?? null, isWritable: false },
     credential: { value: input.credential ?? null, isWritable: false },
     credentialAuthority: {
       value: input.credentialAuthority ?? null,
-      isWritable: false,
+      isWritable: true,
     },
+    allowedMint: { value: input.allowedMint ?? null, isWritable: false },
     stakeMint: { value: input.stakeMint ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
@@ -304,14 +388,19 @@ export async function getCreateChallengeInstructionAsync<
         'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
       seeds: [
         getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
+      ],
+    });
+  }
+  if (!accounts.solVault.value) {
+    accounts.solVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ])
+          new Uint8Array([115, 111, 108, 95, 118, 97, 117, 108, 116])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
+        getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
       ],
     });
   }
@@ -325,14 +414,23 @@ export async function getCreateChallengeInstructionAsync<
       ],
     });
   }
+  if (!accounts.allowedMint.value) {
+    accounts.allowedMint.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            97, 108, 108, 111, 119, 101, 100, 95, 109, 105, 110, 116,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
+      ],
+    });
+  }
   if (!accounts.associatedTokenProgram.value) {
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
   }
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -343,10 +441,12 @@ export async function getCreateChallengeInstructionAsync<
     accounts: [
       getAccountMeta(accounts.initiator),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.solVault),
       getAccountMeta(accounts.challenge),
       getAccountMeta(accounts.schema),
       getAccountMeta(accounts.credential),
       getAccountMeta(accounts.credentialAuthority),
+      getAccountMeta(accounts.allowedMint),
       getAccountMeta(accounts.stakeMint),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.tokenProgram),
@@ -360,10 +460,12 @@ export async function getCreateChallengeInstructionAsync<
     TProgramAddress,
     TAccountInitiator,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountSchema,
     TAccountCredential,
     TAccountCredentialAuthority,
+    TAccountAllowedMint,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -376,40 +478,56 @@ export async function getCreateChallengeInstructionAsync<
 export type CreateChallengeInput<
   TAccountInitiator extends string = string,
   TAccountVault extends string = string,
+  TAccountSolVault extends string = string,
   TAccountChallenge extends string = string,
   TAccountSchema extends string = string,
   TAccountCredential extends string = string,
   TAccountCredentialAuthority extends string = string,
+  TAccountAllowedMint extends string = string,
   TAccountStakeMint extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   initiator: TransactionSigner<TAccountInitiator>;
-  vault: Address<TAccountVault>;
+  vault?: Address<TAccountVault>;
+  solVault?: Address<TAccountSolVault>;
   challenge: Address<TAccountChallenge>;
   schema: Address<TAccountSchema>;
   credential: Address<TAccountCredential>;
   credentialAuthority: Address<TAccountCredentialAuthority>;
+  allowedMint: Address<TAccountAllowedMint>;
   stakeMint: Address<TAccountStakeMint>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
-  tokenProgram?: Address<TAccountTokenProgram>;
+  tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   name: CreateChallengeInstructionDataArgs['name'];
-  tokenAllowed: CreateChallengeInstructionDataArgs['tokenAllowed'];
   stakeAmount: CreateChallengeInstructionDataArgs['stakeAmount'];
+  allowTopUp: CreateChallengeInstructionDataArgs['allowTopUp'];
+  payoutMode: CreateChallengeInstructionDataArgs['payoutMode'];
   fee: CreateChallengeInstructionDataArgs['fee'];
   stakeEndAt: CreateChallengeInstructionDataArgs['stakeEndAt'];
   claimStartFrom: CreateChallengeInstructionDataArgs['claimStartFrom'];
+  claimEndAt: CreateChallengeInstructionDataArgs['claimEndAt'];
+  sweepMode: CreateChallengeInstructionDataArgs['sweepMode'];
+  zeroWinnerPolicy: CreateChallengeInstructionDataArgs['zeroWinnerPolicy'];
+  resolveDeadline: CreateChallengeInstructionDataArgs['resolveDeadline'];
+  disputeWindow: CreateChallengeInstructionDataArgs['disputeWindow'];
+  disputeBond: CreateChallengeInstructionDataArgs['disputeBond'];
+  maxRelayerTip: CreateChallengeInstructionDataArgs['maxRelayerTip'];
+  threshold: CreateChallengeInstructionDataArgs['threshold'];
+  resolutionFields: CreateChallengeInstructionDataArgs['resolutionFields'];
 };
 
 export function getCreateChallengeInstruction<
   TAccountInitiator extends string,
   TAccountVault extends string,
+  TAccountSolVault extends string,
   TAccountChallenge extends string,
   TAccountSchema extends string,
   TAccountCredential extends string,
   TAccountCredentialAuthority extends string,
+  TAccountAllowedMint extends string,
   TAccountStakeMint extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountTokenProgram extends string,
@@ -419,10 +537,12 @@ export function getCreateChallengeInstruction<
   input: CreateChallengeInput<
     TAccountInitiator,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountSchema,
     TAccountCredential,
     TAccountCredentialAuthority,
+    TAccountAllowedMint,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -433,10 +553,12 @@ export function getCreateChallengeInstruction<
   TProgramAddress,
   TAccountInitiator,
   TAccountVault,
+  TAccountSolVault,
   TAccountChallenge,
   TAccountSchema,
   TAccountCredential,
   TAccountCredentialAuthority,
+  TAccountAllowedMint,
   TAccountStakeMint,
   TAccountAssociatedTokenProgram,
   TAccountTokenProgram,
@@ -449,13 +571,15 @@ export function getCreateChallengeInstruction<
   const originalAccounts = {
     initiator: { value: input.initiator ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    solVault: { value: input.solVault ?? null, isWritable: true },
     challenge: { value: input.challenge ?? null, isWritable: true },
     schema: { value: input.schema ?? null, isWritable: false },
     credential: { value: input.credential ?? null, isWritable: false },
     credentialAuthority: {
       value: input.credentialAuthority ?? null,
-      isWritable: false,
+      isWritable: true,
     },
+    allowedMint: { value: input.allowedMint ?? null, isWritable: false },
     stakeMint: { value: input.stakeMint ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
@@ -477,10 +601,6 @@ export function getCreateChallengeInstruction<
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
   }
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -491,10 +611,12 @@ export function getCreateChallengeInstruction<
     accounts: [
       getAccountMeta(accounts.initiator),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.solVault),
       getAccountMeta(accounts.challenge),
       getAccountMeta(accounts.schema),
       getAccountMeta(accounts.credential),
       getAccountMeta(accounts.credentialAuthority),
+      getAccountMeta(accounts.allowedMint),
       getAccountMeta(accounts.stakeMint),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.tokenProgram),
@@ -508,10 +630,12 @@ export function getCreateChallengeInstruction<
     TProgramAddress,
     TAccountInitiator,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountSchema,
     TAccountCredential,
     TAccountCredentialAuthority,
+    TAccountAllowedMint,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -528,15 +652,17 @@ export type ParsedCreateChallengeInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     initiator: TAccountMetas[0];
-    vault: TAccountMetas[1];
-    challenge: TAccountMetas[2];
-    schema: TAccountMetas[3];
-    credential: TAccountMetas[4];
-    credentialAuthority: TAccountMetas[5];
-    stakeMint: TAccountMetas[6];
-    associatedTokenProgram: TAccountMetas[7];
-    tokenProgram: TAccountMetas[8];
-    systemProgram: TAccountMetas[9];
+    vault?: TAccountMetas[1] | undefined;
+    solVault?: TAccountMetas[2] | undefined;
+    challenge: TAccountMetas[3];
+    schema: TAccountMetas[4];
+    credential: TAccountMetas[5];
+    credentialAuthority: TAccountMetas[6];
+    allowedMint: TAccountMetas[7];
+    stakeMint: TAccountMetas[8];
+    associatedTokenProgram: TAccountMetas[9];
+    tokenProgram: TAccountMetas[10];
+    systemProgram: TAccountMetas[11];
   };
   data: CreateChallengeInstructionData;
 };
@@ -549,7 +675,7 @@ export function parseCreateChallengeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateChallengeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -559,15 +685,23 @@ export function parseCreateChallengeInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === DESCIPLINE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       initiator: getNextAccount(),
-      vault: getNextAccount(),
+      vault: getNextOptionalAccount(),
+      solVault: getNextOptionalAccount(),
       challenge: getNextAccount(),
       schema: getNextAccount(),
       credential: getNextAccount(),
       credentialAuthority: getNextAccount(),
+      allowedMint: getNextAccount(),
       stakeMint: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
@@ -6,9 +6,21 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addAllowedMint';
+export * from './cancelChallenge';
 export * from './claim';
-export * from './claimAndClose';
+export * from './claimFor';
+export * from './closeReceipt';
 export * from './createChallenge';
 export * from './initAuthority';
+export * from './invalidateResolution';
+export * from './openDispute';
+export * from './refund';
+export * from './removeAllowedMint';
+export * from './replaceResolution';
 export * from './resolve';
+export * from './setProtocolFee';
+export * from './settleNoWinners';
 export * from './stake';
+export * from './sweep';
+export * from './upholdResolution';
@@ -7,8 +7,6 @@
  */
 
 import {
-  addDecoderSizePrefix,
-  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
@@ -18,17 +16,13 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
-  getU32Decoder,
-  getU32Encoder,
-  getU8Decoder,
-  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type Codec,
-  type Decoder,
-  type Encoder,
+  type FixedSizeCodec,
+  type FixedSizeDecoder,
+  type FixedSizeEncoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
@@ -45,33 +39,29 @@ import {
   type ResolvedAccount,
 } from '../shared';
 
-export const CLAIM_AND_CLOSE_DISCRIMINATOR = new Uint8Array([
-  177, 41, 244, 95, 42, 114, 27, 99,
+export const REFUND_DISCRIMINATOR = new Uint8Array([
+  2, 96, 183, 251, 63, 208, 46, 46,
 ]);
 
-export function getClaimAndCloseDiscriminatorBytes() {
-  return fixEncoderSize(getBytesEncoder(), 8).encode(
-    CLAIM_AND_CLOSE_DISCRIMINATOR
-  );
+export function getRefundDiscriminatorBytes() {
+  return fixEncoderSize(getBytesEncoder(), 8).encode(REFUND_DISCRIMINATOR);
 }
 
-export type ClaimAndCloseInstruction<
+export type RefundInstruction<
   TProgram extends string = typeof DESCIPLINE_PROGRAM_ADDRESS,
-  TAccountWinner extends string | AccountMeta<string> = string,
-  TAccountWinnerAta extends string | AccountMeta<string> = string,
+  TAccountChallenger extends string | AccountMeta<string> = string,
+  TAccountChallengerAta extends string | AccountMeta<string> = string,
+  TAccountReceipt extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
+  TAccountSolVault extends string | AccountMeta<string> = string,
   TAccountChallenge extends string | AccountMeta<string> = string,
   TAccountResolution extends string | AccountMeta<string> = string,
-  TAccountReceipt extends string | AccountMeta<string> = string,
   TAccountInitiator extends string | AccountMeta<string> = string,
-  TAccountAttestor extends string | AccountMeta<string> = string,
   TAccountStakeMint extends string | AccountMeta<string> = string,
   TAccountAssociatedTokenProgram extends
     | string
     | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
-  TAccountTokenProgram extends
-    | string
-    | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -80,33 +70,33 @@ export type ClaimAndCloseInstruction<
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
-      TAccountWinner extends string
-        ? WritableSignerAccount<TAccountWinner> &
-            AccountSignerMeta<TAccountWinner>
-        : TAccountWinner,
-      TAccountWinnerAta extends string
-        ? WritableAccount<TAccountWinnerAta>
-        : TAccountWinnerAta,
+      TAccountChallenger extends string
+        ? WritableSignerAccount<TAccountChallenger> &
+            AccountSignerMeta<TAccountChallenger>
+        : TAccountChallenger,
+      TAccountChallengerAta extends string
+        ? WritableAccount<TAccountChallengerAta>
+        : TAccountChallengerAta,
+      TAccountReceipt extends string
+        ? WritableAccount<TAccountReceipt>
+        : TAccountReceipt,
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
+      TAccountSolVault extends string
+        ? WritableAccount<TAccountSolVault>
+        : TAccountSolVault,
       TAccountChallenge extends string
         ? WritableAccount<TAccountChallenge>
         : TAccountChallenge,
       TAccountResolution extends string
-        ? WritableAccount<TAccountResolution>
+        ? ReadonlyAccount<TAccountResolution>
         : TAccountResolution,
-      TAccountReceipt extends string
-        ? WritableAccount<TAccountReceipt>
-        : TAccountReceipt,
       TAccountInitiator extends string
         ? WritableAccount<TAccountInitiator>
         : TAccountInitiator,
-      TAccountAttestor extends string
-        ? WritableAccount<TAccountAttestor>
-        : TAccountAttestor,
       TAccountStakeMint extends string
-        ? ReadonlyAccount<TAccountStakeMint>
+        ? WritableAccount<TAccountStakeMint>
         : TAccountStakeMint,
       TAccountAssociatedTokenProgram extends string
         ? ReadonlyAccount<TAccountAssociatedTokenProgram>
@@ -121,100 +111,85 @@ export type ClaimAndCloseInstruction<
     ]
   >;
 
-export type ClaimAndCloseInstructionData = {
-  discriminator: ReadonlyUint8Array;
-  proof: ReadonlyUint8Array;
-  index: number;
-};
+export type RefundInstructionData = { discriminator: ReadonlyUint8Array };
 
-export type ClaimAndCloseInstructionDataArgs = {
-  proof: ReadonlyUint8Array;
-  index: number;
-};
+export type RefundInstructionDataArgs = {};
 
-export function getClaimAndCloseInstructionDataEncoder(): Encoder<ClaimAndCloseInstructionDataArgs> {
+export function getRefundInstructionDataEncoder(): FixedSizeEncoder<RefundInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([
-      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
-      ['proof', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
-      ['index', getU8Encoder()],
-    ]),
-    (value) => ({ ...value, discriminator: CLAIM_AND_CLOSE_DISCRIMINATOR })
+    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    (value) => ({ ...value, discriminator: REFUND_DISCRIMINATOR })
   );
 }
 
-export function getClaimAndCloseInstructionDataDecoder(): Decoder<ClaimAndCloseInstructionData> {
+export function getRefundInstructionDataDecoder(): FixedSizeDecoder<RefundInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
-    ['proof', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
-    ['index', getU8Decoder()],
   ]);
 }
 
-export function getClaimAndCloseInstructionDataCodec(): Codec<
-  ClaimAndCloseInstructionDataArgs,
-  ClaimAndCloseInstructionData
+export function getRefundInstructionDataCodec(): FixedSizeCodec<
+  RefundInstructionDataArgs,
+  RefundInstructionData
 > {
   return combineCodec(
-    getClaimAndCloseInstructionDataEncoder(),
-    getClaimAndCloseInstructionDataDecoder()
+    getRefundInstructionDataEncoder(),
+    getRefundInstructionDataDecoder()
   );
 }
 
-export type ClaimAndCloseAsyncInput<
-  TAccountWinner extends string = string,
-  TAccountWinnerAta extends string = string,
+export type RefundAsyncInput<
+  TAccountChallenger extends string = string,
+  TAccountChallengerAta extends string = string,
+  TAccountReceipt extends string = string,
   TAccountVault extends string = string,
+  TAccountSolVault extends string = string,
   TAccountChallenge extends string = string,
   TAccountResolution extends string = string,
-  TAccountReceipt extends string = string,
   TAccountInitiator extends string = string,
-  TAccountAttestor extends string = string,
   TAccountStakeMint extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
-  winner: TransactionSigner<TAccountWinner>;
-  winnerAta?: Address<TAccountWinnerAta>;
+  challenger: TransactionSigner<TAccountChallenger>;
+  challengerAta?: Address<TAccountChallengerAta>;
+  receipt?: Address<TAccountReceipt>;
   vault?: Address<TAccountVault>;
+  solVault?: Address<TAccountSolVault>;
   challenge: Address<TAccountChallenge>;
   resolution?: Address<TAccountResolution>;
-  receipt?: Address<TAccountReceipt>;
   initiator: Address<TAccountInitiator>;
-  attestor: Address<TAccountAttestor>;
   stakeMint: Address<TAccountStakeMint>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
-  tokenProgram?: Address<TAccountTokenProgram>;
+  tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
-  proof: ClaimAndCloseInstructionDataArgs['proof'];
-  index: ClaimAndCloseInstructionDataArgs['index'];
 };
 
-export async function getClaimAndCloseInstructionAsync<
-  TAccountWinner extends string,
-  TAccountWinnerAta extends string,
+export async function getRefundInstructionAsync<
+  TAccountChallenger extends string,
+  TAccountChallengerAta extends string,
+  TAccountReceipt extends string,
   TAccountVault extends string,
+  TAccountSolVault extends string,
   TAccountChallenge extends string,
   TAccountResolution extends string,
-  TAccountReceipt extends string,
   TAccountInitiator extends string,
-  TAccountAttestor extends string,
   TAccountStakeMint extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof DESCIPLINE_PROGRAM_ADDRESS,
 >(
-  input: ClaimAndCloseAsyncInput<
-    TAccountWinner,
-    TAccountWinnerAta,
+  input: RefundAsyncInput<
+    TAccountChallenger,
+    TAccountChallengerAta,
+    TAccountReceipt,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountResolution,
-    TAccountReceipt,
     TAccountInitiator,
-    TAccountAttestor,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -222,16 +197,16 @@ export async function getClaimAndCloseInstructionAsync<
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
-  ClaimAndCloseInstruction<
+  RefundInstruction<
     TProgramAddress,
-    TAccountWinner,
-    TAccountWinnerAta,
+    TAccountChallenger,
+    TAccountChallengerAta,
+    TAccountReceipt,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountResolution,
-    TAccountReceipt,
     TAccountInitiator,
-    TAccountAttestor,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -243,15 +218,15 @@ export async function getClaimAndCloseInstructionAsync<
 
   // Original accounts.
   const originalAccounts = {
-    winner: { value: input.winner ?? null, isWritable: true },
-    winnerAta: { value: input.winnerAta ?? null, isWritable: true },
+    challenger: { value: input.challenger ?? null, isWritable: true },
+    challengerAta: { value: input.challengerAta ?? null, isWritable: true },
+    receipt: { value: input.receipt ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    solVault: { value: input.solVault ?? null, isWritable: true },
     challenge: { value: input.challenge ?? null, isWritable: true },
-    resolution: { value: input.resolution ?? null, isWritable: true },
-    receipt: { value: input.receipt ?? null, isWritable: true },
+    resolution: { value: input.resolution ?? null, isWritable: false },
     initiator: { value: input.initiator ?? null, isWritable: true },
-    attestor: { value: input.attestor ?? null, isWritable: true },
-    stakeMint: { value: input.stakeMint ?? null, isWritable: false },
+    stakeMint: { value: input.stakeMint ?? null, isWritable: true },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
@@ -264,24 +239,27 @@ export async function getClaimAndCloseInstructionAsync<
     ResolvedAccount
   >;
 
-  // Original args.
-  const args = { ...input };
-
   // Resolve default values.
-  if (!accounts.winnerAta.value) {
-    accounts.winnerAta.value = await getProgramDerivedAddress({
+  if (!accounts.challengerAta.value) {
+    accounts.challengerAta.value = await getProgramDerivedAddress({
       programAddress:
         'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
       seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.winner.value)),
+        getAddressEncoder().encode(expectAddress(accounts.challenger.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
+      ],
+    });
+  }
+  if (!accounts.receipt.value) {
+    accounts.receipt.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ])
+          new Uint8Array([114, 101, 99, 101, 105, 112, 116])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
+        getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
+        getAddressEncoder().encode(expectAddress(accounts.challenger.value)),
       ],
     });
   }
@@ -291,37 +269,30 @@ export async function getClaimAndCloseInstructionAsync<
         'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
       seeds: [
         getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
-        getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ])
-        ),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
         getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
       ],
     });
   }
-  if (!accounts.resolution.value) {
-    accounts.resolution.value = await getProgramDerivedAddress({
+  if (!accounts.solVault.value) {
+    accounts.solVault.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([114, 101, 115, 111, 108, 117, 116, 105, 111, 110])
+          new Uint8Array([115, 111, 108, 95, 118, 97, 117, 108, 116])
         ),
         getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
       ],
     });
   }
-  if (!accounts.receipt.value) {
-    accounts.receipt.value = await getProgramDerivedAddress({
+  if (!accounts.resolution.value) {
+    accounts.resolution.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([114, 101, 99, 101, 105, 112, 116])
+          new Uint8Array([114, 101, 115, 111, 108, 117, 116, 105, 111, 110])
         ),
         getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
-        getAddressEncoder().encode(expectAddress(accounts.winner.value)),
       ],
     });
   }
@@ -329,10 +300,6 @@ export async function getClaimAndCloseInstructionAsync<
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
   }
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -341,33 +308,31 @@ export async function getClaimAndCloseInstructionAsync<
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
     accounts: [
-      getAccountMeta(accounts.winner),
-      getAccountMeta(accounts.winnerAta),
+      getAccountMeta(accounts.challenger),
+      getAccountMeta(accounts.challengerAta),
+      getAccountMeta(accounts.receipt),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.solVault),
       getAccountMeta(accounts.challenge),
       getAccountMeta(accounts.resolution),
-      getAccountMeta(accounts.receipt),
       getAccountMeta(accounts.initiator),
-      getAccountMeta(accounts.attestor),
       getAccountMeta(accounts.stakeMint),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
-    data: getClaimAndCloseInstructionDataEncoder().encode(
-      args as ClaimAndCloseInstructionDataArgs
-    ),
-  } as ClaimAndCloseInstruction<
+    data: getRefundInstructionDataEncoder().encode({}),
+  } as RefundInstruction<
     TProgramAddress,
-    TAccountWinner,
-    TAccountWinnerAta,
+    TAccountChallenger,
+    TAccountChallengerAta,
+    TAccountReceipt,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountResolution,
-    TAccountReceipt,
     TAccountInitiator,
-    TAccountAttestor,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -377,76 +342,74 @@ export async function getClaimAndCloseInstructionAsync<
   return instruction;
 }
 
-export type ClaimAndCloseInput<
-  TAccountWinner extends string = string,
-  TAccountWinnerAta extends string = string,
+export type RefundInput<
+  TAccountChallenger extends string = string,
+  TAccountChallengerAta extends string = string,
+  TAccountReceipt extends string = string,
   TAccountVault extends string = string,
+  TAccountSolVault extends string = string,
   TAccountChallenge extends string = string,
   TAccountResolution extends string = string,
-  TAccountReceipt extends string = string,
   TAccountInitiator extends string = string,
-  TAccountAttestor extends string = string,
   TAccountStakeMint extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
-  winner: TransactionSigner<TAccountWinner>;
-  winnerAta: Address<TAccountWinnerAta>;
-  vault: Address<TAccountVault>;
+  challenger: TransactionSigner<TAccountChallenger>;
+  challengerAta?: Address<TAccountChallengerAta>;
+  receipt: Address<TAccountReceipt>;
+  vault?: Address<TAccountVault>;
+  solVault?: Address<TAccountSolVault>;
   challenge: Address<TAccountChallenge>;
   resolution: Address<TAccountResolution>;
-  receipt: Address<TAccountReceipt>;
   initiator: Address<TAccountInitiator>;
-  attestor: Address<TAccountAttestor>;
   stakeMint: Address<TAccountStakeMint>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
-  tokenProgram?: Address<TAccountTokenProgram>;
+  tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
-  proof: ClaimAndCloseInstructionDataArgs['proof'];
-  index: ClaimAndCloseInstructionDataArgs['index'];
 };
 
-export function getClaimAndCloseInstruction<
-  TAccountWinner extends string,
-  TAccountWinnerAta extends string,
+export function getRefundInstruction<
+  TAccountChallenger extends string,
+  TAccountChallengerAta extends string,
+  TAccountReceipt extends string,
   TAccountVault extends string,
+  TAccountSolVault extends string,
   TAccountChallenge extends string,
   TAccountResolution extends string,
-  TAccountReceipt extends string,
   TAccountInitiator extends string,
-  TAccountAttestor extends string,
   TAccountStakeMint extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof DESCIPLINE_PROGRAM_ADDRESS,
 >(
-  input: ClaimAndCloseInput<
-    TAccountWinner,
-    TAccountWinnerAta,
+  input: RefundInput<
+    TAccountChallenger,
+    TAccountChallengerAta,
+    TAccountReceipt,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountResolution,
-    TAccountReceipt,
     TAccountInitiator,
-    TAccountAttestor,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
-): ClaimAndCloseInstruction<
+): RefundInstruction<
   TProgramAddress,
-  TAccountWinner,
-  TAccountWinnerAta,
+  TAccountChallenger,
+  TAccountChallengerAta,
+  TAccountReceipt,
   TAccountVault,
+  TAccountSolVault,
   TAccountChallenge,
   TAccountResolution,
-  TAccountReceipt,
   TAccountInitiator,
-  TAccountAttestor,
   TAccountStakeMint,
   TAccountAssociatedTokenProgram,
   TAccountTokenProgram,
@@ -457,15 +420,15 @@ export function getClaimAndCloseInstruction<
 
   // Original accounts.
   const originalAccounts = {
-    winner: { value: input.winner ?? null, isWritable: true },
-    winnerAta: { value: input.winnerAta ?? null, isWritable: true },
+    challenger: { value: input.challenger ?? null, isWritable: true },
+    challengerAta: { value: input.challengerAta ?? null, isWritable: true },
+    receipt: { value: input.receipt ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    solVault: { value: input.solVault ?? null, isWritable: true },
     challenge: { value: input.challenge ?? null, isWritable: true },
-    resolution: { value: input.resolution ?? null, isWritable: true },
-    receipt: { value: input.receipt ?? null, isWritable: true },
+    resolution: { value: input.resolution ?? null, isWritable: false },
     initiator: { value: input.initiator ?? null, isWritable: true },
-    attestor: { value: input.attestor ?? null, isWritable: true },
-    stakeMint: { value: input.stakeMint ?? null, isWritable: false },
+    stakeMint: { value: input.stakeMint ?? null, isWritable: true },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
@@ -478,18 +441,11 @@ export function getClaimAndCloseInstruction<
     ResolvedAccount
   >;
 
-  // Original args.
-  const args = { ...input };
-
   // Resolve default values.
   if (!accounts.associatedTokenProgram.value) {
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
   }
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -498,33 +454,31 @@ export function getClaimAndCloseInstruction<
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
     accounts: [
-      getAccountMeta(accounts.winner),
-      getAccountMeta(accounts.winnerAta),
+      getAccountMeta(accounts.challenger),
+      getAccountMeta(accounts.challengerAta),
+      getAccountMeta(accounts.receipt),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.solVault),
       getAccountMeta(accounts.challenge),
       getAccountMeta(accounts.resolution),
-      getAccountMeta(accounts.receipt),
       getAccountMeta(accounts.initiator),
-      getAccountMeta(accounts.attestor),
       getAccountMeta(accounts.stakeMint),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
-    data: getClaimAndCloseInstructionDataEncoder().encode(
-      args as ClaimAndCloseInstructionDataArgs
-    ),
-  } as ClaimAndCloseInstruction<
+    data: getRefundInstructionDataEncoder().encode({}),
+  } as RefundInstruction<
     TProgramAddress,
-    TAccountWinner,
-    TAccountWinnerAta,
+    TAccountChallenger,
+    TAccountChallengerAta,
+    TAccountReceipt,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountResolution,
-    TAccountReceipt,
     TAccountInitiator,
-    TAccountAttestor,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
@@ -534,36 +488,36 @@ export function getClaimAndCloseInstruction<
   return instruction;
 }
 
-export type ParsedClaimAndCloseInstruction<
+export type ParsedRefundInstruction<
   TProgram extends string = typeof DESCIPLINE_PROGRAM_ADDRESS,
   TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[],
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    winner: TAccountMetas[0];
-    winnerAta: TAccountMetas[1];
-    vault: TAccountMetas[2];
-    challenge: TAccountMetas[3];
-    resolution: TAccountMetas[4];
-    receipt: TAccountMetas[5];
-    initiator: TAccountMetas[6];
-    attestor: TAccountMetas[7];
+    challenger: TAccountMetas[0];
+    challengerAta?: TAccountMetas[1] | undefined;
+    receipt: TAccountMetas[2];
+    vault?: TAccountMetas[3] | undefined;
+    solVault?: TAccountMetas[4] | undefined;
+    challenge: TAccountMetas[5];
+    resolution: TAccountMetas[6];
+    initiator: TAccountMetas[7];
     stakeMint: TAccountMetas[8];
     associatedTokenProgram: TAccountMetas[9];
     tokenProgram: TAccountMetas[10];
     systemProgram: TAccountMetas[11];
   };
-  data: ClaimAndCloseInstructionData;
+  data: RefundInstructionData;
 };
 
-export function parseClaimAndCloseInstruction<
+export function parseRefundInstruction<
   TProgram extends string,
   TAccountMetas extends readonly AccountMeta[],
 >(
   instruction: Instruction<TProgram> &
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
-): ParsedClaimAndCloseInstruction<TProgram, TAccountMetas> {
+): ParsedRefundInstruction<TProgram, TAccountMetas> {
   if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
@@ -574,22 +528,28 @@ export function parseClaimAndCloseInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === DESCIPLINE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
-      winner: getNextAccount(),
-      winnerAta: getNextAccount(),
-      vault: getNextAccount(),
+      challenger: getNextAccount(),
+      challengerAta: getNextOptionalAccount(),
+      receipt: getNextAccount(),
+      vault: getNextOptionalAccount(),
+      solVault: getNextOptionalAccount(),
       challenge: getNextAccount(),
       resolution: getNextAccount(),
-      receipt: getNextAccount(),
       initiator: getNextAccount(),
-      attestor: getNextAccount(),
       stakeMint: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
     },
-    data: getClaimAndCloseInstructionDataDecoder().decode(instruction.data),
+    data: getRefundInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -16,6 +16,8 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -51,8 +53,16 @@ export type ResolveInstruction<
   TProgram extends string = typeof DESCIPLINE_PROGRAM_ADDRESS,
   TAccountAttestor extends string | AccountMeta<string> = string,
   TAccountChallenge extends string | AccountMeta<string> = string,
+  TAccountSchema extends string | AccountMeta<string> = string,
   TAccountResolution extends string | AccountMeta<string> = string,
-  TAccountAttestation extends string | AccountMeta<string> = string,
+  TAccountClaimed extends string | AccountMeta<string> = string,
+  TAccountVault extends string | AccountMeta<string> = string,
+  TAccountSolVault extends string | AccountMeta<string> = string,
+  TAccountStakeMint extends string | AccountMeta<string> = string,
+  TAccountAssociatedTokenProgram extends
+    | string
+    | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
+  TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -66,14 +76,32 @@ export type ResolveInstruction<
             AccountSignerMeta<TAccountAttestor>
         : TAccountAttestor,
       TAccountChallenge extends string
-        ? ReadonlyAccount<TAccountChallenge>
+        ? WritableAccount<TAccountChallenge>
         : TAccountChallenge,
+      TAccountSchema extends string
+        ? ReadonlyAccount<TAccountSchema>
+        : TAccountSchema,
       TAccountResolution extends string
         ? WritableAccount<TAccountResolution>
         : TAccountResolution,
-      TAccountAttestation extends string
-        ? ReadonlyAccount<TAccountAttestation>
-        : TAccountAttestation,
+      TAccountClaimed extends string
+        ? WritableAccount<TAccountClaimed>
+        : TAccountClaimed,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountSolVault extends string
+        ? ReadonlyAccount<TAccountSolVault>
+        : TAccountSolVault,
+      TAccountStakeMint extends string
+        ? ReadonlyAccount<TAccountStakeMint>
+        : TAccountStakeMint,
+      TAccountAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountAssociatedTokenProgram>
+        : TAccountAssociatedTokenProgram,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -81,13 +109,19 @@ export type ResolveInstruction<
     ]
   >;
 
-export type ResolveInstructionData = { discriminator: ReadonlyUint8Array };
+export type ResolveInstructionData = {
+  discriminator: ReadonlyUint8Array;
+  winnerCount: number;
+};
 
-export type ResolveInstructionDataArgs = {};
+export type ResolveInstructionDataArgs = { winnerCount: number };
 
 export function getResolveInstructionDataEncoder(): FixedSizeEncoder<ResolveInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['winnerCount', getU32Encoder()],
+    ]),
     (value) => ({ ...value, discriminator: RESOLVE_DISCRIMINATOR })
   );
 }
@@ -95,6 +129,7 @@ export function getResolveInstructionDataEncoder(): FixedSizeEncoder<ResolveInst
 export function getResolveInstructionDataDecoder(): FixedSizeDecoder<ResolveInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['winnerCount', getU32Decoder()],
   ]);
 }
 
@@ -111,30 +146,55 @@ export function getResolveInstructionDataCodec(): FixedSizeCodec<
 export type ResolveAsyncInput<
   TAccountAttestor extends string = string,
   TAccountChallenge extends string = string,
+  TAccountSchema extends string = string,
   TAccountResolution extends string = string,
-  TAccountAttestation extends string = string,
+  TAccountClaimed extends string = string,
+  TAccountVault extends string = string,
+  TAccountSolVault extends string = string,
+  TAccountStakeMint extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
+  TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   attestor: TransactionSigner<TAccountAttestor>;
   challenge: Address<TAccountChallenge>;
+  schema: Address<TAccountSchema>;
   resolution?: Address<TAccountResolution>;
-  attestation: Address<TAccountAttestation>;
+  claimed?: Address<TAccountClaimed>;
+  vault?: Address<TAccountVault>;
+  solVault?: Address<TAccountSolVault>;
+  stakeMint: Address<TAccountStakeMint>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  winnerCount: ResolveInstructionDataArgs['winnerCount'];
 };
 
 export async function getResolveInstructionAsync<
   TAccountAttestor extends string,
   TAccountChallenge extends string,
+  TAccountSchema extends string,
   TAccountResolution extends string,
-  TAccountAttestation extends string,
+  TAccountClaimed extends string,
+  TAccountVault extends string,
+  TAccountSolVault extends string,
+  TAccountStakeMint extends string,
+  TAccountAssociatedTokenProgram extends string,
+  TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof DESCIPLINE_PROGRAM_ADDRESS,
 >(
   input: ResolveAsyncInput<
     TAccountAttestor,
     TAccountChallenge,
+    TAccountSchema,
     TAccountResolution,
-    TAccountAttestation,
+    TAccountClaimed,
+    TAccountVault,
+    TAccountSolVault,
+    TAccountStakeMint,
+    TAccountAssociatedTokenProgram,
+    TAccountTokenProgram,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -143,8 +203,14 @@ export async function getResolveInstructionAsync<
     TProgramAddress,
     TAccountAttestor,
     TAccountChallenge,
+    TAccountSchema,
     TAccountResolution,
-    TAccountAttestation,
+    TAccountClaimed,
+    TAccountVault,
+    TAccountSolVault,
+    TAccountStakeMint,
+    TAccountAssociatedTokenProgram,
+    TAccountTokenProgram,
     TAccountSystemProgram
   >
 > {
@@ -154,9 +220,18 @@ export async function getResolveInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     attestor: { value: input.attestor ?? null, isWritable: true },
-    challenge: { value: input.challenge ?? null, isWritable: false },
+    challenge: { value: input.challenge ?? null, isWritable: true },
+    schema: { value: input.schema ?? null, isWritable: false },
     resolution: { value: input.resolution ?? null, isWritable: true },
-    attestation: { value: input.attestation ?? null, isWritable: false },
+    claimed: { value: input.claimed ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: false },
+    solVault: { value: input.solVault ?? null, isWritable: false },
+    stakeMint: { value: input.stakeMint ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -164,6 +239,9 @@ export async function getResolveInstructionAsync<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.resolution.value) {
     accounts.resolution.value = await getProgramDerivedAddress({
@@ -176,6 +254,43 @@ export async function getResolveInstructionAsync<
       ],
     });
   }
+  if (!accounts.claimed.value) {
+    accounts.claimed.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([99, 108, 97, 105, 109, 101, 100])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
+      ],
+    });
+  }
+  if (!accounts.vault.value) {
+    accounts.vault.value = await getProgramDerivedAddress({
+      programAddress:
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
+      ],
+    });
+  }
+  if (!accounts.solVault.value) {
+    accounts.solVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([115, 111, 108, 95, 118, 97, 117, 108, 116])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
+      ],
+    });
+  }
+  if (!accounts.associatedTokenProgram.value) {
+    accounts.associatedTokenProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -186,18 +301,32 @@ export async function getResolveInstructionAsync<
     accounts: [
       getAccountMeta(accounts.attestor),
       getAccountMeta(accounts.challenge),
+      getAccountMeta(accounts.schema),
       getAccountMeta(accounts.resolution),
-      getAccountMeta(accounts.attestation),
+      getAccountMeta(accounts.claimed),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.solVault),
+      getAccountMeta(accounts.stakeMint),
+      getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
-    data: getResolveInstructionDataEncoder().encode({}),
+    data: getResolveInstructionDataEncoder().encode(
+      args as ResolveInstructionDataArgs
+    ),
   } as ResolveInstruction<
     TProgramAddress,
     TAccountAttestor,
     TAccountChallenge,
+    TAccountSchema,
     TAccountResolution,
-    TAccountAttestation,
+    TAccountClaimed,
+    TAccountVault,
+    TAccountSolVault,
+    TAccountStakeMint,
+    TAccountAssociatedTokenProgram,
+    TAccountTokenProgram,
     TAccountSystemProgram
   >;
 
@@ -207,30 +336,55 @@ export async function getResolveInstructionAsync<
 export type ResolveInput<
   TAccountAttestor extends string = string,
   TAccountChallenge extends string = string,
+  TAccountSchema extends string = string,
   TAccountResolution extends string = string,
-  TAccountAttestation extends string = string,
+  TAccountClaimed extends string = string,
+  TAccountVault extends string = string,
+  TAccountSolVault extends string = string,
+  TAccountStakeMint extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
+  TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   attestor: TransactionSigner<TAccountAttestor>;
   challenge: Address<TAccountChallenge>;
+  schema: Address<TAccountSchema>;
   resolution: Address<TAccountResolution>;
-  attestation: Address<TAccountAttestation>;
+  claimed: Address<TAccountClaimed>;
+  vault?: Address<TAccountVault>;
+  solVault?: Address<TAccountSolVault>;
+  stakeMint: Address<TAccountStakeMint>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  winnerCount: ResolveInstructionDataArgs['winnerCount'];
 };
 
 export function getResolveInstruction<
   TAccountAttestor extends string,
   TAccountChallenge extends string,
+  TAccountSchema extends string,
   TAccountResolution extends string,
-  TAccountAttestation extends string,
+  TAccountClaimed extends string,
+  TAccountVault extends string,
+  TAccountSolVault extends string,
+  TAccountStakeMint extends string,
+  TAccountAssociatedTokenProgram extends string,
+  TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof DESCIPLINE_PROGRAM_ADDRESS,
 >(
   input: ResolveInput<
     TAccountAttestor,
     TAccountChallenge,
+    TAccountSchema,
     TAccountResolution,
-    TAccountAttestation,
+    TAccountClaimed,
+    TAccountVault,
+    TAccountSolVault,
+    TAccountStakeMint,
+    TAccountAssociatedTokenProgram,
+    TAccountTokenProgram,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -238,8 +392,14 @@ export function getResolveInstruction<
   TProgramAddress,
   TAccountAttestor,
   TAccountChallenge,
+  TAccountSchema,
   TAccountResolution,
-  TAccountAttestation,
+  TAccountClaimed,
+  TAccountVault,
+  TAccountSolVault,
+  TAccountStakeMint,
+  TAccountAssociatedTokenProgram,
+  TAccountTokenProgram,
   TAccountSystemProgram
 > {
   // Program address.
@@ -248,9 +408,18 @@ export function getResolveInstruction<
   // Original accounts.
   const originalAccounts = {
     attestor: { value: input.attestor ?? null, isWritable: true },
-    challenge: { value: input.challenge ?? null, isWritable: false },
+    challenge: { value: input.challenge ?? null, isWritable: true },
+    schema: { value: input.schema ?? null, isWritable: false },
     resolution: { value: input.resolution ?? null, isWritable: true },
-    attestation: { value: input.attestation ?? null, isWritable: false },
+    claimed: { value: input.claimed ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: false },
+    solVault: { value: input.solVault ?? null, isWritable: false },
+    stakeMint: { value: input.stakeMint ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -258,7 +427,14 @@ export function getResolveInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
+  if (!accounts.associatedTokenProgram.value) {
+    accounts.associatedTokenProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -269,18 +445,32 @@ export function getResolveInstruction<
     accounts: [
       getAccountMeta(accounts.attestor),
       getAccountMeta(accounts.challenge),
+      getAccountMeta(accounts.schema),
       getAccountMeta(accounts.resolution),
-      getAccountMeta(accounts.attestation),
+      getAccountMeta(accounts.claimed),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.solVault),
+      getAccountMeta(accounts.stakeMint),
+      getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
-    data: getResolveInstructionDataEncoder().encode({}),
+    data: getResolveInstructionDataEncoder().encode(
+      args as ResolveInstructionDataArgs
+    ),
   } as ResolveInstruction<
     TProgramAddress,
     TAccountAttestor,
     TAccountChallenge,
+    TAccountSchema,
     TAccountResolution,
-    TAccountAttestation,
+    TAccountClaimed,
+    TAccountVault,
+    TAccountSolVault,
+    TAccountStakeMint,
+    TAccountAssociatedTokenProgram,
+    TAccountTokenProgram,
     TAccountSystemProgram
   >;
 
@@ -295,9 +485,15 @@ export type ParsedResolveInstruction<
   accounts: {
     attestor: TAccountMetas[0];
     challenge: TAccountMetas[1];
-    resolution: TAccountMetas[2];
-    attestation: TAccountMetas[3];
-    systemProgram: TAccountMetas[4];
+    schema: TAccountMetas[2];
+    resolution: TAccountMetas[3];
+    claimed: TAccountMetas[4];
+    vault?: TAccountMetas[5] | undefined;
+    solVault?: TAccountMetas[6] | undefined;
+    stakeMint: TAccountMetas[7];
+    associatedTokenProgram: TAccountMetas[8];
+    tokenProgram: TAccountMetas[9];
+    systemProgram: TAccountMetas[10];
   };
   data: ResolveInstructionData;
 };
@@ -310,7 +506,7 @@ export function parseResolveInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedResolveInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -320,13 +516,25 @@ export function parseResolveInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === DESCIPLINE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       attestor: getNextAccount(),
       challenge: getNextAccount(),
+      schema: getNextAccount(),
       resolution: getNextAccount(),
-      attestation: getNextAccount(),
+      claimed: getNextAccount(),
+      vault: getNextOptionalAccount(),
+      solVault: getNextOptionalAccount(),
+      stakeMint: getNextAccount(),
+      associatedTokenProgram: getNextAccount(),
+      tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
     },
     data: getResolveInstructionDataDecoder().decode(instruction.data),
@@ -53,14 +53,13 @@ export type StakeInstruction<
   TAccountChallengerAta extends string | AccountMeta<string> = string,
   TAccountReceipt extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
+  TAccountSolVault extends string | AccountMeta<string> = string,
   TAccountChallenge extends string | AccountMeta<string> = string,
   TAccountStakeMint extends string | AccountMeta<string> = string,
   TAccountAssociatedTokenProgram extends
     | string
     | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
-  TAccountTokenProgram extends
-    | string
-    | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -82,8 +81,11 @@ export type StakeInstruction<
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
+      TAccountSolVault extends string
+        ? WritableAccount<TAccountSolVault>
+        : TAccountSolVault,
       TAccountChallenge extends string
-        ? ReadonlyAccount<TAccountChallenge>
+        ? WritableAccount<TAccountChallenge>
         : TAccountChallenge,
       TAccountStakeMint extends string
         ? ReadonlyAccount<TAccountStakeMint>
@@ -133,6 +135,7 @@ export type StakeAsyncInput<
   TAccountChallengerAta extends string = string,
   TAccountReceipt extends string = string,
   TAccountVault extends string = string,
+  TAccountSolVault extends string = string,
   TAccountChallenge extends string = string,
   TAccountStakeMint extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
@@ -143,10 +146,11 @@ export type StakeAsyncInput<
   challengerAta?: Address<TAccountChallengerAta>;
   receipt?: Address<TAccountReceipt>;
   vault?: Address<TAccountVault>;
+  solVault?: Address<TAccountSolVault>;
   challenge: Address<TAccountChallenge>;
   stakeMint: Address<TAccountStakeMint>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
-  tokenProgram?: Address<TAccountTokenProgram>;
+  tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
 };
 
@@ -155,6 +159,7 @@ export async function getStakeInstructionAsync<
   TAccountChallengerAta extends string,
   TAccountReceipt extends string,
   TAccountVault extends string,
+  TAccountSolVault extends string,
   TAccountChallenge extends string,
   TAccountStakeMint extends string,
   TAccountAssociatedTokenProgram extends string,
@@ -167,6 +172,7 @@ export async function getStakeInstructionAsync<
     TAccountChallengerAta,
     TAccountReceipt,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
@@ -181,6 +187,7 @@ export async function getStakeInstructionAsync<
     TAccountChallengerAta,
     TAccountReceipt,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
@@ -197,7 +204,8 @@ export async function getStakeInstructionAsync<
     challengerAta: { value: input.challengerAta ?? null, isWritable: true },
     receipt: { value: input.receipt ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
-    challenge: { value: input.challenge ?? null, isWritable: false },
+    solVault: { value: input.solVault ?? null, isWritable: true },
+    challenge: { value: input.challenge ?? null, isWritable: true },
     stakeMint: { value: input.stakeMint ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
@@ -218,13 +226,7 @@ export async function getStakeInstructionAsync<
         'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
       seeds: [
         getAddressEncoder().encode(expectAddress(accounts.challenger.value)),
-        getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ])
-        ),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
         getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
       ],
     });
@@ -247,14 +249,19 @@ export async function getStakeInstructionAsync<
         'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
       seeds: [
         getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
+      ],
+    });
+  }
+  if (!accounts.solVault.value) {
+    accounts.solVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ])
+          new Uint8Array([115, 111, 108, 95, 118, 97, 117, 108, 116])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.stakeMint.value)),
+        getAddressEncoder().encode(expectAddress(accounts.challenge.value)),
       ],
     });
   }
@@ -262,10 +269,6 @@ export async function getStakeInstructionAsync<
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
   }
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -278,6 +281,7 @@ export async function getStakeInstructionAsync<
       getAccountMeta(accounts.challengerAta),
       getAccountMeta(accounts.receipt),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.solVault),
       getAccountMeta(accounts.challenge),
       getAccountMeta(accounts.stakeMint),
       getAccountMeta(accounts.associatedTokenProgram),
@@ -292,6 +296,7 @@ export async function getStakeInstructionAsync<
     TAccountChallengerAta,
     TAccountReceipt,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
@@ -307,6 +312,7 @@ export type StakeInput<
   TAccountChallengerAta extends string = string,
   TAccountReceipt extends string = string,
   TAccountVault extends string = string,
+  TAccountSolVault extends string = string,
   TAccountChallenge extends string = string,
   TAccountStakeMint extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
@@ -314,13 +320,14 @@ export type StakeInput<
   TAccountSystemProgram extends string = string,
 > = {
   challenger: TransactionSigner<TAccountChallenger>;
-  challengerAta: Address<TAccountChallengerAta>;
+  challengerAta?: Address<TAccountChallengerAta>;
   receipt: Address<TAccountReceipt>;
-  vault: Address<TAccountVault>;
+  vault?: Address<TAccountVault>;
+  solVault?: Address<TAccountSolVault>;
   challenge: Address<TAccountChallenge>;
   stakeMint: Address<TAccountStakeMint>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
-  tokenProgram?: Address<TAccountTokenProgram>;
+  tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
 };
 
@@ -329,6 +336,7 @@ export function getStakeInstruction<
   TAccountChallengerAta extends string,
   TAccountReceipt extends string,
   TAccountVault extends string,
+  TAccountSolVault extends string,
   TAccountChallenge extends string,
   TAccountStakeMint extends string,
   TAccountAssociatedTokenProgram extends string,
@@ -341,6 +349,7 @@ export function getStakeInstruction<
     TAccountChallengerAta,
     TAccountReceipt,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
@@ -354,6 +363,7 @@ export function getStakeInstruction<
   TAccountChallengerAta,
   TAccountReceipt,
   TAccountVault,
+  TAccountSolVault,
   TAccountChallenge,
   TAccountStakeMint,
   TAccountAssociatedTokenProgram,
@@ -369,7 +379,8 @@ export function getStakeInstruction<
     challengerAta: { value: input.challengerAta ?? null, isWritable: true },
     receipt: { value: input.receipt ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
-    challenge: { value: input.challenge ?? null, isWritable: false },
+    solVault: { value: input.solVault ?? null, isWritable: true },
+    challenge: { value: input.challenge ?? null, isWritable: true },
     stakeMint: { value: input.stakeMint ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
@@ -388,10 +399,6 @@ export function getStakeInstruction<
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
   }
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -404,6 +411,7 @@ export function getStakeInstruction<
       getAccountMeta(accounts.challengerAta),
       getAccountMeta(accounts.receipt),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.solVault),
       getAccountMeta(accounts.challenge),
       getAccountMeta(accounts.stakeMint),
       getAccountMeta(accounts.associatedTokenProgram),
@@ -418,6 +426,7 @@ export function getStakeInstruction<
     TAccountChallengerAta,
     TAccountReceipt,
     TAccountVault,
+    TAccountSolVault,
     TAccountChallenge,
     TAccountStakeMint,
     TAccountAssociatedTokenProgram,
@@ -435,14 +444,15 @@ export type ParsedStakeInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     challenger: TAccountMetas[0];
-    challengerAta: TAccountMetas[1];
+    challengerAta?: TAccountMetas[1] | undefined;
     receipt: TAccountMetas[2];
-    vault: TAccountMetas[3];
-    challenge: TAccountMetas[4];
-    stakeMint: TAccountMetas[5];
-    associatedTokenProgram: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
-    systemProgram: TAccountMetas[8];
+    vault?: TAccountMetas[3] | undefined;
+    solVault?: TAccountMetas[4] | undefined;
+    challenge: TAccountMetas[5];
+    stakeMint: TAccountMetas[6];
+    associatedTokenProgram: TAccountMetas[7];
+    tokenProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[9];
   };
   data: StakeInstructionData;
 };
@@ -455,7 +465,7 @@ export function parseStakeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedStakeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -465,13 +475,20 @@ export function parseStakeInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === DESCIPLINE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       challenger: getNextAccount(),
-      challengerAta: getNextAccount(),
+      challengerAta: getNextOptionalAccount(),
       receipt: getNextAccount(),
-      vault: getNextAccount(),
+      vault: getNextOptionalAccount(),
+      solVault: getNextOptionalAccount(),
       challenge: getNextAccount(),
       stakeMint: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
@@ -14,20 +14,35 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
-  type ParsedClaimAndCloseInstruction,
+  type ParsedAddAllowedMintInstruction,
+  type ParsedCancelChallengeInstruction,
+  type ParsedClaimForInstruction,
   type ParsedClaimInstruction,
+  type ParsedCloseReceiptInstruction,
   type ParsedCreateChallengeInstruction,
   type ParsedInitAuthorityInstruction,
+  type ParsedInvalidateResolutionInstruction,
+  type ParsedOpenDisputeInstruction,
+  type ParsedRefundInstruction,
+  type ParsedRemoveAllowedMintInstruction,
+  type ParsedReplaceResolutionInstruction,
   type ParsedResolveInstruction,
+  type ParsedSetProtocolFeeInstruction,
+  type ParsedSettleNoWinnersInstruction,
   type ParsedStakeInstruction,
+  type ParsedSweepInstruction,
+  type ParsedUpholdResolutionInstruction,
 } from '../instructions';
 
 export const DESCIPLINE_PROGRAM_ADDRESS =
-  '2s3weLk9GXoqkbw377YbiQpfQX1eQ6DxUsfHi82WRZLF' as Address<'2s3weLk9GXoqkbw377YbiQpfQX1eQ6DxUsfHi82WRZLF'>;
+  '59cH5dmYsEMCJh8vBsBtfNJjWrsotZBQoGZELbmqQ1tz' as Address<'59cH5dmYsEMCJh8vBsBtfNJjWrsotZBQoGZELbmqQ1tz'>;
 
 export enum DesciplineAccount {
+  AllowedMint,
   Challenge,
+  ClaimedBitmap,
   CredentialAuthority,
+  Dispute,
   Receipt,
   Resolution,
 }
@@ -36,6 +51,17 @@ export function identifyDesciplineAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): DesciplineAccount {
   const data = 'data' in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([173, 229, 179, 46, 121, 164, 247, 6])
+      ),
+      0
+    )
+  ) {
+    return DesciplineAccount.AllowedMint;
+  }
   if (
     containsBytes(
       data,
@@ -47,6 +73,17 @@ export function identifyDesciplineAccount(
   ) {
     return DesciplineAccount.Challenge;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([42, 242, 64, 97, 154, 187, 151, 183])
+      ),
+      0
+    )
+  ) {
+    return DesciplineAccount.ClaimedBitmap;
+  }
   if (
     containsBytes(
       data,
@@ -58,6 +95,17 @@ export function identifyDesciplineAccount(
   ) {
     return DesciplineAccount.CredentialAuthority;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([36, 49, 241, 67, 40, 36, 241, 74])
+      ),
+      0
+    )
+  ) {
+    return DesciplineAccount.Dispute;
+  }
   if (
     containsBytes(
       data,
@@ -86,18 +134,52 @@ export function identifyDesciplineAccount(
 }
 
 export enum DesciplineInstruction {
+  AddAllowedMint,
+  CancelChallenge,
   Claim,
-  ClaimAndClose,
+  ClaimFor,
+  CloseReceipt,
   CreateChallenge,
   InitAuthority,
+  InvalidateResolution,
+  OpenDispute,
+  Refund,
+  RemoveAllowedMint,
+  ReplaceResolution,
   Resolve,
+  SetProtocolFee,
+  SettleNoWinners,
   Stake,
+  Sweep,
+  UpholdResolution,
 }
 
 export function identifyDesciplineInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): DesciplineInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([114, 83, 166, 247, 86, 17, 220, 147])
+      ),
+      0
+    )
+  ) {
+    return DesciplineInstruction.AddAllowedMint;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([231, 253, 0, 151, 179, 94, 5, 152])
+      ),
+      0
+    )
+  ) {
+    return DesciplineInstruction.CancelChallenge;
+  }
   if (
     containsBytes(
       data,
@@ -113,12 +195,23 @@ export function identifyDesciplineInstruction(
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([177, 41, 244, 95, 42, 114, 27, 99])
+        new Uint8Array([245, 67, 97, 44, 59, 223, 144, 1])
       ),
       0
     )
   ) {
-    return DesciplineInstruction.ClaimAndClose;
+    return DesciplineInstruction.ClaimFor;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([126, 254, 244, 203, 124, 164, 134, 89])
+      ),
+      0
+    )
+  ) {
+    return DesciplineInstruction.CloseReceipt;
   }
   if (
     containsBytes(
@@ -142,6 +235,61 @@ export function identifyDesciplineInstruction(
   ) {
     return DesciplineInstruction.InitAuthority;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([215, 154, 0, 46, 182, 21, 105, 253])
+      ),
+      0
+    )
+  ) {
+    return DesciplineInstruction.InvalidateResolution;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([137, 25, 99, 119, 23, 223, 161, 42])
+      ),
+      0
+    )
+  ) {
+    return DesciplineInstruction.OpenDispute;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([2, 96, 183, 251, 63, 208, 46, 46])
+      ),
+      0
+    )
+  ) {
+    return DesciplineInstruction.Refund;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([53, 133, 46, 51, 25, 228, 27, 73])
+      ),
+      0
+    )
+  ) {
+    return DesciplineInstruction.RemoveAllowedMint;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([49, 178, 228, 254, 175, 15, 201, 15])
+      ),
+      0
+    )
+  ) {
+    return DesciplineInstruction.ReplaceResolution;
+  }
   if (
     containsBytes(
       data,
@@ -153,6 +301,28 @@ export function identifyDesciplineInstruction(
   ) {
     return DesciplineInstruction.Resolve;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([173, 239, 83, 242, 136, 43, 144, 217])
+      ),
+      0
+    )
+  ) {
+    return DesciplineInstruction.SetProtocolFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([42, 239, 102, 56, 58, 138, 179, 143])
+      ),
+      0
+    )
+  ) {
+    return DesciplineInstruction.SettleNoWinners;
+  }
   if (
     containsBytes(
       data,
@@ -164,29 +334,87 @@ export function identifyDesciplineInstruction(
   ) {
     return DesciplineInstruction.Stake;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([40, 23, 234, 175, 14, 61, 154, 177])
+      ),
+      0
+    )
+  ) {
+    return DesciplineInstruction.Sweep;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([12, 178, 10, 169, 23, 138, 155, 170])
+      ),
+      0
+    )
+  ) {
+    return DesciplineInstruction.UpholdResolution;
+  }
   throw new Error(
     'The provided instruction could not be identified as a descipline instruction.'
   );
 }
 
 export type ParsedDesciplineInstruction<
-  TProgram extends string = '2s3weLk9GXoqkbw377YbiQpfQX1eQ6DxUsfHi82WRZLF',
+  TProgram extends string = '59cH5dmYsEMCJh8vBsBtfNJjWrsotZBQoGZELbmqQ1tz',
 > =
+  | ({
+      instructionType: DesciplineInstruction.AddAllowedMint;
+    } & ParsedAddAllowedMintInstruction<TProgram>)
+  | ({
+      instructionType: DesciplineInstruction.CancelChallenge;
+    } & ParsedCancelChallengeInstruction<TProgram>)
   | ({
       instructionType: DesciplineInstruction.Claim;
     } & ParsedClaimInstruction<TProgram>)
   | ({
-      instructionType: DesciplineInstruction.ClaimAndClose;
-    } & ParsedClaimAndCloseInstruction<TProgram>)
+      instructionType: DesciplineInstruction.ClaimFor;
+    } & ParsedClaimForInstruction<TProgram>)
+  | ({
+      instructionType: DesciplineInstruction.CloseReceipt;
+    } & ParsedCloseReceiptInstruction<TProgram>)
   | ({
       instructionType: DesciplineInstruction.CreateChallenge;
     } & ParsedCreateChallengeInstruction<TProgram>)
   | ({
       instructionType: DesciplineInstruction.InitAuthority;
     } & ParsedInitAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: DesciplineInstruction.InvalidateResolution;
+    } & ParsedInvalidateResolutionInstruction<TProgram>)
+  | ({
+      instructionType: DesciplineInstruction.OpenDispute;
+    } & ParsedOpenDisputeInstruction<TProgram>)
+  | ({
+      instructionType: DesciplineInstruction.Refund;
+    } & ParsedRefundInstruction<TProgram>)
+  | ({
+      instructionType: DesciplineInstruction.RemoveAllowedMint;
+    } & ParsedRemoveAllowedMintInstruction<TProgram>)
+  | ({
+      instructionType: DesciplineInstruction.ReplaceResolution;
+    } & ParsedReplaceResolutionInstruction<TProgram>)
   | ({
       instructionType: DesciplineInstruction.Resolve;
     } & ParsedResolveInstruction<TProgram>)
+  | ({
+      instructionType: DesciplineInstruction.SetProtocolFee;
+    } & ParsedSetProtocolFeeInstruction<TProgram>)
+  | ({
+      instructionType: DesciplineInstruction.SettleNoWinners;
+    } & ParsedSettleNoWinnersInstruction<TProgram>)
   | ({
       instructionType: DesciplineInstruction.Stake;
-    } & ParsedStakeInstruction<TProgram>);
+    } & ParsedStakeInstruction<TProgram>)
+  | ({
+      instructionType: DesciplineInstruction.Sweep;
+    } & ParsedSweepInstruction<TProgram>)
+  | ({
+      instructionType: DesciplineInstruction.UpholdResolution;
+    } & ParsedUpholdResolutionInstruction<TProgram>);
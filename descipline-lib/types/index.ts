@@ -6,4 +6,9 @@
  * @see https://github.com/codama-idl/codama
  */
 
-export * from './tokenAllowed';
+export * from './challengeStatus';
+export * from './payoutMode';
+export * from './receiptStatus';
+export * from './resolutionFields';
+export * from './sweepMode';
+export * from './zeroWinnerPolicy';
@@ -0,0 +1,27 @@
+#![no_main]
+
+use descipline::interfaces::{AttestationInterface, CredentialInterface, SchemaInterface};
+use libfuzzer_sys::fuzz_target;
+
+// Every loader must return an error instead of panicking on malformed account data.
+fuzz_target!(|data: &[u8]| {
+    let _ = CredentialInterface::new(data);
+
+    if let Ok(schema) = SchemaInterface::new(data) {
+        // parse the whole input against the layout it declares
+        let attestation = AttestationInterface {
+            credential: schema.credential,
+            schema: Default::default(),
+            data: data.to_vec(),
+            signer: Default::default(),
+            expiry: 0,
+        };
+        let _ = attestation.verify_layout_and_parse(schema.layout);
+    }
+
+    if let Ok(attestation) = AttestationInterface::new(data) {
+        // the first bytes of the attested data double as the layout
+        let layout = attestation.data.iter().take(8).copied().collect();
+        let _ = attestation.verify_layout_and_parse(layout);
+    }
+});
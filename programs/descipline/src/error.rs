@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 #[error_code]
 pub enum DesciplineError {
     // -------- General --------
-    #[msg("Only WSOL or USDC allowed")]
+    #[msg("Mint is not allowed")]
     NotAllowedToken,
     #[msg("Invalid credential authority")]
     InvalidCredentialAuthority,
@@ -17,7 +17,7 @@ pub enum DesciplineError {
     InvalidAuthority,
     #[msg("Unauthorized signer")]
     UnauthorizedSigner,
-    #[msg("Too many signers (only one allowed)")]
+    #[msg("Too many signers")]
     TooManySigners,
 
     // -------- Schema --------
@@ -89,4 +89,132 @@ pub enum DesciplineError {
     // -------- Common --------
     #[msg("Invalid discriminator")]
     InvalidDiscriminator,
+
+    // Codes above are deployed, new variants are only ever appended below.
+
+    // -------- General --------
+    #[msg("Mint extension is not supported")]
+    UnsupportedMintExtension,
+    #[msg("Vault does not match challenge mint")]
+    InvalidVault,
+    #[msg("Token account not provided")]
+    MissingTokenAccount,
+    #[msg("Not the challenge initiator")]
+    InvalidInitiator,
+
+    // -------- Credential --------
+    #[msg("Invalid attestation threshold")]
+    InvalidThreshold,
+
+    // -------- Schema --------
+    #[msg("Resolution fields do not match schema layout")]
+    InvalidResolutionFields,
+
+    // -------- Attestation --------
+    #[msg("Attestor has already attested")]
+    DuplicateAttestation,
+    #[msg("Attestations do not commit to the same resolution")]
+    ResolutionMismatch,
+    #[msg("Not enough attestations")]
+    ThresholdNotMet,
+    #[msg("Winner count exceeds the maximum")]
+    InvalidWinnerCount,
+    #[msg("Winner count exceeds participant count")]
+    TooManyWinners,
+    #[msg("Attestation has expired")]
+    AttestationExpired,
+    #[msg("Attestation is not part of the resolution")]
+    AttestationNotFound,
+    #[msg("Attestation has not been revoked")]
+    AttestationNotRevoked,
+    #[msg("Account is not owned by the attestation service")]
+    InvalidAccountOwner,
+
+    // -------- Challenge --------
+    #[msg("Invalid challenge status for this instruction")]
+    InvalidChallengeStatus,
+    #[msg("Resolve deadline must be after stake end")]
+    InvalidResolveDeadline,
+    #[msg("Resolve deadline has passed")]
+    ResolveDeadlinePassed,
+    #[msg("Claim deadline must be after claim start and the dispute window of the resolve deadline")]
+    InvalidClaimDeadline,
+
+    // -------- Stake --------
+    #[msg("Already staked in this challenge")]
+    AlreadyStaked,
+
+    // -------- Claim --------
+    #[msg("Claim period has ended")]
+    ClaimEnded,
+    #[msg("Claim period has not ended")]
+    SweepNotStarted,
+    #[msg("Invalid receipt")]
+    InvalidReceipt,
+    #[msg("Challenge has not been closed")]
+    ChallengeNotClosed,
+    #[msg("Resolution has no winners")]
+    NoWinners,
+    #[msg("Resolution has winners")]
+    HasWinners,
+    #[msg("Not the configured beneficiary")]
+    InvalidBeneficiary,
+    #[msg("Donation failed")]
+    DonationFailed,
+    #[msg("Malformed merkle proof")]
+    InvalidProof,
+    #[msg("Invalid merkle proof index")]
+    InvalidProofIndex,
+    #[msg("Merkle root mismatch")]
+    MerkleRootMismatch,
+    #[msg("Prize has already been claimed")]
+    AlreadyClaimed,
+    #[msg("Payout amount does not match payout mode")]
+    InvalidPayoutAmount,
+    #[msg("Payout exceeds the prize pool")]
+    PrizePoolExceeded,
+    #[msg("Initiator and attestor are required for the last claim")]
+    MissingRentDestination,
+    #[msg("Relayer tip exceeds the cap or the prize")]
+    InvalidRelayerTip,
+    #[msg("Relayer tip needs the winner's ed25519 signature in the preceding instruction")]
+    MissingTipConsent,
+    #[msg("Winner did not sign this relayer tip")]
+    InvalidTipConsent,
+    #[msg("Prizes have already been claimed")]
+    ClaimStarted,
+
+    // -------- Refund --------
+    #[msg("Resolve deadline has not passed")]
+    RefundNotStarted,
+    #[msg("Challenge has already been resolved")]
+    ChallengeAlreadyResolved,
+    #[msg("Refund failed")]
+    RefundFailed,
+
+    // -------- Dispute --------
+    #[msg("Dispute window requires a non-zero bond")]
+    InvalidDisputeConfig,
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+    #[msg("Dispute window has not closed")]
+    DisputeWindowOpen,
+    #[msg("Claims are frozen while a dispute is open")]
+    DisputeOpen,
+    #[msg("Dispute bond transfer failed")]
+    DisputeBondFailed,
+    #[msg("Invalid dispute account")]
+    InvalidDispute,
+    #[msg("Every open dispute must be settled when the resolution is replaced")]
+    DisputesNotSettled,
+
+    // -------- Fee --------
+    #[msg("Fee account not provided")]
+    MissingFeeAccount,
+    #[msg("Fee transfer failed")]
+    FeeTransferFailed,
+
+    // -------- Resolution --------
+    #[msg("Winner list uri is too long")]
+    InvalidWinnerListUri,
 }
\ No newline at end of file
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{self, spl_token_2022::{
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        state::Mint as MintState,
+    }},
+    token_interface::Mint,
+};
+
+use crate::{
+    state::{AllowedMint, CredentialAuthority},
+    error::DesciplineError,
+};
+
+// adds a mint to the registry, or updates its stake limits if already registered
+
+#[derive(Accounts)]
+pub struct AddAllowedMint<'info> {
+  #[account(mut)]
+  pub signer: Signer<'info>,
+
+  #[account(
+    has_one = signer @ DesciplineError::InvalidCredentialAuthority,
+    seeds = [b"authority"],
+    bump = credential_authority.bump
+  )]
+  pub credential_authority: Account<'info, CredentialAuthority>,
+
+  #[account(
+    init_if_needed,
+    payer = signer,
+    seeds = [b"allowed_mint", stake_mint.key().as_ref()],
+    bump,
+    space = 8 + AllowedMint::INIT_SPACE
+  )]
+  pub allowed_mint: Account<'info, AllowedMint>,
+
+  pub stake_mint: InterfaceAccount<'info, Mint>,
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddAllowedMint<'info> {
+  pub fn add_allowed_mint(
+    &mut self,
+    min_stake: u64,
+    max_stake: u64,
+    bumps: &AddAllowedMintBumps,
+  ) -> Result<()> {
+    require!(min_stake > 0 && min_stake <= max_stake, DesciplineError::InvalidStakeAmount);
+    self.verify_mint_extensions()?;
+
+    self.allowed_mint.set_inner(
+      AllowedMint {
+        mint: self.stake_mint.key(),
+        min_stake,
+        max_stake,
+        bump: bumps.allowed_mint
+      }
+    );
+
+    Ok(())
+  }
+
+  // The vault can't forward transfer hook accounts, and a permanent delegate could drain it.
+  fn verify_mint_extensions(&self) -> Result<()> {
+    let mint_info = self.stake_mint.to_account_info();
+    if *mint_info.owner != token_2022::ID {
+      return Ok(());
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    for extension in mint.get_extension_types()? {
+      require!(
+        !matches!(
+          extension,
+          ExtensionType::TransferHook | ExtensionType::PermanentDelegate | ExtensionType::NonTransferable
+        ),
+        DesciplineError::UnsupportedMintExtension
+      );
+    }
+
+    Ok(())
+  }
+}
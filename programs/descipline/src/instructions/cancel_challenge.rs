@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+
+use crate::{
+    state::{Challenge, ChallengeStatus},
+    error::DesciplineError,
+};
+
+use super::shared::ChallengeVault;
+
+// only initiator
+// no stakers, at any time until resolved: dust sent to the vault goes to initiator, vault and challenge closed,
+// rent back to initiator
+// otherwise, before stake_end_at: challenge becomes refund only
+
+#[derive(Accounts)]
+pub struct CancelChallenge<'info> {
+  #[account(mut)]
+  pub initiator: Signer<'info>,
+
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = challenge,
+    associated_token::token_program = token_program,
+    constraint = stake_mint.key() == challenge.stake_mint @ DesciplineError::NotAllowedToken
+  )]
+  pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    seeds = [b"sol_vault", challenge.key().as_ref()],
+    bump = challenge.vault_bump
+  )]
+  pub sol_vault: Option<SystemAccount<'info>>,
+
+  #[account(
+    mut,
+    has_one = initiator,
+    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_bytes()],
+    bump = challenge.bump
+  )]
+  pub challenge: Account<'info, Challenge>,
+
+  // required when tokens were sent to the vault of a challenge nobody staked in
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = initiator,
+    associated_token::token_program = token_program
+  )]
+  pub initiator_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  // receives the transfer fees withheld by the vault when it closes
+  #[account(mut)]
+  pub stake_mint: InterfaceAccount<'info, Mint>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub token_program: Interface<'info, TokenInterface>,
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> CancelChallenge<'info> {
+  pub fn cancel_challenge(&mut self) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    self.challenge.refresh_status(now);
+
+    // stakers recover their funds through refund
+    if self.challenge.participant_count > 0 {
+      require!(now < self.challenge.stake_end_at, DesciplineError::StakeEnded);
+      self.challenge.require_status(ChallengeStatus::Open)?;
+      self.challenge.status = ChallengeStatus::Cancelled;
+      return Ok(());
+    }
+
+    // nobody can stake, refund or win anymore, a resolution is settled by its zero winner policy instead
+    require!(
+      matches!(self.challenge.status, ChallengeStatus::Open | ChallengeStatus::Locked),
+      DesciplineError::InvalidChallengeStatus
+    );
+
+    let vault = ChallengeVault::new(
+      &self.challenge,
+      self.vault.as_ref(),
+      self.sol_vault.as_ref(),
+      &self.stake_mint,
+      &self.token_program,
+      &self.system_program,
+    );
+    // anyone can transfer to the vault, it must not keep the challenge from closing
+    let dust = vault.balance()?;
+    if dust > 0 {
+      let initiator = vault.funds_account(Some(self.initiator.to_account_info()), self.initiator_ata.as_ref())?;
+      vault.pay(&initiator, dust)?;
+    }
+    vault.close(&self.initiator.to_account_info())?;
+
+    self.challenge.status = ChallengeStatus::Closed;
+    self.challenge.close(self.initiator.to_account_info())?;
+
+    Ok(())
+  }
+}
@@ -1,16 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{associated_token::AssociatedToken, token::{Mint, Token, TokenAccount}};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
 
 
 use crate::{
-    state::{Challenge, Receipt, Resolution}, 
-    // interfaces::{SchemaInterface, CredentialInterface},
-    // constants::{TokenAllowed, ATTESTOR_NUMBER}, 
+    state::{Challenge, ClaimedBitmap, CredentialAuthority, Receipt, Resolution}, 
     error::DesciplineError,
-    // utils::{PinocchioVerifier, SchemaValidator}
 };
 
-use super::shared::{transfer_tokens, verify_address};
+use super::shared::{ChallengeVault, WinnerClaim};
+
+// winner with a merkle proof, between claim_start_from and claim_end_at
+// pays the prize net of fee, fees stay in vault until the last claim
+// the last claim settles the fees and closes everything:
+// - protocol share of the fees to treasury, the rest of the vault to initiator
+// - vault and challenge closed to initiator, resolution and claimed bitmap closed to attestor
+// initiator and attestor are only required for the last claim
 
 #[derive(Accounts)]
 pub struct Claim<'info> {
@@ -20,20 +24,30 @@ pub struct Claim<'info> {
   #[account(
     mut,
     associated_token::mint = stake_mint,
-    associated_token::authority = winner
+    associated_token::authority = winner,
+    associated_token::token_program = token_program
   )]
-  pub winner_ata: Account<'info, TokenAccount>,
+  pub winner_ata: Option<InterfaceAccount<'info, TokenAccount>>,
 
   #[account(
     mut,
     associated_token::mint = stake_mint,
     associated_token::authority = challenge,
-    constraint = stake_mint.key() == challenge.token_allowed.mint() @ DesciplineError::NotAllowedToken
+    associated_token::token_program = token_program,
+    constraint = stake_mint.key() == challenge.stake_mint @ DesciplineError::NotAllowedToken
+  )]
+  pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    seeds = [b"sol_vault", challenge.key().as_ref()],
+    bump = challenge.vault_bump
   )]
-  pub vault: Account<'info, TokenAccount>,
+  pub sol_vault: Option<SystemAccount<'info>>,
 
   #[account(
-    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_str().as_bytes()],
+    mut,
+    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_bytes()],
     bump = challenge.bump,
   )]
   pub challenge: Account<'info, Challenge>,
@@ -47,15 +61,69 @@ pub struct Claim<'info> {
 
   #[account(
     mut,
-    close = winner,
+    seeds = [b"claimed", challenge.key().as_ref()],
+    bump = claimed.bump,
+  )]
+  pub claimed: Account<'info, ClaimedBitmap>,
+
+  // closed in handler unless unclaimed prizes may still be swept to winners
+  #[account(
+    mut,
     seeds = [b"receipt", challenge.key().as_ref(), winner.key().as_ref()],
-    bump = receipt.bump
+    bump = receipt.bump,
+    constraint = receipt.challenge_id == challenge.id @ DesciplineError::InvalidReceipt
   )]
   pub receipt: Account<'info, Receipt>,
 
-  pub stake_mint: Account<'info, Mint>,
+  #[account(
+    seeds = [b"authority"],
+    bump = credential_authority.bump
+  )]
+  pub credential_authority: Account<'info, CredentialAuthority>,
+
+  // required when the last claim owes the initiator part of the fee, unallocated stakes or donations
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = challenge.initiator,
+    associated_token::token_program = token_program
+  )]
+  pub initiator_fee_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  // required when the last claim owes the protocol part of the fee
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = credential_authority.treasury,
+    associated_token::token_program = token_program
+  )]
+  pub treasury_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  /// CHECK: receives the protocol fee of native SOL challenges
+  #[account(
+    mut,
+    address = credential_authority.treasury
+  )]
+  pub treasury: Option<UncheckedAccount<'info>>,
+
+  // rent destinations, required for the last claim
+  #[account(
+    mut,
+    address = challenge.initiator @ DesciplineError::InvalidInitiator
+  )]
+  pub initiator: Option<SystemAccount<'info>>,
+
+  #[account(
+    mut,
+    address = resolution.attestor @ DesciplineError::InvalidAttestor
+  )]
+  pub attestor: Option<SystemAccount<'info>>,
+
+  // receives the transfer fees withheld by the vault when it closes
+  #[account(mut)]
+  pub stake_mint: InterfaceAccount<'info, Mint>,
   pub associated_token_program: Program<'info, AssociatedToken>,
-  pub token_program: Program<'info, Token>,
+  pub token_program: Interface<'info, TokenInterface>,
   pub system_program: Program<'info, System>,
 }
 
@@ -63,43 +131,31 @@ impl<'info> Claim<'info> {
   pub fn claim(
     &mut self,
     proof: Vec<u8>, 
-    index: u8
+    index: u32,
+    amount: Option<u64>
   ) -> Result<()> {
-    // time lock
-    // require!(Clock::get()?.unix_timestamp > self.challenge.claim_start_from, DesciplineError::ClaimNotStart);
-
-    require!(self.resolution.winner_notclaim_count > 1, DesciplineError::ShouldCloseChallenge);
-    // verify merkle proof
-    let merkle_root = self.resolution.root_hash;
-
-    verify_address(
-      self.winner.key(),
-      proof,
-      index,
-      merkle_root
-    )?;
-
-    let amount = self.vault.amount.checked_div(self.resolution.winner_notclaim_count as u64).unwrap();
-    let signers_seeds = &[
-      b"challenge", 
-      self.challenge.initiator.as_ref(), 
-      self.challenge.name.as_str().as_bytes(),
-      &[self.challenge.bump]
-    ];
-
-    transfer_tokens(
-      &self.vault,
-      &self.winner_ata,
-      &amount,
-      &self.stake_mint,
-      &self.challenge.to_account_info(),
-      &self.token_program,
-      Some(signers_seeds),
-    )
-    .map_err(|_| DesciplineError::ClaimFailed)?;
-
-  self.resolution.winner_notclaim_count -= 1;
-
-    Ok(())
+    let claim = WinnerClaim {
+      vault: ChallengeVault::new(
+        &self.challenge,
+        self.vault.as_ref(),
+        self.sol_vault.as_ref(),
+        &self.stake_mint,
+        &self.token_program,
+        &self.system_program,
+      ),
+      resolution: &mut self.resolution,
+      claimed: &mut self.claimed,
+      receipt: &mut self.receipt,
+      winner: self.winner.to_account_info(),
+      winner_ata: self.winner_ata.as_ref(),
+      credential_authority: &self.credential_authority,
+      treasury: self.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+      treasury_ata: self.treasury_ata.as_ref(),
+      initiator: self.initiator.as_ref().map(|initiator| initiator.to_account_info()),
+      initiator_fee_ata: self.initiator_fee_ata.as_ref(),
+      attestor: self.attestor.as_ref().map(|attestor| attestor.to_account_info()),
+    };
+
+    claim.claim(proof, index, amount, None)
   }
-}
\ No newline at end of file
+}
@@ -0,0 +1,196 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions as sysvar_instructions};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+
+use crate::{
+    state::{Challenge, ClaimedBitmap, CredentialAuthority, Receipt, Resolution},
+    error::DesciplineError,
+};
+
+use super::shared::{tip_consent_message, verify_tip_consent, ChallengeVault, RelayerTip, WinnerClaim};
+
+// anyone relaying a winner's merkle proof, between claim_start_from and claim_end_at
+// the relayer signs and pays the transaction, the winner does not need to hold SOL
+// pays the prize net of fee to the winner's wallet or token account only,
+// the relayer may keep a tip out of it up to the challenge's max_relayer_tip,
+// only with the winner's ed25519 signature over the tip in the instruction right before
+// fees stay in vault until the last claim
+// the last claim settles the fees and closes everything:
+// - protocol share of the fees to treasury, the rest of the vault to initiator
+// - vault and challenge closed to initiator, resolution and claimed bitmap closed to attestor
+// initiator and attestor are only required for the last claim
+
+#[derive(Accounts)]
+pub struct ClaimFor<'info> {
+  #[account(mut)]
+  pub relayer: Signer<'info>,
+
+  // required when the relayer keeps a tip
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = relayer,
+    associated_token::token_program = token_program
+  )]
+  pub relayer_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  /// CHECK: instructions sysvar holding the winner's consent, required when the relayer keeps a tip
+  #[account(address = sysvar_instructions::ID)]
+  pub instructions: Option<UncheckedAccount<'info>>,
+
+  // gets the prize and the receipt rent
+  #[account(mut)]
+  pub winner: SystemAccount<'info>,
+
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = winner,
+    associated_token::token_program = token_program
+  )]
+  pub winner_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = challenge,
+    associated_token::token_program = token_program,
+    constraint = stake_mint.key() == challenge.stake_mint @ DesciplineError::NotAllowedToken
+  )]
+  pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    seeds = [b"sol_vault", challenge.key().as_ref()],
+    bump = challenge.vault_bump
+  )]
+  pub sol_vault: Option<SystemAccount<'info>>,
+
+  #[account(
+    mut,
+    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_bytes()],
+    bump = challenge.bump,
+  )]
+  pub challenge: Account<'info, Challenge>,
+
+  #[account(
+    mut,
+    seeds = [b"resolution", challenge.key().as_ref()],
+    bump = resolution.bump,
+  )]
+  pub resolution: Account<'info, Resolution>,
+
+  #[account(
+    mut,
+    seeds = [b"claimed", challenge.key().as_ref()],
+    bump = claimed.bump,
+  )]
+  pub claimed: Account<'info, ClaimedBitmap>,
+
+  // closed in handler unless unclaimed prizes may still be swept to winners
+  #[account(
+    mut,
+    seeds = [b"receipt", challenge.key().as_ref(), winner.key().as_ref()],
+    bump = receipt.bump,
+    constraint = receipt.challenge_id == challenge.id @ DesciplineError::InvalidReceipt
+  )]
+  pub receipt: Account<'info, Receipt>,
+
+  #[account(
+    seeds = [b"authority"],
+    bump = credential_authority.bump
+  )]
+  pub credential_authority: Account<'info, CredentialAuthority>,
+
+  // required when the last claim owes the initiator part of the fee, unallocated stakes or donations
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = challenge.initiator,
+    associated_token::token_program = token_program
+  )]
+  pub initiator_fee_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  // required when the last claim owes the protocol part of the fee
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = credential_authority.treasury,
+    associated_token::token_program = token_program
+  )]
+  pub treasury_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  /// CHECK: receives the protocol fee of native SOL challenges
+  #[account(
+    mut,
+    address = credential_authority.treasury
+  )]
+  pub treasury: Option<UncheckedAccount<'info>>,
+
+  // rent destinations, required for the last claim
+  #[account(
+    mut,
+    address = challenge.initiator @ DesciplineError::InvalidInitiator
+  )]
+  pub initiator: Option<SystemAccount<'info>>,
+
+  #[account(
+    mut,
+    address = resolution.attestor @ DesciplineError::InvalidAttestor
+  )]
+  pub attestor: Option<SystemAccount<'info>>,
+
+  // receives the transfer fees withheld by the vault when it closes
+  #[account(mut)]
+  pub stake_mint: InterfaceAccount<'info, Mint>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub token_program: Interface<'info, TokenInterface>,
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimFor<'info> {
+  pub fn claim_for(
+    &mut self,
+    proof: Vec<u8>, 
+    index: u32,
+    amount: Option<u64>,
+    tip: u64
+  ) -> Result<()> {
+    // the winner signs off on the tip, a relayer can not help itself to the cap
+    if tip > 0 {
+      let instructions = self.instructions.as_ref().ok_or(DesciplineError::MissingTipConsent)?;
+      verify_tip_consent(
+        instructions,
+        &self.winner.key(),
+        &tip_consent_message(&self.challenge.key(), &self.relayer.key(), tip)
+      )?;
+    }
+
+    let claim = WinnerClaim {
+      vault: ChallengeVault::new(
+        &self.challenge,
+        self.vault.as_ref(),
+        self.sol_vault.as_ref(),
+        &self.stake_mint,
+        &self.token_program,
+        &self.system_program,
+      ),
+      resolution: &mut self.resolution,
+      claimed: &mut self.claimed,
+      receipt: &mut self.receipt,
+      winner: self.winner.to_account_info(),
+      winner_ata: self.winner_ata.as_ref(),
+      credential_authority: &self.credential_authority,
+      treasury: self.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+      treasury_ata: self.treasury_ata.as_ref(),
+      initiator: self.initiator.as_ref().map(|initiator| initiator.to_account_info()),
+      initiator_fee_ata: self.initiator_fee_ata.as_ref(),
+      attestor: self.attestor.as_ref().map(|attestor| attestor.to_account_info()),
+    };
+
+    claim.claim(proof, index, amount, Some(RelayerTip {
+      relayer: self.relayer.to_account_info(),
+      relayer_ata: self.relayer_ata.as_ref(),
+      amount: tip,
+    }))
+  }
+}
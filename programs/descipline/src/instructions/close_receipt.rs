@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Challenge, Receipt},
+    error::DesciplineError,
+};
+
+// only the staker, once the challenge account has been closed
+// receipts outlive their challenge when the staker did not win, or claimed before the last claim closed it
+// a challenge recreated under the same name does not keep the receipts of the closed one
+// receipt rent back to staker
+
+#[derive(Accounts)]
+pub struct CloseReceipt<'info> {
+  #[account(mut)]
+  pub staker: Signer<'info>,
+
+  /// CHECK: closed or recreated challenge, checked in handler
+  pub challenge: UncheckedAccount<'info>,
+
+  #[account(
+    mut,
+    close = staker,
+    has_one = staker @ DesciplineError::InvalidReceipt,
+    seeds = [b"receipt", challenge.key().as_ref(), staker.key().as_ref()],
+    bump = receipt.bump
+  )]
+  pub receipt: Account<'info, Receipt>,
+}
+
+impl<'info> CloseReceipt<'info> {
+  pub fn close_receipt(&mut self) -> Result<()> {
+    if self.challenge.data_is_empty() {
+      return Ok(());
+    }
+
+    require_keys_eq!(*self.challenge.owner, crate::ID, DesciplineError::ChallengeNotClosed);
+    let challenge = Challenge::try_deserialize(&mut &self.challenge.try_borrow_data()?[..])?;
+    require!(challenge.id != self.receipt.challenge_id, DesciplineError::ChallengeNotClosed);
+
+    Ok(())
+  }
+}
@@ -1,16 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{associated_token::AssociatedToken, token::{Mint, Token, TokenAccount}};
+use anchor_spl::{associated_token::AssociatedToken, token::spl_token::native_mint, token_interface::{Mint, TokenAccount, TokenInterface}};
 
 use crate::{
-    state::{Challenge, CredentialAuthority}, 
+    state::{AllowedMint, Challenge, ChallengeStatus, CredentialAuthority, PayoutMode, ResolutionFields, SweepMode, ZeroWinnerPolicy}, 
     interfaces::{SchemaInterface, CredentialInterface},
-    constants::{TokenAllowed, ATTESTOR_NUMBER}, 
+    constants::{MAX_ATTESTORS, MAX_FEE}, 
     error::DesciplineError,
-    // utils::{PinocchioVerifier, SchemaValidator}
 };
 
+use super::shared::transfer_lamports;
+
 #[derive(Accounts)]
-#[instruction(name: String, token_allowed: TokenAllowed)]
+#[instruction(name: String)]
 pub struct CreateChallenge<'info> {
   #[account(mut)]
   pub initiator: Signer<'info>,
@@ -20,79 +21,166 @@ pub struct CreateChallenge<'info> {
     payer = initiator,
     associated_token::mint = stake_mint,
     associated_token::authority = challenge,
-    constraint = stake_mint.key() == token_allowed.mint() @ DesciplineError::NotAllowedToken
+    associated_token::token_program = token_program
+  )]
+  pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    seeds = [b"sol_vault", challenge.key().as_ref()],
+    bump
   )]
-  pub vault: Account<'info, TokenAccount>,
+  pub sol_vault: Option<SystemAccount<'info>>,
 
   #[account(
     init,
     payer = initiator,
-    seeds = [b"challenge", initiator.key().as_ref(), name.as_str().as_bytes()],
+    seeds = [b"challenge", initiator.key().as_ref(), name.as_bytes()],
     bump,
     space = 8 + Challenge::INIT_SPACE
   )]
   pub challenge: Account<'info, Challenge>,
 
-  /// CHECK: manually verified schema structure
+  /// CHECK: owner and structure verified by SchemaInterface
   pub schema: UncheckedAccount<'info>,
-  /// CHECK: manually verified credential structure
+  /// CHECK: owner and structure verified by CredentialInterface
   pub credential: UncheckedAccount<'info>,
 
   #[account(
+    mut,
     seeds = [b"authority"],
     bump = credential_authority.bump
   )]
   pub credential_authority: Account<'info, CredentialAuthority>,
 
-  pub stake_mint: Account<'info, Mint>,
+  #[account(
+    seeds = [b"allowed_mint", stake_mint.key().as_ref()],
+    bump = allowed_mint.bump
+  )]
+  pub allowed_mint: Account<'info, AllowedMint>,
+
+  pub stake_mint: InterfaceAccount<'info, Mint>,
   pub associated_token_program: Program<'info, AssociatedToken>,
-  pub token_program: Program<'info, Token>,
+  pub token_program: Interface<'info, TokenInterface>,
   pub system_program: Program<'info, System>,
 }
 
 impl<'info> CreateChallenge<'info> {
+  #[allow(clippy::too_many_arguments)]
   pub fn create_challenge(
     &mut self,
     name: String,
-    token_allowed: TokenAllowed,
     stake_amount: u64,
+    allow_top_up: bool,
+    payout_mode: PayoutMode,
     fee: u16,
     stake_end_at: i64,
     claim_start_from: i64,
+    claim_end_at: i64,
+    sweep_mode: SweepMode,
+    zero_winner_policy: ZeroWinnerPolicy,
+    resolve_deadline: i64,
+    dispute_window: i64,
+    dispute_bond: u64,
+    max_relayer_tip: u64,
+    threshold: u8,
+    resolution_fields: ResolutionFields,
     bumps: &CreateChallengeBumps,
   ) -> Result<()> {
+    // stake window must still be open and close before claiming starts
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < stake_end_at, DesciplineError::ChallengeEnded);
+    require!(stake_end_at < claim_start_from, DesciplineError::ChallengeNotStarted);
+    require!(stake_end_at < resolve_deadline, DesciplineError::InvalidResolveDeadline);
+    // a resolution made at the deadline must still be claimable once its dispute window closes
+    require!(
+      claim_start_from < claim_end_at
+        && resolve_deadline.checked_add(dispute_window).is_some_and(|claimable| claimable < claim_end_at),
+      DesciplineError::InvalidClaimDeadline
+    );
+    require!(fee <= MAX_FEE, DesciplineError::InvalidFeePercentage);
+    require!(
+      dispute_window >= 0 && (dispute_window == 0 || dispute_bond > 0),
+      DesciplineError::InvalidDisputeConfig
+    );
+    self.allowed_mint.verify_stake_amount(stake_amount)?;
+
+    // WSOL challenges take native SOL into a sol vault instead of a token account
+    let is_native = self.stake_mint.key() == native_mint::ID;
+    require!(
+      is_native == self.sol_vault.is_some() && is_native != self.vault.is_some(),
+      DesciplineError::InvalidVault
+    );
+
     // Load and verify schema
-    let schema_data = self.schema.try_borrow_data()?;
-    let schema = SchemaInterface::new(&schema_data)?;
+    let schema = SchemaInterface::load(&self.schema)?;
 
-    // Verify schema name matches challenge name
-    schema.verify_name()?;
+    // any schema of the credential works as long as it can carry a resolution
     schema.verify_credential(self.credential.key())?;
+    resolution_fields.verify_layout(&schema.layout)?;
+    // amount leaves are attested with their total, equal payouts allocate the whole pool
+    require!(
+      resolution_fields.prize_allocated.is_some() == (payout_mode == PayoutMode::Amount),
+      DesciplineError::InvalidResolutionFields
+    );
     
     // Extract credential
-    let credential_data = self.credential.try_borrow_data()?;
-    let credential = CredentialInterface::new(&credential_data)?;
+    let credential = CredentialInterface::load(&self.credential)?;
     credential.verify_authority(self.credential_authority.signer)?;
 
-    require!(credential.authorized_signers.len() as u8 == ATTESTOR_NUMBER, DesciplineError::TooManySigners);
-    let attestor = credential.authorized_signers[0]; // simply only allow one signer
-    require!(attestor == self.credential_authority.signer || attestor == self.challenge.initiator, DesciplineError::InvalidAttestor);
+    // signers are curated by the credential authority, any `threshold` of them can resolve
+    let mut attestors = credential.authorized_signers;
+    attestors.sort();
+    attestors.dedup();
+    require!(attestors.len() <= MAX_ATTESTORS, DesciplineError::TooManySigners);
+    require!(threshold > 0 && threshold as usize <= attestors.len(), DesciplineError::InvalidThreshold);
+
+    // receipts of an earlier challenge under the same name must not count for this one
+    let id = self.credential_authority.challenge_count;
+    self.credential_authority.challenge_count += 1;
 
     self.challenge.set_inner(
       Challenge {
         name, 
-        token_allowed,
+        stake_mint: self.stake_mint.key(),
         stake_amount,
+        allow_top_up,
+        payout_mode,
         fee, 
         stake_end_at,
         claim_start_from,
-        attestor,
+        claim_end_at,
+        sweep_mode,
+        zero_winner_policy,
+        resolve_deadline,
+        dispute_window,
+        dispute_bond,
+        max_relayer_tip,
+        participant_count: 0,
+        attestors,
+        threshold,
         initiator: self.initiator.key(), 
+        id,
         schema: self.schema.key(), 
+        credential: self.credential.key(),
+        resolution_fields,
+        status: ChallengeStatus::Open,
+        vault_bump: bumps.sol_vault.unwrap_or_default(),
         bump: bumps.challenge
       }
     );
 
+    // the sol vault stays rent exempt until it is closed
+    if let Some(sol_vault) = &self.sol_vault {
+      transfer_lamports(
+        &self.initiator.to_account_info(),
+        &sol_vault.to_account_info(),
+        &Rent::get()?.minimum_balance(0),
+        &self.system_program,
+        None,
+      )?;
+    }
+
     Ok(())
   }
 }
\ No newline at end of file
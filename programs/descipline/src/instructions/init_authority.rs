@@ -24,6 +24,9 @@ impl<'info> InitAuthority<'info> {
     self.credential_authority.set_inner(
       CredentialAuthority {
         signer: self.signer.key(),
+        treasury: self.signer.key(),
+        protocol_fee: 0,
+        challenge_count: 0,
         bump: bumps.credential_authority
       }
     );
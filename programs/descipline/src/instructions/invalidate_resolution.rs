@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Challenge, ChallengeStatus, ClaimedBitmap, CredentialAuthority, Resolution},
+    error::DesciplineError,
+};
+
+// only credential authority, once one of the resolving attestations was revoked (closed)
+// no prize claimed yet and no dispute open
+// resolution and claimed bitmap closed, rent back to attestor, challenge can be resolved again or refunded
+
+#[derive(Accounts)]
+pub struct InvalidateResolution<'info> {
+  pub signer: Signer<'info>,
+
+  #[account(
+    has_one = signer @ DesciplineError::InvalidCredentialAuthority,
+    seeds = [b"authority"],
+    bump = credential_authority.bump
+  )]
+  pub credential_authority: Account<'info, CredentialAuthority>,
+
+  #[account(
+    mut,
+    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_bytes()],
+    bump = challenge.bump
+  )]
+  pub challenge: Account<'info, Challenge>,
+
+  #[account(
+    mut,
+    close = attestor,
+    seeds = [b"resolution", challenge.key().as_ref()],
+    bump = resolution.bump,
+    constraint = attestor.key() == resolution.attestor @ DesciplineError::InvalidAttestor
+  )]
+  pub resolution: Account<'info, Resolution>,
+
+  #[account(
+    mut,
+    close = attestor,
+    seeds = [b"claimed", challenge.key().as_ref()],
+    bump = claimed.bump,
+  )]
+  pub claimed: Account<'info, ClaimedBitmap>,
+
+  /// CHECK: one of the resolving attestations, must be closed
+  #[account(
+    constraint = resolution.attestations.contains(&attestation.key()) @ DesciplineError::AttestationNotFound
+  )]
+  pub attestation: UncheckedAccount<'info>,
+
+  #[account(mut)]
+  pub attestor: SystemAccount<'info>,
+}
+
+impl<'info> InvalidateResolution<'info> {
+  pub fn invalidate_resolution(&mut self) -> Result<()> {
+    self.challenge.require_status(ChallengeStatus::Resolved)?;
+    require!(
+      self.resolution.winner_notclaim_count == self.resolution.winner_count,
+      DesciplineError::ClaimStarted
+    );
+    require!(self.resolution.open_disputes == 0, DesciplineError::DisputeOpen);
+
+    // revoking closes the attestation account
+    require!(
+      self.attestation.data_is_empty() && self.attestation.lamports() == 0,
+      DesciplineError::AttestationNotRevoked
+    );
+
+    self.challenge.status = ChallengeStatus::Locked;
+
+    Ok(())
+  }
+}
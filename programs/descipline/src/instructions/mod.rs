@@ -1,15 +1,39 @@
 pub mod shared;
 pub mod init_authority;
+pub mod set_protocol_fee;
+pub mod add_allowed_mint;
+pub mod remove_allowed_mint;
 pub mod create_challenge;
 pub mod stake;
 pub mod resolve;
+pub mod invalidate_resolution;
+pub mod open_dispute;
+pub mod uphold_resolution;
+pub mod replace_resolution;
 pub mod claim;
-pub mod claim_and_close;
+pub mod claim_for;
+pub mod sweep;
+pub mod settle_no_winners;
+pub mod close_receipt;
+pub mod refund;
+pub mod cancel_challenge;
 
 pub use shared::*;
 pub use init_authority::*;
+pub use set_protocol_fee::*;
+pub use add_allowed_mint::*;
+pub use remove_allowed_mint::*;
 pub use create_challenge::*;
 pub use stake::*;
 pub use resolve::*;
+pub use invalidate_resolution::*;
+pub use open_dispute::*;
+pub use uphold_resolution::*;
+pub use replace_resolution::*;
 pub use claim::*;
-pub use claim_and_close::*;
\ No newline at end of file
+pub use claim_for::*;
+pub use sweep::*;
+pub use settle_no_winners::*;
+pub use close_receipt::*;
+pub use refund::*;
+pub use cancel_challenge::*;
\ No newline at end of file
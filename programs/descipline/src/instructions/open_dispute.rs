@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+
+use crate::{
+    state::{Challenge, ChallengeStatus, Dispute, Receipt, ReceiptStatus, Resolution},
+    error::DesciplineError,
+};
+
+use super::shared::ChallengeVault;
+
+// only stakers, within dispute_window after resolve
+// bond goes into the vault, claims are frozen until the credential authority settles the dispute
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+  #[account(mut)]
+  pub disputer: Signer<'info>,
+
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = disputer,
+    associated_token::token_program = token_program
+  )]
+  pub disputer_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    seeds = [b"receipt", challenge.key().as_ref(), disputer.key().as_ref()],
+    bump = receipt.bump,
+    constraint = receipt.challenge_id == challenge.id @ DesciplineError::InvalidReceipt
+  )]
+  pub receipt: Account<'info, Receipt>,
+
+  #[account(
+    init,
+    payer = disputer,
+    seeds = [b"dispute", challenge.key().as_ref(), disputer.key().as_ref()],
+    bump,
+    space = 8 + Dispute::INIT_SPACE
+  )]
+  pub dispute: Account<'info, Dispute>,
+
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = challenge,
+    associated_token::token_program = token_program,
+    constraint = stake_mint.key() == challenge.stake_mint @ DesciplineError::NotAllowedToken
+  )]
+  pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    seeds = [b"sol_vault", challenge.key().as_ref()],
+    bump = challenge.vault_bump
+  )]
+  pub sol_vault: Option<SystemAccount<'info>>,
+
+  #[account(
+    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_bytes()],
+    bump = challenge.bump
+  )]
+  pub challenge: Account<'info, Challenge>,
+
+  #[account(
+    mut,
+    seeds = [b"resolution", challenge.key().as_ref()],
+    bump = resolution.bump,
+  )]
+  pub resolution: Account<'info, Resolution>,
+
+  pub stake_mint: InterfaceAccount<'info, Mint>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub token_program: Interface<'info, TokenInterface>,
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> OpenDispute<'info> {
+  pub fn open_dispute(&mut self, bumps: &OpenDisputeBumps) -> Result<()> {
+    self.challenge.require_status(ChallengeStatus::Resolved)?;
+    require!(
+      Clock::get()?.unix_timestamp < self.resolution.resolved_at + self.challenge.dispute_window,
+      DesciplineError::DisputeWindowClosed
+    );
+
+    let vault = ChallengeVault::new(
+      &self.challenge,
+      self.vault.as_ref(),
+      self.sol_vault.as_ref(),
+      &self.stake_mint,
+      &self.token_program,
+      &self.system_program,
+    );
+    let source = vault.funds_account(Some(self.disputer.to_account_info()), self.disputer_ata.as_ref())?;
+    let received = vault.deposit(
+      &source,
+      &self.disputer.to_account_info(),
+      self.challenge.dispute_bond,
+    )
+    .map_err(|_| DesciplineError::DisputeBondFailed)?;
+
+    self.dispute.set_inner(
+      Dispute {
+        disputer: self.disputer.key(),
+        bond: received,
+        bump: bumps.dispute
+      }
+    );
+    self.resolution.open_disputes += 1;
+    self.receipt.status = ReceiptStatus::Disputed;
+
+    Ok(())
+  }
+}
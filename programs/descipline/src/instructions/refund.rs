@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+
+use crate::{
+    state::{Challenge, ChallengeStatus, Receipt},
+    error::DesciplineError,
+};
+
+use super::shared::ChallengeVault;
+
+// challenge is not resolved before resolve_deadline, or cancelled by initiator
+// staker gets the amount the vault received back and closes receipt
+// last refund closes vault and challenge
+
+#[derive(Accounts)]
+pub struct Refund<'info> {
+  #[account(mut)]
+  pub challenger: Signer<'info>,
+
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = challenger,
+    associated_token::token_program = token_program
+  )]
+  pub challenger_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    close = challenger,
+    seeds = [b"receipt", challenge.key().as_ref(), challenger.key().as_ref()],
+    bump = receipt.bump,
+    constraint = receipt.challenge_id == challenge.id @ DesciplineError::InvalidReceipt
+  )]
+  pub receipt: Account<'info, Receipt>,
+
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = challenge,
+    associated_token::token_program = token_program,
+    constraint = stake_mint.key() == challenge.stake_mint @ DesciplineError::NotAllowedToken
+  )]
+  pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    seeds = [b"sol_vault", challenge.key().as_ref()],
+    bump = challenge.vault_bump
+  )]
+  pub sol_vault: Option<SystemAccount<'info>>,
+
+  #[account(
+    mut,
+    has_one = initiator,
+    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_bytes()],
+    bump = challenge.bump
+  )]
+  pub challenge: Account<'info, Challenge>,
+
+  /// CHECK: must not be initialized, checked in handler
+  #[account(
+    seeds = [b"resolution", challenge.key().as_ref()],
+    bump,
+  )]
+  pub resolution: UncheckedAccount<'info>,
+
+  #[account(mut)]
+  pub initiator: SystemAccount<'info>,
+  // receives the transfer fees withheld by the vault when it closes
+  #[account(mut)]
+  pub stake_mint: InterfaceAccount<'info, Mint>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub token_program: Interface<'info, TokenInterface>,
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> Refund<'info> {
+  pub fn refund(&mut self) -> Result<()> {
+    // only once cancelled or the attestor missed the resolve deadline
+    let now = Clock::get()?.unix_timestamp;
+    self.challenge.refresh_status(now);
+    require!(self.resolution.data_is_empty(), DesciplineError::ChallengeAlreadyResolved);
+    if self.challenge.status != ChallengeStatus::Cancelled {
+      self.challenge.require_status(ChallengeStatus::Locked)?;
+      require!(now >= self.challenge.resolve_deadline, DesciplineError::RefundNotStarted);
+    }
+
+    let vault = ChallengeVault::new(
+      &self.challenge,
+      self.vault.as_ref(),
+      self.sol_vault.as_ref(),
+      &self.stake_mint,
+      &self.token_program,
+      &self.system_program,
+    );
+    let challenger = vault.funds_account(Some(self.challenger.to_account_info()), self.challenger_ata.as_ref())?;
+
+    // last staker takes whatever is left so the vault can be closed
+    let is_last = self.challenge.participant_count == 1;
+    let amount = if is_last {
+      vault.balance()?
+    } else {
+      self.receipt.amount
+    };
+
+    vault.pay(&challenger, amount)
+      .map_err(|_| DesciplineError::RefundFailed)?;
+
+    if is_last {
+      vault.close(&self.initiator.to_account_info())?;
+
+      self.challenge.status = ChallengeStatus::Closed;
+      self.challenge.close(self.initiator.to_account_info())?;
+    } else {
+      self.challenge.participant_count -= 1;
+    }
+
+    Ok(())
+  }
+}
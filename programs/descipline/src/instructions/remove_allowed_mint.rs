@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{AllowedMint, CredentialAuthority},
+    error::DesciplineError,
+};
+
+// existing challenges keep their mint, only new challenges are rejected
+
+#[derive(Accounts)]
+pub struct RemoveAllowedMint<'info> {
+  #[account(mut)]
+  pub signer: Signer<'info>,
+
+  #[account(
+    has_one = signer @ DesciplineError::InvalidCredentialAuthority,
+    seeds = [b"authority"],
+    bump = credential_authority.bump
+  )]
+  pub credential_authority: Account<'info, CredentialAuthority>,
+
+  #[account(
+    mut,
+    close = signer,
+    seeds = [b"allowed_mint", allowed_mint.mint.as_ref()],
+    bump = allowed_mint.bump
+  )]
+  pub allowed_mint: Account<'info, AllowedMint>,
+
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> RemoveAllowedMint<'info> {
+  pub fn remove_allowed_mint(&mut self) -> Result<()> {
+    msg!("removed allowed mint: {}", self.allowed_mint.mint);
+
+    Ok(())
+  }
+}
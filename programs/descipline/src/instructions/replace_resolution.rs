@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+
+use crate::{
+    state::{Challenge, ChallengeStatus, ClaimedBitmap, CredentialAuthority, Dispute, Resolution},
+    constants::{MAX_URI_LEN, MAX_WINNER_COUNT},
+    error::DesciplineError,
+};
+
+use super::shared::{ChallengeVault, DisputeRefund};
+
+// only credential authority, the dispute was right
+// resolution gets the corrected winner root, bond back to disputer, dispute rent back to disputer
+// every other open dispute against the replaced root is passed in remaining accounts and settled the same way
+// claims are frozen while disputes are open, so no prize has been paid from the replaced root
+// the corrected root gets a dispute window of its own
+
+#[derive(Accounts)]
+#[instruction(root_hash: [u8; 32], winner_count: u32)]
+pub struct ReplaceResolution<'info> {
+  #[account(mut)]
+  pub signer: Signer<'info>,
+
+  #[account(
+    has_one = signer @ DesciplineError::InvalidCredentialAuthority,
+    seeds = [b"authority"],
+    bump = credential_authority.bump
+  )]
+  pub credential_authority: Account<'info, CredentialAuthority>,
+
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = challenge,
+    associated_token::token_program = token_program,
+    constraint = stake_mint.key() == challenge.stake_mint @ DesciplineError::NotAllowedToken
+  )]
+  pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    seeds = [b"sol_vault", challenge.key().as_ref()],
+    bump = challenge.vault_bump
+  )]
+  pub sol_vault: Option<SystemAccount<'info>>,
+
+  #[account(
+    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_bytes()],
+    bump = challenge.bump
+  )]
+  pub challenge: Account<'info, Challenge>,
+
+  #[account(
+    mut,
+    seeds = [b"resolution", challenge.key().as_ref()],
+    bump = resolution.bump,
+  )]
+  pub resolution: Account<'info, Resolution>,
+
+  // resized for the corrected winner count, the credential authority covers any extra rent
+  #[account(
+    mut,
+    seeds = [b"claimed", challenge.key().as_ref()],
+    bump = claimed.bump,
+    realloc = ClaimedBitmap::space(winner_count),
+    realloc::payer = signer,
+    realloc::zero = false,
+  )]
+  pub claimed: Account<'info, ClaimedBitmap>,
+
+  #[account(
+    mut,
+    close = disputer,
+    has_one = disputer,
+    seeds = [b"dispute", challenge.key().as_ref(), disputer.key().as_ref()],
+    bump = dispute.bump
+  )]
+  pub dispute: Account<'info, Dispute>,
+
+  #[account(mut)]
+  pub disputer: SystemAccount<'info>,
+
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = disputer,
+    associated_token::token_program = token_program
+  )]
+  pub disputer_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  pub stake_mint: InterfaceAccount<'info, Mint>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub token_program: Interface<'info, TokenInterface>,
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReplaceResolution<'info> {
+  pub fn replace_resolution(
+    &mut self,
+    root_hash: [u8; 32],
+    winner_count: u32,
+    winner_list_uri: Vec<u8>,
+    prize_allocated: Option<u64>,
+    disputes: &[AccountInfo<'info>],
+  ) -> Result<()> {
+    self.challenge.require_status(ChallengeStatus::Resolved)?;
+    self.challenge.require_payout_amount(prize_allocated)?;
+    require!(winner_count <= MAX_WINNER_COUNT, DesciplineError::InvalidWinnerCount);
+    require!(winner_count <= self.challenge.participant_count, DesciplineError::TooManyWinners);
+    require!(winner_list_uri.len() <= MAX_URI_LEN, DesciplineError::InvalidWinnerListUri);
+
+    let vault = ChallengeVault::new(
+      &self.challenge,
+      self.vault.as_ref(),
+      self.sol_vault.as_ref(),
+      &self.stake_mint,
+      &self.token_program,
+      &self.system_program,
+    );
+    let disputer = vault.funds_account(Some(self.disputer.to_account_info()), self.disputer_ata.as_ref())?;
+    vault.pay(&disputer, self.dispute.bond)
+      .map_err(|_| DesciplineError::DisputeBondFailed)?;
+    self.resolution.open_disputes -= 1;
+
+    for refund in DisputeRefund::load_all(&self.challenge, &self.token_program, disputes)? {
+      require_keys_neq!(refund.dispute_info.key(), self.dispute.key(), DesciplineError::InvalidDispute);
+      vault.pay(&refund.funds_account, refund.dispute.bond)
+        .map_err(|_| DesciplineError::DisputeBondFailed)?;
+      refund.close_dispute()?;
+      self.resolution.open_disputes -= 1;
+    }
+    // a dispute left open would be upheld against a root that no longer exists
+    require!(self.resolution.open_disputes == 0, DesciplineError::DisputesNotSettled);
+
+    self.resolution.root_hash = root_hash;
+    self.resolution.winner_count = winner_count;
+    self.resolution.winner_notclaim_count = winner_count;
+    self.resolution.winner_list_uri = winner_list_uri;
+    self.resolution.resolved_at = Clock::get()?.unix_timestamp;
+    self.claimed.bits = vec![0; ClaimedBitmap::len(winner_count)];
+    // equal shares follow the corrected winner count
+    let prize_pool = self.resolution.prize_pool;
+    self.resolution.set_prize_pool(prize_pool, prize_allocated)?;
+
+    Ok(())
+  }
+}
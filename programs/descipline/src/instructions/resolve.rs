@@ -1,32 +1,44 @@
 use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
 
 use crate::{
-    state::{Challenge, Resolution}, 
-    interfaces::AttestationInterface,
-    constants::SCHEMA_LAY_OUT, 
+    state::{Challenge, ChallengeStatus, ClaimedBitmap, Resolution}, 
+    interfaces::{AttestationInterface, SchemaInterface},
+    constants::{MAX_URI_LEN, MAX_WINNER_COUNT},
     error::DesciplineError,
 };
 
+use super::shared::ChallengeVault;
 
-// tx signer == attestor in challenge - done
-// attestor in challenge == attestation signer - done
-// store resolution account with merkle root, winner_count, winner_list_uri
+
+// tx signer is one of the attestors in challenge
+// attestations passed as remaining accounts, one per distinct attestor
+// attestations owned by the attestation service, of the challenge's schema and credential, not expired
+// at least threshold attestations, all with the same resolution data
+// resolution values are read through the challenge's field mapping of its schema layout
+// store resolution account with merkle root, winner_count, winner_list_uri and the prize pool snapshot
 
 #[derive(Accounts)]
+#[instruction(winner_count: u32)]
 pub struct Resolve<'info> {
   #[account(
     mut,
-    constraint = attestor.key() == challenge.attestor.key() @ DesciplineError::InvalidAttestor
+    constraint = challenge.attestors.contains(&attestor.key()) @ DesciplineError::InvalidAttestor
   )]
   pub attestor: Signer<'info>,
 
 
   #[account(
-    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_str().as_bytes()],
+    mut,
+    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_bytes()],
     bump = challenge.bump,
   )]
   pub challenge: Account<'info, Challenge>,
 
+  /// CHECK: owner and structure verified by SchemaInterface, layout is read in handler
+  #[account(address = challenge.schema @ DesciplineError::SchemaMismatch)]
+  pub schema: UncheckedAccount<'info>,
+
   #[account(
     init,
     payer = attestor,
@@ -36,45 +48,120 @@ pub struct Resolve<'info> {
   )]
   pub resolution: Account<'info, Resolution>,
 
-  /// CHECK: manually verified attestation structure
-  pub attestation: UncheckedAccount<'info>,
+  #[account(
+    init,
+    payer = attestor,
+    seeds = [b"claimed", challenge.key().as_ref()],
+    bump,
+    space = ClaimedBitmap::space(winner_count)
+  )]
+  pub claimed: Account<'info, ClaimedBitmap>,
+
+  #[account(
+    associated_token::mint = stake_mint,
+    associated_token::authority = challenge,
+    associated_token::token_program = token_program,
+    constraint = stake_mint.key() == challenge.stake_mint @ DesciplineError::NotAllowedToken
+  )]
+  pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    seeds = [b"sol_vault", challenge.key().as_ref()],
+    bump = challenge.vault_bump
+  )]
+  pub sol_vault: Option<SystemAccount<'info>>,
 
+  pub stake_mint: InterfaceAccount<'info, Mint>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub token_program: Interface<'info, TokenInterface>,
   pub system_program: Program<'info, System>,
 }
 
 impl<'info> Resolve<'info> {
   pub fn resolve(
     &mut self,
+    attestation_accounts: &[AccountInfo<'info>],
+    winner_count: u32,
     bumps: &ResolveBumps,
   ) -> Result<()> {
+    // only after the stake window has closed and before stakers can refund
+    let now = Clock::get()?.unix_timestamp;
+    self.challenge.refresh_status(now);
+    self.challenge.require_status(ChallengeStatus::Locked)?;
+    require!(now < self.challenge.resolve_deadline, DesciplineError::ResolveDeadlinePassed);
+
+    let mut attestations: Vec<AttestationInterface> = Vec::with_capacity(attestation_accounts.len());
+    for attestation_account in attestation_accounts {
+      let attestation = AttestationInterface::load(attestation_account)?;
 
-    let attestation_data = self.attestation.try_borrow_data()?;
-    let attestation = AttestationInterface::new(&attestation_data)?;
-    
-    attestation.verify_signer(&self.attestor.key())?;
-    let attestation_fields = attestation.verify_layout_and_parse(SCHEMA_LAY_OUT.to_vec()).unwrap();
+      attestation.verify_expiry(now)?;
+      attestation.verify_schema(self.challenge.schema)?;
+      attestation.verify_credential(self.challenge.credential)?;
+      attestation.verify_signers(&self.challenge.attestors)?;
+      require!(
+        attestations.iter().all(|attested| attested.signer != attestation.signer),
+        DesciplineError::DuplicateAttestation
+      );
+      if let Some(first) = attestations.first() {
+        require!(first.data == attestation.data, DesciplineError::ResolutionMismatch);
+      }
+      attestations.push(attestation);
+    }
+    require!(attestations.len() >= self.challenge.threshold as usize, DesciplineError::ThresholdNotMet);
+
+    // the resolution is read from wherever the challenge mapped it in the schema layout
+    let schema = SchemaInterface::load(&self.schema)?;
+    let resolution_fields = self.challenge.resolution_fields;
+    resolution_fields.verify_layout(&schema.layout)?;
 
-    let mut raw_bytes: [u8; 36] = attestation_fields[0].clone().try_into().unwrap();
-    let challenge_bytes: &[u8] = &raw_bytes[4..];
-    require!(challenge_bytes == self.challenge.key().as_ref(), DesciplineError::ChallengeMismatch);
-    
-    raw_bytes = attestation_fields[1].clone().try_into().unwrap();
-    let root_hash: [u8; 32] = raw_bytes[4..].try_into().unwrap();// [4..].try_into().unwrap();
-    // root_hash = root_hash[4:];
-    let winner_count: u8 =  attestation_fields[2][0];
-    let winner_list_uri = attestation_fields[3].clone(); // first 4 bytes are length
+    let attestation_fields = attestations[0].verify_layout_and_parse(schema.layout)?;
+    let resolution = resolution_fields.read(&attestation_fields)?;
+    require!(resolution.challenge == self.challenge.key(), DesciplineError::ChallengeMismatch);
+    // the claimed bitmap was sized from the winner count passed in
+    require!(resolution.winner_count == winner_count, DesciplineError::ResolutionMismatch);
+    require!(winner_count <= MAX_WINNER_COUNT, DesciplineError::InvalidWinnerCount);
+    // every winner holds a receipt, more winners than stakers would leave shares nobody can claim
+    require!(winner_count <= self.challenge.participant_count, DesciplineError::TooManyWinners);
+    require!(resolution.winner_list_uri.len() <= MAX_URI_LEN, DesciplineError::InvalidWinnerListUri);
 
-    // verify(root_hash, );
+    let prize_pool = ChallengeVault::new(
+      &self.challenge,
+      self.vault.as_ref(),
+      self.sol_vault.as_ref(),
+      &self.stake_mint,
+      &self.token_program,
+      &self.system_program,
+    ).balance()?;
 
     self.resolution.set_inner(
       Resolution {
-        root_hash,
-        winner_count,
-        winner_notclaim_count: winner_count,
-        winner_list_uri,
+        root_hash: resolution.root_hash,
+        winner_count: resolution.winner_count,
+        winner_notclaim_count: resolution.winner_count,
+        winner_list_uri: resolution.winner_list_uri,
+        prize_pool: 0,
+        prize_allocated: 0,
+        prize_share: 0,
+        prize_paid: 0,
+        fee_accrued: 0,
+        attestor: self.attestor.key(),
+        attestations: attestation_accounts.iter().map(|account| account.key()).collect(),
+        resolved_at: now,
+        open_disputes: 0,
+        swept_count: 0,
         bump: bumps.resolution
       }
     );
+    // prizes are fixed now, whatever the vault receives later does not change them
+    self.resolution.set_prize_pool(prize_pool, resolution.prize_allocated)?;
+    self.claimed.set_inner(
+      ClaimedBitmap {
+        bits: vec![0; ClaimedBitmap::len(winner_count)],
+        bump: bumps.claimed
+      }
+    );
+    self.challenge.status = ChallengeStatus::Resolved;
+
     Ok(())
   }
 }
\ No newline at end of file
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::CredentialAuthority,
+    constants::MAX_FEE,
+    error::DesciplineError,
+};
+
+#[derive(Accounts)]
+pub struct SetProtocolFee<'info> {
+  pub signer: Signer<'info>,
+
+  #[account(
+    mut,
+    has_one = signer @ DesciplineError::InvalidCredentialAuthority,
+    seeds = [b"authority"],
+    bump = credential_authority.bump
+  )]
+  pub credential_authority: Account<'info, CredentialAuthority>,
+}
+
+impl<'info> SetProtocolFee<'info> {
+  pub fn set_protocol_fee(&mut self, treasury: Pubkey, protocol_fee: u16) -> Result<()> {
+    require!(protocol_fee <= MAX_FEE, DesciplineError::InvalidFeePercentage);
+
+    self.credential_authority.treasury = treasury;
+    self.credential_authority.protocol_fee = protocol_fee;
+
+    Ok(())
+  }
+}
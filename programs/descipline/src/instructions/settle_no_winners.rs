@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+
+use crate::{
+    state::{Challenge, ChallengeStatus, ClaimedBitmap, Resolution, ZeroWinnerPolicy},
+    error::DesciplineError,
+};
+
+use super::shared::{ChallengeVault, StakerPayout};
+
+// anyone, once a resolution without winners can no longer be disputed
+// the vault is settled by the challenge's zero_winner_policy:
+// - Refund: every staker gets its receipt amount back, receipts are passed in remaining accounts
+//   and may be split over several calls, the one refunding the last staker closes the challenge
+// - Initiator: the whole vault goes to the initiator
+// - Donate: the whole vault goes to the beneficiary
+// vault and challenge closed to initiator, resolution and claimed bitmap closed to attestor, receipts to stakers
+
+#[derive(Accounts)]
+pub struct SettleNoWinners<'info> {
+  pub signer: Signer<'info>,
+
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = challenge,
+    associated_token::token_program = token_program,
+    constraint = stake_mint.key() == challenge.stake_mint @ DesciplineError::NotAllowedToken
+  )]
+  pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    seeds = [b"sol_vault", challenge.key().as_ref()],
+    bump = challenge.vault_bump
+  )]
+  pub sol_vault: Option<SystemAccount<'info>>,
+
+  #[account(
+    mut,
+    has_one = initiator,
+    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_bytes()],
+    bump = challenge.bump,
+  )]
+  pub challenge: Account<'info, Challenge>,
+
+  #[account(
+    mut,
+    seeds = [b"resolution", challenge.key().as_ref()],
+    bump = resolution.bump,
+    constraint = attestor.key() == resolution.attestor @ DesciplineError::InvalidAttestor
+  )]
+  pub resolution: Account<'info, Resolution>,
+
+  #[account(
+    mut,
+    seeds = [b"claimed", challenge.key().as_ref()],
+    bump = claimed.bump,
+  )]
+  pub claimed: Account<'info, ClaimedBitmap>,
+
+  // required when the initiator is owed the vault or donations after resolve
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = initiator,
+    associated_token::token_program = token_program
+  )]
+  pub initiator_fee_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  /// CHECK: receives the donation of native SOL challenges, checked in handler
+  #[account(mut)]
+  pub beneficiary: Option<UncheckedAccount<'info>>,
+
+  // receives the donation of token challenges, checked in handler
+  #[account(mut)]
+  pub beneficiary_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(mut)]
+  pub initiator: SystemAccount<'info>,
+  #[account(mut)]
+  pub attestor: SystemAccount<'info>,
+  // receives the transfer fees withheld by the vault when it closes
+  #[account(mut)]
+  pub stake_mint: InterfaceAccount<'info, Mint>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub token_program: Interface<'info, TokenInterface>,
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> SettleNoWinners<'info> {
+  pub fn settle_no_winners(&mut self, receipts: &[AccountInfo<'info>]) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    self.challenge.require_status(ChallengeStatus::Resolved)?;
+    require!(self.resolution.winner_count == 0, DesciplineError::HasWinners);
+    // a dispute may still replace the resolution with actual winners
+    self.resolution.require_claimable(now, self.challenge.dispute_window)?;
+
+    match self.challenge.zero_winner_policy {
+      ZeroWinnerPolicy::Refund => {
+        self.refund_stakers(receipts)?;
+        if self.resolution.swept_count < self.challenge.participant_count {
+          return Ok(());
+        }
+      }
+      ZeroWinnerPolicy::Initiator => {}
+      ZeroWinnerPolicy::Donate { beneficiary } => self.donate(beneficiary)?,
+    }
+
+    self.close_challenge()
+  }
+
+  fn refund_stakers(&mut self, receipts: &[AccountInfo<'info>]) -> Result<()> {
+    let vault = ChallengeVault::new(
+      &self.challenge,
+      self.vault.as_ref(),
+      self.sol_vault.as_ref(),
+      &self.stake_mint,
+      &self.token_program,
+      &self.system_program,
+    );
+    for staker in StakerPayout::load_all(&self.challenge, &self.token_program, receipts)? {
+      vault.pay(&staker.funds_account, staker.receipt.amount)
+        .map_err(|_| DesciplineError::RefundFailed)?;
+      staker.close_receipt()?;
+      self.resolution.swept_count += 1;
+    }
+
+    Ok(())
+  }
+
+  fn donate(&self, beneficiary: Pubkey) -> Result<()> {
+    // native SOL goes to the beneficiary wallet, tokens to a token account of the beneficiary
+    let destination = if self.challenge.is_native() {
+      let wallet = self.beneficiary.as_ref().ok_or(DesciplineError::MissingTokenAccount)?;
+      require_keys_eq!(wallet.key(), beneficiary, DesciplineError::InvalidBeneficiary);
+      wallet.to_account_info()
+    } else {
+      let token_account = self.beneficiary_ata.as_ref().ok_or(DesciplineError::MissingTokenAccount)?;
+      require!(
+        token_account.owner == beneficiary && token_account.mint == self.challenge.stake_mint,
+        DesciplineError::InvalidBeneficiary
+      );
+      token_account.to_account_info()
+    };
+
+    let vault = ChallengeVault::new(
+      &self.challenge,
+      self.vault.as_ref(),
+      self.sol_vault.as_ref(),
+      &self.stake_mint,
+      &self.token_program,
+      &self.system_program,
+    );
+    let amount = vault.balance()?;
+    if amount == 0 {
+      return Ok(());
+    }
+    vault.pay(&destination, amount)
+      .map_err(|_| DesciplineError::DonationFailed.into())
+  }
+
+  fn close_challenge(&mut self) -> Result<()> {
+    self.challenge.status = ChallengeStatus::Closed;
+
+    // the whole vault unless refunded or donated, donations after resolve in any case
+    let vault = ChallengeVault::new(
+      &self.challenge,
+      self.vault.as_ref(),
+      self.sol_vault.as_ref(),
+      &self.stake_mint,
+      &self.token_program,
+      &self.system_program,
+    );
+    vault.close_resolved(
+      &self.resolution,
+      &self.claimed,
+      &self.initiator.to_account_info(),
+      self.initiator_fee_ata.as_ref(),
+      &self.attestor.to_account_info()
+    )
+  }
+}
@@ -1,23 +1,41 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    solana_program::{ed25519_program, program::invoke, sysvar::instructions::get_instruction_relative},
+    system_program,
+};
 
-use anchor_spl::token::{
-    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, Token,
-    TransferChecked,
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::{instruction::harvest_withheld_tokens_to_mint, TransferFeeAmount},
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+    },
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
 };
 
 use svm_merkle_tree::{HashingAlgorithm, MerkleProof};
 
-use crate::error::DesciplineError;
+use crate::{
+    state::{Challenge, ChallengeStatus, ClaimedBitmap, CredentialAuthority, Dispute, Receipt, Resolution, SweepMode},
+    constants::{MAX_PROOF_DEPTH, TIP_CONSENT_DOMAIN},
+    error::DesciplineError,
+    interfaces::AccountReader,
+};
 
 // Transfer tokens from one account to another
 // If transferring from a token account owned by a PDA, owning_pda_seeds must be provided.
 pub fn transfer_tokens<'info>(
-    from: &Account<'info, TokenAccount>,
-    to: &Account<'info, TokenAccount>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
     amount: &u64,
-    mint: &Account<'info, Mint>,
+    mint: &InterfaceAccount<'info, Mint>,
     authority: &AccountInfo<'info>,
-    token_program: &Program<'info, Token>,
+    token_program: &Interface<'info, TokenInterface>,
     owning_pda_seeds: Option<&[&[u8]]>,
 ) -> Result<()> {
     let transfer_accounts = TransferChecked {
@@ -50,10 +68,10 @@ pub fn transfer_tokens<'info>(
 // Close a token account and send the rent to the specified destination
 // If the token account is owned by a PDA, owning_pda_seeds must be provided.
 pub fn close_token_account<'info>(
-    token_account: &Account<'info, TokenAccount>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
     destination: &AccountInfo<'info>,
     authority: &AccountInfo<'info>,
-    token_program: &Program<'info, Token>,
+    token_program: &Interface<'info, TokenInterface>,
     owning_pda_seeds: Option<&[&[u8]]>,
 ) -> Result<()> {
     let close_accounts = CloseAccount {
@@ -72,22 +90,606 @@ pub fn close_token_account<'info>(
     })
 }
 
+// Transfer lamports from a system owned account to another
+// If transferring from a PDA, owning_pda_seeds must be provided.
+pub fn transfer_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: &u64,
+    system_program: &Program<'info, System>,
+    owning_pda_seeds: Option<&[&[u8]]>,
+) -> Result<()> {
+    let transfer_accounts = system_program::Transfer {
+        from: from.to_account_info(),
+        to: to.to_account_info(),
+    };
+
+    let signers_seeds_bytes = owning_pda_seeds.map(|seeds| [seeds]);
+
+    system_program::transfer(
+        if let Some(seeds_arr) = signers_seeds_bytes.as_ref() {
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                transfer_accounts,
+                seeds_arr,
+            )
+        } else {
+            CpiContext::new(system_program.to_account_info(), transfer_accounts)
+        },
+        *amount,
+    )
+}
+
+// The stakes of a challenge: lamports in a system owned PDA for native SOL challenges,
+// tokens in the challenge's associated token account otherwise.
+pub struct ChallengeVault<'a, 'info> {
+    challenge: &'a Account<'info, Challenge>,
+    vault: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    sol_vault: Option<&'a SystemAccount<'info>>,
+    stake_mint: &'a InterfaceAccount<'info, Mint>,
+    token_program: &'a Interface<'info, TokenInterface>,
+    system_program: &'a Program<'info, System>,
+}
+
+impl<'a, 'info> ChallengeVault<'a, 'info> {
+    pub fn new(
+        challenge: &'a Account<'info, Challenge>,
+        vault: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+        sol_vault: Option<&'a SystemAccount<'info>>,
+        stake_mint: &'a InterfaceAccount<'info, Mint>,
+        token_program: &'a Interface<'info, TokenInterface>,
+        system_program: &'a Program<'info, System>,
+    ) -> Self {
+        Self { challenge, vault, sol_vault, stake_mint, token_program, system_program }
+    }
+
+    fn vault_info(&self) -> Result<AccountInfo<'info>> {
+        let vault = if self.challenge.is_native() {
+            self.sol_vault.map(|vault| vault.to_account_info())
+        } else {
+            self.vault.map(|vault| vault.to_account_info())
+        };
+        vault.ok_or(DesciplineError::InvalidVault.into())
+    }
+
+    // Native SOL moves to and from the wallet itself, tokens through its token account.
+    pub fn funds_account(
+        &self,
+        wallet: Option<AccountInfo<'info>>,
+        token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    ) -> Result<AccountInfo<'info>> {
+        let account = if self.challenge.is_native() {
+            wallet
+        } else {
+            token_account.map(|account| account.to_account_info())
+        };
+        account.ok_or(DesciplineError::MissingTokenAccount.into())
+    }
+
+    pub fn balance_of(&self, account: &AccountInfo<'info>) -> Result<u64> {
+        if self.challenge.is_native() {
+            return Ok(account.lamports());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+    }
+
+    // Stakes available for payouts, the sol vault keeps its rent exempt reserve until closed.
+    pub fn balance(&self) -> Result<u64> {
+        let balance = self.balance_of(&self.vault_info()?)?;
+        if self.challenge.is_native() {
+            return Ok(balance.saturating_sub(Rent::get()?.minimum_balance(0)));
+        }
+        Ok(balance)
+    }
+
+    // Returns the amount received by the vault, mints with a transfer fee deliver less than `amount`.
+    pub fn deposit(
+        &self,
+        from: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<u64> {
+        let vault = self.vault_info()?;
+        if self.challenge.is_native() {
+            transfer_lamports(from, &vault, &amount, self.system_program, None)?;
+            return Ok(amount);
+        }
+
+        let balance = self.balance_of(&vault)?;
+        transfer_tokens(
+            from,
+            &vault,
+            &amount,
+            self.stake_mint,
+            authority,
+            self.token_program,
+            None,
+        )?;
+        Ok(self.balance_of(&vault)? - balance)
+    }
+
+    pub fn pay(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        let vault = self.vault_info()?;
+        if self.challenge.is_native() {
+            let challenge_key = self.challenge.key();
+            let signers_seeds: &[&[u8]] = &[
+                b"sol_vault",
+                challenge_key.as_ref(),
+                &[self.challenge.vault_bump]
+            ];
+            return transfer_lamports(&vault, to, &amount, self.system_program, Some(signers_seeds));
+        }
+
+        let signers_seeds: &[&[u8]] = &[
+            b"challenge",
+            self.challenge.initiator.as_ref(),
+            self.challenge.name.as_bytes(),
+            &[self.challenge.bump]
+        ];
+        transfer_tokens(
+            &vault,
+            to,
+            &amount,
+            self.stake_mint,
+            &self.challenge.to_account_info(),
+            self.token_program,
+            Some(signers_seeds),
+        )
+    }
+
+    // The vault must not hold any tokens anymore, a sol vault hands its remaining lamports to `destination`.
+    pub fn close(&self, destination: &AccountInfo<'info>) -> Result<()> {
+        if self.challenge.is_native() {
+            let lamports = self.vault_info()?.lamports();
+            return self.pay(destination, lamports);
+        }
+
+        let vault = self.vault.ok_or(DesciplineError::InvalidVault)?;
+        self.harvest_withheld_fees(vault)?;
+
+        let signers_seeds: &[&[u8]] = &[
+            b"challenge",
+            self.challenge.initiator.as_ref(),
+            self.challenge.name.as_bytes(),
+            &[self.challenge.bump]
+        ];
+        close_token_account(
+            vault,
+            destination,
+            &self.challenge.to_account_info(),
+            self.token_program,
+            Some(signers_seeds),
+        )
+    }
+
+    // Fees and leftovers go to the wallet itself for native SOL challenges, to its token account otherwise.
+    pub fn pay_fee(
+        &self,
+        wallet: Option<AccountInfo<'info>>,
+        fee_ata: Option<&InterfaceAccount<'info, TokenAccount>>,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let destination = self.funds_account(wallet, fee_ata)
+            .map_err(|_| DesciplineError::MissingFeeAccount)?;
+
+        self.pay(&destination, amount)
+            .map_err(|_| DesciplineError::FeeTransferFailed.into())
+    }
+
+    // Closes a resolved challenge once its payouts are settled: the rest of the vault, vault and challenge rent
+    // go to the initiator, resolution and claimed bitmap rent back to the attestor.
+    pub fn close_resolved(
+        &self,
+        resolution: &Account<'info, Resolution>,
+        claimed: &Account<'info, ClaimedBitmap>,
+        initiator: &AccountInfo<'info>,
+        initiator_fee_ata: Option<&InterfaceAccount<'info, TokenAccount>>,
+        attestor: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let rest = self.balance()?;
+        self.pay_fee(Some(initiator.clone()), initiator_fee_ata, rest)?;
+        self.close(initiator)?;
+
+        self.challenge.close(initiator.clone())?;
+        resolution.close(attestor.clone())?;
+        claimed.close(attestor.clone())
+    }
+
+    // Token-2022 refuses to close an account still withholding transfer fees, harvesting is
+    // permissionless and hands them to the mint where the withdraw authority collects them.
+    fn harvest_withheld_fees(&self, vault: &InterfaceAccount<'info, TokenAccount>) -> Result<()> {
+        let vault = vault.to_account_info();
+        if vault.owner != &spl_token_2022::ID {
+            return Ok(());
+        }
+        let withheld = {
+            let data = vault.try_borrow_data()?;
+            let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+            account
+                .get_extension::<TransferFeeAmount>()
+                .map_or(0, |fee| u64::from(fee.withheld_amount))
+        };
+        if withheld == 0 {
+            return Ok(());
+        }
+
+        let harvest = harvest_withheld_tokens_to_mint(
+            self.token_program.key,
+            &self.stake_mint.key(),
+            &[vault.key],
+        )?;
+        invoke(
+            &harvest,
+            &[
+                self.stake_mint.to_account_info(),
+                vault,
+                self.token_program.to_account_info(),
+            ],
+        )
+        .map_err(Into::into)
+    }
+}
+
+// A relayer's cut of the prize it claims for a winner.
+pub struct RelayerTip<'a, 'info> {
+    pub relayer: AccountInfo<'info>,
+    pub relayer_ata: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub amount: u64,
+}
+
+// The claim of a winner proven by its merkle leaf, made by the winner itself or by a relayer.
+// Initiator, attestor and the fee accounts are only used by the last claim, which closes the challenge.
+pub struct WinnerClaim<'a, 'info> {
+    pub vault: ChallengeVault<'a, 'info>,
+    pub resolution: &'a mut Account<'info, Resolution>,
+    pub claimed: &'a mut Account<'info, ClaimedBitmap>,
+    pub receipt: &'a mut Account<'info, Receipt>,
+    pub winner: AccountInfo<'info>,
+    pub winner_ata: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub credential_authority: &'a Account<'info, CredentialAuthority>,
+    pub treasury: Option<AccountInfo<'info>>,
+    pub treasury_ata: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub initiator: Option<AccountInfo<'info>>,
+    pub initiator_fee_ata: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub attestor: Option<AccountInfo<'info>>,
+}
+
+impl<'a, 'info> WinnerClaim<'a, 'info> {
+    pub fn claim(
+        self,
+        proof: Vec<u8>,
+        index: u32,
+        amount: Option<u64>,
+        tip: Option<RelayerTip<'a, 'info>>,
+    ) -> Result<()> {
+        let challenge = self.vault.challenge;
+
+        // time lock
+        let now = Clock::get()?.unix_timestamp;
+        challenge.require_claim_window(now)?;
+        challenge.require_status(ChallengeStatus::Resolved)?;
+        self.resolution.require_claimable(now, challenge.dispute_window)?;
+
+        // verify merkle proof
+        challenge.require_payout_amount(amount)?;
+        verify_leaf(
+            &winner_leaf(self.winner.key(), amount),
+            proof,
+            index,
+            self.resolution.winner_count,
+            self.resolution.root_hash,
+        )?;
+
+        // prizes come from the pool snapshot taken at resolve
+        let is_last = self.resolution.is_last_claim();
+        let payout = self.resolution.prize_of(amount)?;
+        let fee = challenge.fee_of(payout);
+        let tip_amount = tip.as_ref().map_or(0, |tip| tip.amount);
+        challenge.verify_relayer_tip(tip_amount, payout, fee)?;
+
+        // the receipt weighs this claimer's share of a sweep to winners
+        self.receipt.record_claim(payout)?;
+        self.claimed.claim(index)?;
+
+        let winner = self.vault.funds_account(Some(self.winner.clone()), self.winner_ata)?;
+        self.vault.pay(&winner, payout - fee - tip_amount)
+            .map_err(|_| DesciplineError::ClaimFailed)?;
+        if let Some(tip) = tip.filter(|tip| tip.amount > 0) {
+            let relayer = self.vault.funds_account(Some(tip.relayer), tip.relayer_ata)?;
+            self.vault.pay(&relayer, tip.amount)
+                .map_err(|_| DesciplineError::ClaimFailed)?;
+        }
+
+        self.resolution.record_claim(payout, fee);
+
+        if is_last {
+            self.receipt.close(self.winner.clone())?;
+            return self.close_challenge();
+        }
+
+        if challenge.sweep_mode != SweepMode::Winners {
+            self.receipt.close(self.winner.clone())?;
+        }
+
+        Ok(())
+    }
+
+    fn close_challenge(&self) -> Result<()> {
+        let (Some(initiator), Some(attestor)) = (&self.initiator, &self.attestor) else {
+            return err!(DesciplineError::MissingRentDestination);
+        };
+
+        // settle all fees accrued over the claims, the initiator's fee share goes with the rest of the vault
+        // along with stakes no amount leaf was allocated and donations after resolve
+        let protocol_fee = self.credential_authority.protocol_share(self.resolution.fee_accrued);
+        self.vault.pay_fee(self.treasury.clone(), self.treasury_ata, protocol_fee)?;
+
+        self.vault.close_resolved(self.resolution, self.claimed, initiator, self.initiator_fee_ata, attestor)
+    }
+}
+
+// What a winner signs to let `relayer` keep `tip` out of its prize in `challenge`,
+// naming the relayer keeps anyone copying the signature from taking the tip.
+pub fn tip_consent_message(challenge: &Pubkey, relayer: &Pubkey, tip: u64) -> Vec<u8> {
+    [TIP_CONSENT_DOMAIN, challenge.as_ref(), relayer.as_ref(), &tip.to_le_bytes()].concat()
+}
+
+// The instruction before the claim must be an ed25519 program instruction checking a single
+// signature of `winner` over `message`. The runtime fails the transaction on a bad signature,
+// so its presence with the expected key and message is the consent.
+pub fn verify_tip_consent(instructions: &AccountInfo, winner: &Pubkey, message: &[u8]) -> Result<()> {
+    let consent = get_instruction_relative(-1, instructions)
+        .map_err(|_| DesciplineError::MissingTipConsent)?;
+    require_keys_eq!(consent.program_id, ed25519_program::ID, DesciplineError::MissingTipConsent);
+
+    let mut reader = AccountReader::new(&consent.data, DesciplineError::InvalidTipConsent);
+    require!(reader.read_u8()? == 1, DesciplineError::InvalidTipConsent);
+    reader.read_u8()?; // padding
+    reader.read_u16()?; // signature offset, checked by the ed25519 program
+    let signature_instruction = reader.read_u16()?;
+    let public_key_offset = reader.read_u16()? as usize;
+    let public_key_instruction = reader.read_u16()?;
+    let message_offset = reader.read_u16()? as usize;
+    let message_size = reader.read_u16()? as usize;
+    let message_instruction = reader.read_u16()?;
+
+    // u16::MAX points into the ed25519 instruction itself, anything else could verify other data
+    require!(
+        [signature_instruction, public_key_instruction, message_instruction].iter().all(|&index| index == u16::MAX),
+        DesciplineError::InvalidTipConsent
+    );
+    let signer = consent.data.get(public_key_offset..public_key_offset + 32)
+        .ok_or(DesciplineError::InvalidTipConsent)?;
+    let signed = consent.data.get(message_offset..message_offset + message_size)
+        .ok_or(DesciplineError::InvalidTipConsent)?;
+    require!(signer == winner.as_ref() && signed == message, DesciplineError::InvalidTipConsent);
+
+    Ok(())
+}
+
+// A receipt passed in remaining accounts as [receipt, staker], followed by the staker's
+// token account for token challenges, so batched payouts reach the staker and nobody else.
+pub struct StakerPayout<'info> {
+    pub receipt: Receipt,
+    pub receipt_info: AccountInfo<'info>,
+    pub staker: AccountInfo<'info>,
+    pub funds_account: AccountInfo<'info>,
+}
+
+impl<'info> StakerPayout<'info> {
+    pub fn load_all(
+        challenge: &Account<'info, Challenge>,
+        token_program: &Interface<'info, TokenInterface>,
+        accounts: &[AccountInfo<'info>],
+    ) -> Result<Vec<Self>> {
+        let payouts = remaining_chunks(challenge, accounts, DesciplineError::InvalidReceipt)?
+            .map(|chunk| Self::load(challenge, token_program, chunk))
+            .collect::<Result<Vec<Self>>>()?;
+        // every receipt is paid once, a repeated one would still look open until the batch closes it
+        require_distinct(payouts.iter().map(|payout| payout.receipt_info.key), DesciplineError::InvalidReceipt)?;
+        Ok(payouts)
+    }
+
+    fn load(
+        challenge: &Account<'info, Challenge>,
+        token_program: &Interface<'info, TokenInterface>,
+        chunk: &[AccountInfo<'info>],
+    ) -> Result<Self> {
+        let (receipt_info, staker) = (&chunk[0], &chunk[1]);
+        // a receipt closed by an earlier batch is owned by the system program again
+        require_keys_eq!(*receipt_info.owner, crate::ID, DesciplineError::InvalidReceipt);
+        let receipt = Receipt::try_deserialize(&mut &receipt_info.try_borrow_data()?[..])?;
+
+        let challenge_key = challenge.key();
+        let address = Pubkey::create_program_address(
+            &[b"receipt", challenge_key.as_ref(), staker.key.as_ref(), &[receipt.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| DesciplineError::InvalidReceipt)?;
+        require_keys_eq!(address, receipt_info.key(), DesciplineError::InvalidReceipt);
+        require_keys_eq!(receipt.staker, staker.key(), DesciplineError::InvalidReceipt);
+        // left behind by an earlier challenge at the same address
+        require!(receipt.challenge_id == challenge.id, DesciplineError::InvalidReceipt);
+
+        Ok(Self {
+            funds_account: owned_funds_account(challenge, token_program, chunk, DesciplineError::InvalidReceipt)?,
+            receipt,
+            receipt_info: receipt_info.clone(),
+            staker: staker.clone(),
+        })
+    }
+
+    // Receipt rent goes back to the staker.
+    pub fn close_receipt(&self) -> Result<()> {
+        close_remaining_account(&self.receipt_info, &self.staker)
+    }
+}
+
+// A dispute passed in remaining accounts as [dispute, disputer], followed by the disputer's
+// token account for token challenges, so batched bond refunds reach the disputer and nobody else.
+pub struct DisputeRefund<'info> {
+    pub dispute: Dispute,
+    pub dispute_info: AccountInfo<'info>,
+    pub disputer: AccountInfo<'info>,
+    pub funds_account: AccountInfo<'info>,
+}
+
+impl<'info> DisputeRefund<'info> {
+    pub fn load_all(
+        challenge: &Account<'info, Challenge>,
+        token_program: &Interface<'info, TokenInterface>,
+        accounts: &[AccountInfo<'info>],
+    ) -> Result<Vec<Self>> {
+        let refunds = remaining_chunks(challenge, accounts, DesciplineError::InvalidDispute)?
+            .map(|chunk| Self::load(challenge, token_program, chunk))
+            .collect::<Result<Vec<Self>>>()?;
+        require_distinct(refunds.iter().map(|refund| refund.dispute_info.key), DesciplineError::InvalidDispute)?;
+        Ok(refunds)
+    }
+
+    fn load(
+        challenge: &Account<'info, Challenge>,
+        token_program: &Interface<'info, TokenInterface>,
+        chunk: &[AccountInfo<'info>],
+    ) -> Result<Self> {
+        let (dispute_info, disputer) = (&chunk[0], &chunk[1]);
+        require_keys_eq!(*dispute_info.owner, crate::ID, DesciplineError::InvalidDispute);
+        let dispute = Dispute::try_deserialize(&mut &dispute_info.try_borrow_data()?[..])?;
+
+        let challenge_key = challenge.key();
+        let address = Pubkey::create_program_address(
+            &[b"dispute", challenge_key.as_ref(), disputer.key.as_ref(), &[dispute.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| DesciplineError::InvalidDispute)?;
+        require_keys_eq!(address, dispute_info.key(), DesciplineError::InvalidDispute);
+        require_keys_eq!(dispute.disputer, disputer.key(), DesciplineError::InvalidDispute);
+
+        Ok(Self {
+            funds_account: owned_funds_account(challenge, token_program, chunk, DesciplineError::InvalidDispute)?,
+            dispute,
+            dispute_info: dispute_info.clone(),
+            disputer: disputer.clone(),
+        })
+    }
+
+    // Dispute rent goes back to the disputer.
+    pub fn close_dispute(&self) -> Result<()> {
+        close_remaining_account(&self.dispute_info, &self.disputer)
+    }
+}
+
+// Remaining accounts come as [account, wallet] pairs, followed by the wallet's token account for token challenges.
+fn remaining_chunks<'a, 'info>(
+    challenge: &Account<'info, Challenge>,
+    accounts: &'a [AccountInfo<'info>],
+    error: DesciplineError,
+) -> Result<std::slice::ChunksExact<'a, AccountInfo<'info>>> {
+    let stride = if challenge.is_native() { 2 } else { 3 };
+    let chunks = accounts.chunks_exact(stride);
+    if !chunks.remainder().is_empty() {
+        return Err(error.into());
+    }
+    Ok(chunks)
+}
+
+fn require_distinct<'a>(keys: impl Iterator<Item = &'a Pubkey>, error: DesciplineError) -> Result<()> {
+    let keys = keys.collect::<Vec<_>>();
+    for (i, key) in keys.iter().enumerate() {
+        if keys[..i].contains(key) {
+            return Err(error.into());
+        }
+    }
+    Ok(())
+}
+
+// Native SOL is paid to the wallet itself, tokens to a token account of the wallet in the stake mint.
+fn owned_funds_account<'info>(
+    challenge: &Account<'info, Challenge>,
+    token_program: &Interface<'info, TokenInterface>,
+    chunk: &[AccountInfo<'info>],
+    error: DesciplineError,
+) -> Result<AccountInfo<'info>> {
+    let wallet = &chunk[1];
+    if challenge.is_native() {
+        return Ok(wallet.clone());
+    }
+
+    require_keys_eq!(*chunk[2].owner, token_program.key(), error);
+    let token_account = TokenAccount::try_deserialize(&mut &chunk[2].try_borrow_data()?[..])?;
+    if token_account.owner != wallet.key() || token_account.mint != challenge.stake_mint {
+        return Err(error.into());
+    }
+    Ok(chunk[2].clone())
+}
+
+// Rent goes to `destination`, the account is handed back to the system program like `close` does.
+fn close_remaining_account<'info>(account: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? += lamports;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&system_program::ID);
+    account.realloc(0, false).map_err(Into::into)
+}
+
+// Merkle leaf of a winner: the address alone when prizes are split equally,
+// followed by the little endian payout amount when every leaf carries its own prize.
+pub fn winner_leaf(address: Pubkey, amount: Option<u64>) -> Vec<u8> {
+    let mut leaf = address.to_bytes().to_vec();
+    if let Some(amount) = amount {
+        leaf.extend_from_slice(&amount.to_le_bytes());
+    }
+    leaf
+}
+
+// Verify that `address` is the leaf at `index` of the merkle tree committed to by `merkle_root`.
+// A malformed proof, an index outside the tree and a root mismatch are reported separately.
 pub fn verify_address(
     address: Pubkey, 
     hashes: Vec<u8>, 
-    index: u8,
+    index: u32,
+    winner_count: u32,
     merkle_root: [u8; 32]
-  ) -> Result<bool> {
-    let leaf = address.to_bytes().to_vec();
+  ) -> Result<()> {
+    verify_leaf(&winner_leaf(address, None), hashes, index, winner_count, merkle_root)
+  }
+
+// Verify that `leaf` is the leaf at `index` of the merkle tree committed to by `merkle_root`.
+pub fn verify_leaf(
+    leaf: &[u8],
+    hashes: Vec<u8>, 
+    index: u32,
+    winner_count: u32,
+    merkle_root: [u8; 32]
+  ) -> Result<()> {
+    require!(hashes.chunks_exact(32).remainder().is_empty(), DesciplineError::InvalidProof);
+    require!(index < winner_count, DesciplineError::InvalidProofIndex);
+
+    // every level of the proof consumes one bit of the index,
+    // a u32 index never needs more than MAX_PROOF_DEPTH levels which bounds the hashing cost
+    let depth = hashes.len() / 32;
+    require!(depth <= MAX_PROOF_DEPTH, DesciplineError::InvalidProof);
+    require!(
+        index.checked_shr(depth as u32).unwrap_or(0) == 0,
+        DesciplineError::InvalidProofIndex
+    );
+
     let merkle_proof = MerkleProof::new(
         HashingAlgorithm::Keccak,
         32,
-        index as u32,
+        index,
         hashes,
     );
 
-    let computed_root = merkle_proof.merklize(&leaf)
-        .map_err(|_| DesciplineError::NotInWhitelist)?;
+    let computed_root = merkle_proof.merklize(leaf)
+        .map_err(|_| DesciplineError::InvalidProof)?;
+
+    require!(computed_root.eq(&merkle_root), DesciplineError::MerkleRootMismatch);
 
-    Ok(computed_root.eq(&merkle_root))
+    Ok(())
   }
@@ -1,12 +1,12 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{associated_token::AssociatedToken, token::{Mint, Token, TokenAccount}};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
 
 use crate::{
-    state::{Challenge, Receipt},
+    state::{Challenge, ChallengeStatus, Receipt},
     error::DesciplineError,
 };
 
-use super::shared::{transfer_tokens};
+use super::shared::ChallengeVault;
 
 #[derive(Accounts)]
 pub struct Stake<'info> {
@@ -16,9 +16,10 @@ pub struct Stake<'info> {
   #[account(
     mut,
     associated_token::mint = stake_mint,
-    associated_token::authority = challenger
+    associated_token::authority = challenger,
+    associated_token::token_program = token_program
   )]
-  pub challenger_ata: Account<'info, TokenAccount>,
+  pub challenger_ata: Option<InterfaceAccount<'info, TokenAccount>>,
 
   #[account(
     init_if_needed,
@@ -33,19 +34,28 @@ pub struct Stake<'info> {
     mut,
     associated_token::mint = stake_mint,
     associated_token::authority = challenge,
-    constraint = stake_mint.key() == challenge.token_allowed.mint() @ DesciplineError::NotAllowedToken
+    associated_token::token_program = token_program,
+    constraint = stake_mint.key() == challenge.stake_mint @ DesciplineError::NotAllowedToken
   )]
-  pub vault: Account<'info, TokenAccount>,
+  pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
   #[account(
-    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_str().as_bytes()],
+    mut,
+    seeds = [b"sol_vault", challenge.key().as_ref()],
+    bump = challenge.vault_bump
+  )]
+  pub sol_vault: Option<SystemAccount<'info>>,
+
+  #[account(
+    mut,
+    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_bytes()],
     bump = challenge.bump
   )]
   pub challenge: Account<'info, Challenge>,
 
-  pub stake_mint: Account<'info, Mint>,
+  pub stake_mint: InterfaceAccount<'info, Mint>,
   pub associated_token_program: Program<'info, AssociatedToken>,
-  pub token_program: Program<'info, Token>,
+  pub token_program: Interface<'info, TokenInterface>,
   pub system_program: Program<'info, System>,
 }
 
@@ -55,31 +65,44 @@ impl<'info> Stake<'info> {
     bumps: &StakeBumps
   ) -> Result<()> {
     // before stake endtime
-    // require!(Clock::get()?.unix_timestamp < self.challenge.stake_end_at, DesciplineError::StakeEnded);
-    // msg!("challenge amount is: {} {}", self.challenger_ata.amount, self.challenge.stake_amount);
-    // assert!(false, "early revert");
-    // check token balance >= required
-    require!( self.challenger_ata.amount >= self.challenge.stake_amount, DesciplineError::InsufficientToken);
-    
-    // transfer token
-    transfer_tokens(
-      &self.challenger_ata,
-      &self.vault,
-      &self.challenge.stake_amount,
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < self.challenge.stake_end_at, DesciplineError::StakeEnded);
+    self.challenge.require_status(ChallengeStatus::Open)?;
+    let vault = ChallengeVault::new(
+      &self.challenge,
+      self.vault.as_ref(),
+      self.sol_vault.as_ref(),
       &self.stake_mint,
-      &self.challenger,
       &self.token_program,
-      None,
+      &self.system_program,
+    );
+    let source = vault.funds_account(Some(self.challenger.to_account_info()), self.challenger_ata.as_ref())?;
+    // check token balance >= required
+    require!( vault.balance_of(&source)? >= self.challenge.stake_amount, DesciplineError::InsufficientToken);
+    
+    // transfer token, mints with a transfer fee deliver less than stake_amount
+    let received = vault.deposit(
+      &source,
+      &self.challenger.to_account_info(),
+      self.challenge.stake_amount,
     )
     .map_err(|_| DesciplineError::StakeFailed)?;
 
-    // set receipt
-    self.receipt.set_inner(
-      Receipt {
-        bump: bumps.receipt
-      }
-    );
+    // top-ups do not add a participant
+    if self.receipt.is_new(self.challenge.id) {
+      self.challenge.participant_count += 1;
+    }
+
+    // set receipt, repeat stakes are top-ups and rejected unless the challenge allows them
+    self.receipt.record_stake(
+      self.challenger.key(),
+      self.challenge.id,
+      received,
+      now,
+      self.challenge.allow_top_up
+    )?;
+    self.receipt.bump = bumps.receipt;
 
     Ok(())
   }
-}
\ No newline at end of file
+}
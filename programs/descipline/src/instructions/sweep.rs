@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+
+use crate::{
+    state::{Challenge, ChallengeStatus, ClaimedBitmap, CredentialAuthority, ReceiptStatus, Resolution, SweepMode},
+    error::DesciplineError,
+};
+
+use super::shared::{ChallengeVault, StakerPayout};
+
+// anyone, once claim_end_at has passed and no dispute is open
+// unclaimed prizes go where the challenge's sweep_mode says:
+// - Initiator: with the rest of the vault
+// - Treasury: with the protocol share of the accrued fees
+// - Winners: pro-rata to what each claimer was paid, claimer receipts are passed in remaining accounts
+//   and may be split over several sweeps, the one paying the last claimer closes the challenge
+// the initiator gets its fee share, unallocated stakes, donations and the dust
+// vault and challenge closed to initiator, resolution and claimed bitmap closed to attestor, receipts to claimers
+
+#[derive(Accounts)]
+pub struct Sweep<'info> {
+  pub signer: Signer<'info>,
+
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = challenge,
+    associated_token::token_program = token_program,
+    constraint = stake_mint.key() == challenge.stake_mint @ DesciplineError::NotAllowedToken
+  )]
+  pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    seeds = [b"sol_vault", challenge.key().as_ref()],
+    bump = challenge.vault_bump
+  )]
+  pub sol_vault: Option<SystemAccount<'info>>,
+
+  #[account(
+    mut,
+    has_one = initiator,
+    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_bytes()],
+    bump = challenge.bump,
+  )]
+  pub challenge: Account<'info, Challenge>,
+
+  #[account(
+    mut,
+    seeds = [b"resolution", challenge.key().as_ref()],
+    bump = resolution.bump,
+    constraint = attestor.key() == resolution.attestor @ DesciplineError::InvalidAttestor
+  )]
+  pub resolution: Account<'info, Resolution>,
+
+  #[account(
+    mut,
+    seeds = [b"claimed", challenge.key().as_ref()],
+    bump = claimed.bump,
+  )]
+  pub claimed: Account<'info, ClaimedBitmap>,
+
+  #[account(
+    seeds = [b"authority"],
+    bump = credential_authority.bump
+  )]
+  pub credential_authority: Account<'info, CredentialAuthority>,
+
+  // required when the initiator is owed part of the fee or unclaimed prizes
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = initiator,
+    associated_token::token_program = token_program
+  )]
+  pub initiator_fee_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  // required when the protocol is owed part of the fee or unclaimed prizes
+  #[account(
+    mut,
+    associated_token::mint = stake_mint,
+    associated_token::authority = credential_authority.treasury,
+    associated_token::token_program = token_program
+  )]
+  pub treasury_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+  /// CHECK: receives the protocol fee of native SOL challenges
+  #[account(
+    mut,
+    address = credential_authority.treasury
+  )]
+  pub treasury: Option<UncheckedAccount<'info>>,
+
+  #[account(mut)]
+  pub initiator: SystemAccount<'info>,
+  #[account(mut)]
+  pub attestor: SystemAccount<'info>,
+  // receives the transfer fees withheld by the vault when it closes
+  #[account(mut)]
+  pub stake_mint: InterfaceAccount<'info, Mint>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub token_program: Interface<'info, TokenInterface>,
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> Sweep<'info> {
+  pub fn sweep(&mut self, receipts: &[AccountInfo<'info>]) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    self.challenge.require_status(ChallengeStatus::Resolved)?;
+    require!(now >= self.challenge.claim_end_at, DesciplineError::SweepNotStarted);
+    // zero-winner resolutions are settled by the challenge's zero winner policy
+    require!(self.resolution.winner_count > 0, DesciplineError::NoWinners);
+    // a pending dispute still owns its bond
+    require!(self.resolution.open_disputes == 0, DesciplineError::DisputeOpen);
+
+    if self.challenge.sweep_mode == SweepMode::Winners {
+      self.pay_claimers(receipts)?;
+      if self.resolution.swept_count < self.resolution.claimer_count() {
+        return Ok(());
+      }
+    }
+
+    self.close_challenge()
+  }
+
+  fn pay_claimers(&mut self, receipts: &[AccountInfo<'info>]) -> Result<()> {
+    let vault = ChallengeVault::new(
+      &self.challenge,
+      self.vault.as_ref(),
+      self.sol_vault.as_ref(),
+      &self.stake_mint,
+      &self.token_program,
+      &self.system_program,
+    );
+    for claimer in StakerPayout::load_all(&self.challenge, &self.token_program, receipts)? {
+      require!(claimer.receipt.status == ReceiptStatus::Claimed, DesciplineError::InvalidReceipt);
+
+      let share = self.resolution.sweep_share(claimer.receipt.prize);
+      if share > 0 {
+        vault.pay(&claimer.funds_account, share)
+          .map_err(|_| DesciplineError::ClaimFailed)?;
+      }
+      claimer.close_receipt()?;
+      self.resolution.swept_count += 1;
+    }
+
+    Ok(())
+  }
+
+  fn close_challenge(&mut self) -> Result<()> {
+    self.challenge.status = ChallengeStatus::Closed;
+
+    let vault = ChallengeVault::new(
+      &self.challenge,
+      self.vault.as_ref(),
+      self.sol_vault.as_ref(),
+      &self.stake_mint,
+      &self.token_program,
+      &self.system_program,
+    );
+    let mut protocol_amount = self.credential_authority.protocol_share(self.resolution.fee_accrued);
+    // only allocated prizes nobody claimed, stakes no amount leaf was allocated stay with the initiator
+    if self.challenge.sweep_mode == SweepMode::Treasury {
+      protocol_amount += self.resolution.remaining_prize();
+    }
+
+    vault.pay_fee(
+      self.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+      self.treasury_ata.as_ref(),
+      protocol_amount
+    )?;
+    // the initiator's fee share, unallocated stakes, donations after resolve and the dust,
+    // unclaimed prizes as well unless they went to treasury or winners
+    vault.close_resolved(
+      &self.resolution,
+      &self.claimed,
+      &self.initiator.to_account_info(),
+      self.initiator_fee_ata.as_ref(),
+      &self.attestor.to_account_info()
+    )
+  }
+}
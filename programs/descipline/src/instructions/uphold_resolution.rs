@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Challenge, ChallengeStatus, CredentialAuthority, Dispute, PayoutMode, Resolution},
+    error::DesciplineError,
+};
+
+// only credential authority, the disputed resolution stands
+// bond stays in the vault as part of the prize pool, dispute rent back to disputer
+
+#[derive(Accounts)]
+pub struct UpholdResolution<'info> {
+  pub signer: Signer<'info>,
+
+  #[account(
+    has_one = signer @ DesciplineError::InvalidCredentialAuthority,
+    seeds = [b"authority"],
+    bump = credential_authority.bump
+  )]
+  pub credential_authority: Account<'info, CredentialAuthority>,
+
+  #[account(
+    seeds = [b"challenge", challenge.initiator.key().as_ref(), challenge.name.as_bytes()],
+    bump = challenge.bump
+  )]
+  pub challenge: Account<'info, Challenge>,
+
+  #[account(
+    mut,
+    seeds = [b"resolution", challenge.key().as_ref()],
+    bump = resolution.bump,
+  )]
+  pub resolution: Account<'info, Resolution>,
+
+  #[account(
+    mut,
+    close = disputer,
+    has_one = disputer,
+    seeds = [b"dispute", challenge.key().as_ref(), disputer.key().as_ref()],
+    bump = dispute.bump
+  )]
+  pub dispute: Account<'info, Dispute>,
+
+  #[account(mut)]
+  pub disputer: SystemAccount<'info>,
+}
+
+impl<'info> UpholdResolution<'info> {
+  pub fn uphold_resolution(&mut self) -> Result<()> {
+    self.challenge.require_status(ChallengeStatus::Resolved)?;
+
+    // a forfeited bond grows equal shares, amount leaves keep their attested total
+    let prize_pool = self.resolution.prize_pool + self.dispute.bond;
+    let prize_allocated = (self.challenge.payout_mode == PayoutMode::Amount)
+      .then_some(self.resolution.prize_allocated);
+    self.resolution.set_prize_pool(prize_pool, prize_allocated)?;
+    self.resolution.open_disputes -= 1;
+
+    Ok(())
+  }
+}
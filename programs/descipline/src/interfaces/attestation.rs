@@ -1,10 +1,43 @@
 use anchor_lang::prelude::*;
-use crate::{constants::{Discriminators, SchemaDataTypes}, error::DesciplineError};
+use crate::{constants::{Discriminators, SchemaDataTypes, ATTESTATION_PROGRAM_ID}, error::DesciplineError};
+use super::AccountReader;
+
+// Vec<String> has no fixed element size, every string carries its own length prefix.
+fn skip_vec_string(reader: &mut AccountReader) -> Result<()> {
+    let count = reader.read_u32()?;
+    for _ in 0..count {
+        reader.skip_vec(1)?;
+    }
+    Ok(())
+}
+
+// Bools must be 0 or 1, chars valid unicode scalar values and strings valid utf-8.
+fn verify_field_value(data_type: SchemaDataTypes, field: &[u8]) -> Result<()> {
+    let valid = match data_type {
+        SchemaDataTypes::Bool => field[0] <= 1,
+        SchemaDataTypes::VecBool => field[4..].iter().all(|byte| *byte <= 1),
+        SchemaDataTypes::Char => is_char(field),
+        SchemaDataTypes::VecChar => field[4..].chunks(4).all(is_char),
+        SchemaDataTypes::String => core::str::from_utf8(&field[4..]).is_ok(),
+        SchemaDataTypes::VecString => {
+            let mut offset = 4;
+            let mut valid = true;
+            while offset < field.len() {
+                let len = u32::from_le_bytes(field[offset..offset + 4].try_into().unwrap()) as usize;
+                valid &= core::str::from_utf8(&field[offset + 4..offset + 4 + len]).is_ok();
+                offset += 4 + len;
+            }
+            valid
+        }
+        _ => true,
+    };
+    require!(valid, DesciplineError::InvalidAttestationData);
+    Ok(())
+}
 
 #[inline]
-fn get_size_of_vec(offset: usize, element_size: usize, data: &Vec<u8>) -> usize {
-    let len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-    4 + len * element_size
+fn is_char(bytes: &[u8]) -> bool {
+    char::from_u32(u32::from_le_bytes(bytes.try_into().unwrap())).is_some()
 }
 
 /// Interface for loading Pinocchio Attestation account data
@@ -13,48 +46,41 @@ pub struct AttestationInterface {
     pub schema: Pubkey,
     pub data: Vec<u8>,
     pub signer: Pubkey,
+    pub expiry: i64,
 }
 
 impl AttestationInterface {
+    /// Load a AttestationInterface from an account owned by the attestation service
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*account.owner, ATTESTATION_PROGRAM_ID, DesciplineError::InvalidAccountOwner);
+        Self::new(&account.try_borrow_data()?)
+    }
+
     /// Create a new AttestationInterface from account data
     pub fn new(account_data: &[u8]) -> Result<Self> {
-        // Check discriminator
-        require!(account_data[0] == Discriminators::Attestation as u8, DesciplineError::InvalidAccountData);
+        let mut reader = AccountReader::new(account_data, DesciplineError::InvalidAttestationData);
 
-        // for discriminator and nonce and 3 pubkeys
-        let mut offset = 97;
+        // Check discriminator
+        reader.read_discriminator(Discriminators::Attestation as u8)?;
 
         // Deserialize Pinocchio Attestation account based on the provided structure
-        require!(account_data.len() > 141, DesciplineError::InvalidAttestationData);
-        
-        // Extract credential (next 32 bytes)
-        let credential = Pubkey::new_from_array(account_data[33..65].try_into().unwrap());
-        
-        // Extract schema (next 32 bytes)
-        let schema = Pubkey::new_from_array(account_data[65..97].try_into().unwrap());
-        
-        // Read data length (4 bytes for length)
-        let data_length = u32::from_le_bytes(
-            account_data[offset..offset+4].try_into().unwrap()
-        ) as usize;
-
-        offset += 4;
-
-        // Read data bytes
-        let data = account_data[offset..offset + data_length].to_vec();
-        
-        offset += data_length;
-        
-        require!(account_data.len() == offset + 72, DesciplineError::InvalidAttestationData);
-
-        // Extract signer (next 32 bytes)
-        let signer = Pubkey::new_from_array(account_data[offset..offset+32].try_into().unwrap());
+        // nonce
+        reader.read_bytes(32)?;
+        let credential = reader.read_pubkey()?;
+        let schema = reader.read_pubkey()?;
+        let data = reader.read_vec()?.to_vec();
+        let signer = reader.read_pubkey()?;
+        let expiry = reader.read_i64()?;
+        // token account
+        reader.read_bytes(32)?;
+        reader.finish()?;
 
         Ok(Self {
             credential,
             schema,
             data,
             signer,
+            expiry,
         })
     }
     
@@ -80,43 +106,39 @@ impl AttestationInterface {
         // Iterate over the data and ensure there are no overflows.
         // If we do not overflow and match with the end of the data,
         // then we can assume the data is valid for the schema.
-        let mut data_offset = 0;
-        let mut parsed_fields = Vec::new();
+        let mut reader = AccountReader::new(&self.data, DesciplineError::InvalidAttestationData);
+        let mut parsed_fields = Vec::with_capacity(layout.len());
 
         for data_type in layout {
-            let schema_data_type: SchemaDataTypes = data_type.into();
+            let schema_data_type = SchemaDataTypes::try_from(data_type)?;
+            let start = reader.offset();
             match schema_data_type {
-                // u8
-                SchemaDataTypes::U8 => {
-                    msg!("data offset: {},  self.data: {}", data_offset, self.data.len());
-                    if data_offset + 1 > self.data.len() {
-                        return Err(DesciplineError::InvalidAttestationData.into());
-                    }
-                    let val = vec![self.data[data_offset]];
-                    parsed_fields.push(val);
-                    data_offset += 1;
-                }
-
-                // Vec<u8> -> Vec<u128>
-                SchemaDataTypes::VecU8 => {
-                    // get length of vector from your helper
-                    let vec_size = get_size_of_vec(data_offset, 1, &self.data);
-                    if data_offset + vec_size > self.data.len() {
-                        return Err(DesciplineError::InvalidAttestationData.into());
-                    }
-                    let vec_data = self.data[data_offset..data_offset + vec_size].to_vec();
-                    parsed_fields.push(vec_data);
-                    data_offset += vec_size;
+                SchemaDataTypes::VecString => skip_vec_string(&mut reader)?,
+                // vectors and strings, including their length prefix
+                _ if schema_data_type.is_vec() => reader.skip_vec(schema_data_type.element_size())?,
+                _ => {
+                    reader.read_bytes(schema_data_type.element_size())?;
                 }
             }
-        }
 
-        if data_offset != self.data.len() {
-            return Err(DesciplineError::InvalidAttestationData.into());
+            let field = &self.data[start..reader.offset()];
+            verify_field_value(schema_data_type, field)?;
+            parsed_fields.push(field.to_vec());
         }
+
+        reader.finish()?;
         Ok(parsed_fields)
     }
 
+    /// Verify attestation has not expired, zero expiry never expires
+    pub fn verify_expiry(&self, now: i64) -> Result<()> {
+        require!(
+            self.expiry == 0 || now < self.expiry,
+            DesciplineError::AttestationExpired
+        );
+        Ok(())
+    }
+
     /// Verify signer is authorized
     pub fn verify_signers(&self, authorized_signers: &[Pubkey]) -> Result<()> {
         require!(
@@ -1,7 +1,9 @@
 pub mod schema;
 pub mod credential;
 pub mod attestation;
+pub mod reader;
 
 pub use schema::*;
 pub use credential::*;
-pub use attestation::*; 
\ No newline at end of file
+pub use attestation::*;
+pub use reader::*; 
\ No newline at end of file
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::error::DesciplineError;
+
+/// Bounds checked cursor over borsh encoded account data
+pub struct AccountReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+    error: DesciplineError,
+}
+
+impl<'a> AccountReader<'a> {
+    /// Every read past the end of `data` fails with `error`
+    pub fn new(data: &'a [u8], error: DesciplineError) -> Self {
+        Self { data, offset: 0, error }
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self.offset.checked_add(len).ok_or(self.error)?;
+        let bytes = self.data.get(self.offset..end).ok_or(self.error)?;
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.read_bytes(2)?.try_into().unwrap()))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64> {
+        Ok(i64::from_le_bytes(self.read_bytes(8)?.try_into().unwrap()))
+    }
+
+    pub fn read_pubkey(&mut self) -> Result<Pubkey> {
+        Ok(Pubkey::new_from_array(self.read_bytes(32)?.try_into().unwrap()))
+    }
+
+    /// Check the leading discriminator byte
+    pub fn read_discriminator(&mut self, discriminator: u8) -> Result<()> {
+        require!(self.read_u8()? == discriminator, DesciplineError::InvalidAccountData);
+        Ok(())
+    }
+
+    /// Vec<u8> with a u32 length prefix
+    pub fn read_vec(&mut self) -> Result<&'a [u8]> {
+        let len = self.read_u32()? as usize;
+        self.read_bytes(len)
+    }
+
+    /// Vec<u8> with a u32 length prefix holding utf-8
+    pub fn read_string(&mut self) -> Result<String> {
+        let bytes = self.read_vec()?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| self.error.into())
+    }
+
+    /// Skip a u32 prefixed vector of `element_size` wide elements
+    pub fn skip_vec(&mut self, element_size: usize) -> Result<()> {
+        let len = self.read_u32()? as usize;
+        self.read_bytes(len.checked_mul(element_size).ok_or(self.error)?)?;
+        Ok(())
+    }
+
+    /// All data must have been read
+    pub fn finish(&self) -> Result<()> {
+        require!(self.offset == self.data.len(), self.error);
+        Ok(())
+    }
+}
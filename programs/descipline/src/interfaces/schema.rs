@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::{constants::{Discriminators, SCHEMA_NAME}, error::DesciplineError};
+use crate::{constants::{Discriminators, ATTESTATION_PROGRAM_ID}, error::DesciplineError};
+use super::AccountReader;
 
 /// Interface for loading Pinocchio Schema account data
 pub struct SchemaInterface {
@@ -9,52 +10,29 @@ pub struct SchemaInterface {
 }
 
 impl SchemaInterface {
+    /// Load a SchemaInterface from an account owned by the attestation service
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*account.owner, ATTESTATION_PROGRAM_ID, DesciplineError::InvalidAccountOwner);
+        Self::new(&account.try_borrow_data()?)
+    }
+
     /// Create a new SchemaInterface from account data
     pub fn new(account_data: &[u8]) -> Result<Self> {
-        // Check discriminator
-        require!(account_data[0] == Discriminators::Schema as u8, DesciplineError::InvalidAccountData);
+        let mut reader = AccountReader::new(account_data, DesciplineError::InvalidSchemaData);
 
-        // for discriminator and credential
-        let mut offset = 33;
+        // Check discriminator
+        reader.read_discriminator(Discriminators::Schema as u8)?;
 
         // Deserialize Pinocchio Schema account based on the provided structure
-        require!(account_data.len() > 51, DesciplineError::InvalidSchemaData);
-        msg!("schema account data length: {}", account_data.len());
-        
-        // Extract credential
-        let credential = Pubkey::new_from_array(account_data[1..33].try_into().unwrap());
-        
-
-        let name_length = u32::from_le_bytes(
-            account_data[offset..offset+4].try_into().unwrap()
-        ) as usize;
-        msg!("name_length: {}", name_length);
-
-        offset += 4;
-
-        let name_bytes = &account_data[offset..offset + name_length];
-        let name = String::from_utf8(name_bytes.to_vec())
-            .map_err(|_| DesciplineError::InvalidSchemaData)?;
-        
-        offset += name_length;
-
-        let desc_length = u32::from_le_bytes(
-            account_data[offset..offset+4].try_into().unwrap()
-        ) as usize;
-        
-        offset += 4 + desc_length;
-
-        let layout_length = u32::from_le_bytes(
-            account_data[offset..offset+4].try_into().unwrap()
-        ) as usize;
-        
-        // Read name bytes
-        if account_data.len() < offset + 4 + layout_length + 6 {
-            return err!(DesciplineError::InvalidSchemaData);
-        }
-        
-        // Read layout bytes
-        let layout = account_data[offset..offset + layout_length].to_vec();
+        let credential = reader.read_pubkey()?;
+        let name = reader.read_string()?;
+        // description
+        reader.read_vec()?;
+        let layout = reader.read_vec()?.to_vec();
+        // field names, is_paused and version
+        reader.read_vec()?;
+        reader.read_bytes(2)?;
+        reader.finish()?;
 
         Ok(Self {
             credential,
@@ -63,16 +41,7 @@ impl SchemaInterface {
         })
     }
     
-    /// Verify that schema name matches challenge name
-    pub fn verify_name(&self) -> Result<()> {
-        require!(
-            self.name == SCHEMA_NAME,
-            DesciplineError::NameMismatch
-        );
-        Ok(())
-    }
-
-    /// Verify that schema name matches challenge name
+    /// Verify that the schema belongs to the challenge credential
     pub fn verify_credential(&self, credential: Pubkey) -> Result<()> {
         require!(
             self.credential == credential,
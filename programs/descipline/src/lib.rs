@@ -9,8 +9,9 @@ pub mod error;
 use anchor_lang::prelude::*;
 pub use instructions::*;
 pub use constants::*;
+use state::{PayoutMode, ResolutionFields, SweepMode, ZeroWinnerPolicy};
 
-declare_id!("2s3weLk9GXoqkbw377YbiQpfQX1eQ6DxUsfHi82WRZLF");
+declare_id!("59cH5dmYsEMCJh8vBsBtfNJjWrsotZBQoGZELbmqQ1tz");
 
 #[program]
 pub mod descipline {
@@ -22,22 +23,61 @@ pub mod descipline {
         Ok(())
     }
 
+    pub fn set_protocol_fee(ctx: Context<SetProtocolFee>, treasury: Pubkey, protocol_fee: u16) -> Result<()> {
+        ctx.accounts.set_protocol_fee(treasury, protocol_fee)?;
+
+        Ok(())
+    }
+
+    pub fn add_allowed_mint(ctx: Context<AddAllowedMint>, min_stake: u64, max_stake: u64) -> Result<()> {
+        ctx.accounts.add_allowed_mint(min_stake, max_stake, &ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn remove_allowed_mint(ctx: Context<RemoveAllowedMint>) -> Result<()> {
+        ctx.accounts.remove_allowed_mint()?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn create_challenge(
         ctx: Context<CreateChallenge>,
         name: String,
-        token_allowed: TokenAllowed,
         stake_amount: u64,
+        allow_top_up: bool,
+        payout_mode: PayoutMode,
         fee: u16,
         stake_end_at: i64,
         claim_start_from: i64,
+        claim_end_at: i64,
+        sweep_mode: SweepMode,
+        zero_winner_policy: ZeroWinnerPolicy,
+        resolve_deadline: i64,
+        dispute_window: i64,
+        dispute_bond: u64,
+        max_relayer_tip: u64,
+        threshold: u8,
+        resolution_fields: ResolutionFields,
     ) -> Result<()> {
         ctx.accounts.create_challenge(
             name,
-            token_allowed,
             stake_amount,
+            allow_top_up,
+            payout_mode,
             fee,
             stake_end_at,
             claim_start_from,
+            claim_end_at,
+            sweep_mode,
+            zero_winner_policy,
+            resolve_deadline,
+            dispute_window,
+            dispute_bond,
+            max_relayer_tip,
+            threshold,
+            resolution_fields,
             &ctx.bumps
         )?;
         
@@ -50,20 +90,92 @@ pub mod descipline {
         Ok(())
     }
 
-    pub fn resolve(ctx: Context<Resolve>) -> Result<()> {
-        ctx.accounts.resolve(&ctx.bumps)?;
+    pub fn resolve<'info>(ctx: Context<'_, '_, '_, 'info, Resolve<'info>>, winner_count: u32) -> Result<()> {
+        ctx.accounts.resolve(ctx.remaining_accounts, winner_count, &ctx.bumps)?;
+        
+        Ok(())
+    }
+
+    pub fn invalidate_resolution(ctx: Context<InvalidateResolution>) -> Result<()> {
+        ctx.accounts.invalidate_resolution()?;
+        
+        Ok(())
+    }
+
+    pub fn open_dispute(ctx: Context<OpenDispute>) -> Result<()> {
+        ctx.accounts.open_dispute(&ctx.bumps)?;
+        
+        Ok(())
+    }
+
+    pub fn uphold_resolution(ctx: Context<UpholdResolution>) -> Result<()> {
+        ctx.accounts.uphold_resolution()?;
+        
+        Ok(())
+    }
+
+    pub fn replace_resolution<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReplaceResolution<'info>>,
+        root_hash: [u8; 32],
+        winner_count: u32,
+        winner_list_uri: Vec<u8>,
+        prize_allocated: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts.replace_resolution(
+            root_hash,
+            winner_count,
+            winner_list_uri,
+            prize_allocated,
+            ctx.remaining_accounts,
+        )?;
+        
+        Ok(())
+    }
+
+    pub fn claim(ctx: Context<Claim>, proof: Vec<u8>, index: u32, amount: Option<u64>) -> Result<()> {
+        ctx.accounts.claim(proof, index, amount)?;
+        
+        Ok(())
+    }
+
+    pub fn claim_for(
+        ctx: Context<ClaimFor>,
+        proof: Vec<u8>,
+        index: u32,
+        amount: Option<u64>,
+        tip: u64,
+    ) -> Result<()> {
+        ctx.accounts.claim_for(proof, index, amount, tip)?;
+        
+        Ok(())
+    }
+
+    pub fn sweep<'info>(ctx: Context<'_, '_, '_, 'info, Sweep<'info>>) -> Result<()> {
+        ctx.accounts.sweep(ctx.remaining_accounts)?;
+        
+        Ok(())
+    }
+
+    pub fn settle_no_winners<'info>(ctx: Context<'_, '_, '_, 'info, SettleNoWinners<'info>>) -> Result<()> {
+        ctx.accounts.settle_no_winners(ctx.remaining_accounts)?;
+        
+        Ok(())
+    }
+
+    pub fn close_receipt(ctx: Context<CloseReceipt>) -> Result<()> {
+        ctx.accounts.close_receipt()?;
         
         Ok(())
     }
 
-    pub fn claim(ctx: Context<Claim>, proof: Vec<u8>, index: u8) -> Result<()> {
-        ctx.accounts.claim(proof, index)?;
+    pub fn refund(ctx: Context<Refund>) -> Result<()> {
+        ctx.accounts.refund()?;
         
         Ok(())
     }
 
-    pub fn claim_and_close(ctx: Context<ClaimAndClose>, proof: Vec<u8>, index: u8) -> Result<()> {
-        ctx.accounts.claim_and_close(proof, index)?;
+    pub fn cancel_challenge(ctx: Context<CancelChallenge>) -> Result<()> {
+        ctx.accounts.cancel_challenge()?;
         
         Ok(())
     }
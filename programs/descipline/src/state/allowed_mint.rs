@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::error::DesciplineError;
+
+#[account]
+#[derive(InitSpace)]
+pub struct AllowedMint {
+  pub mint: Pubkey, // Challenges can only be created with registered mints.
+  pub min_stake: u64,
+  pub max_stake: u64,
+  pub bump: u8
+}
+
+impl AllowedMint {
+  pub fn verify_stake_amount(&self, stake_amount: u64) -> Result<()> {
+    require!(
+      stake_amount >= self.min_stake && stake_amount <= self.max_stake,
+      DesciplineError::InvalidStakeAmount
+    );
+    Ok(())
+  }
+}
@@ -1,5 +1,106 @@
 use anchor_lang::prelude::*;
-use crate::constants::TokenAllowed;
+use anchor_spl::token::spl_token::native_mint;
+use crate::{constants::{SchemaDataTypes, FEE_DENOMINATOR, MAX_ATTESTORS}, error::DesciplineError};
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ChallengeStatus {
+  Open,      // Accepting stakes until `stake_end_at`.
+  Locked,    // Staking closed, waiting for a resolution or refunds after `resolve_deadline`.
+  Resolved,  // Resolution written, winners can claim from `claim_start_from` until `claim_end_at`.
+  Closed,    // Last prize claimed, unclaimed prizes swept or last stake refunded, accounts closed.
+  Cancelled, // Cancelled by initiator after someone staked, stakers can only refund.
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PayoutMode {
+  Equal,  // Leaves are winner addresses, the prize pool is split equally.
+  Amount, // Leaves are (winner, amount) pairs, e.g. for ranked tiers or partial completion.
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum SweepMode {
+  Initiator, // Unclaimed prizes go to the initiator with the rest of the vault.
+  Treasury,  // Unclaimed prizes go to the protocol treasury.
+  Winners,   // Unclaimed prizes are split pro-rata among the winners who claimed.
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ZeroWinnerPolicy {
+  Refund,                        // Every staker gets its stake back.
+  Initiator,                     // The vault goes to the initiator as a discipline penalty.
+  Donate { beneficiary: Pubkey }, // The vault is donated to the beneficiary.
+}
+
+// Position of each resolution value in the layout of the challenge's schema.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct ResolutionFields {
+  pub challenge: u8,       // VecU8 holding the challenge address.
+  pub root_hash: u8,       // VecU8 holding the merkle root of the winners.
+  pub winner_count: u8,    // U8, U16 or U32 number of winners.
+  pub winner_list_uri: u8, // VecU8 or String pointing at the winner list.
+  pub prize_allocated: Option<u8>, // U64 total of the amount leaves, only for amount payouts.
+}
+
+// The resolution values read from an attestation.
+pub struct ResolutionData {
+  pub challenge: Pubkey,
+  pub root_hash: [u8; 32],
+  pub winner_count: u32,
+  pub winner_list_uri: Vec<u8>,
+  pub prize_allocated: Option<u64>,
+}
+
+impl ResolutionFields {
+  // Every role must point at a distinct field of a compatible type.
+  pub fn verify_layout(&self, layout: &[u8]) -> Result<()> {
+    let mut roles = vec![self.challenge, self.root_hash, self.winner_count, self.winner_list_uri];
+    roles.extend(self.prize_allocated);
+    for (i, role) in roles.iter().enumerate() {
+      require!(!roles[..i].contains(role), DesciplineError::InvalidResolutionFields);
+    }
+
+    let data_type = |index: u8| -> Result<SchemaDataTypes> {
+      let byte = *layout.get(index as usize).ok_or(DesciplineError::InvalidResolutionFields)?;
+      SchemaDataTypes::try_from(byte)
+    };
+    require!(
+      data_type(self.challenge)? == SchemaDataTypes::VecU8
+        && data_type(self.root_hash)? == SchemaDataTypes::VecU8
+        && matches!(data_type(self.winner_count)?, SchemaDataTypes::U8 | SchemaDataTypes::U16 | SchemaDataTypes::U32)
+        && matches!(data_type(self.winner_list_uri)?, SchemaDataTypes::VecU8 | SchemaDataTypes::String),
+      DesciplineError::InvalidResolutionFields
+    );
+    if let Some(prize_allocated) = self.prize_allocated {
+      require!(data_type(prize_allocated)? == SchemaDataTypes::U64, DesciplineError::InvalidResolutionFields);
+    }
+    Ok(())
+  }
+
+  // `fields` are the parsed attestation fields, vectors still carry their length prefix.
+  pub fn read(&self, fields: &[Vec<u8>]) -> Result<ResolutionData> {
+    let hash = |index: u8| -> Result<[u8; 32]> {
+      let field = &fields[index as usize];
+      require!(field.len() == 36, DesciplineError::InvalidAttestationData);
+      Ok(field[4..].try_into().unwrap())
+    };
+    // little endian u8, u16 or u32
+    let count = |index: u8| -> u32 {
+      let field = &fields[index as usize];
+      let mut bytes = [0u8; 4];
+      bytes[..field.len()].copy_from_slice(field);
+      u32::from_le_bytes(bytes)
+    };
+
+    Ok(ResolutionData {
+      challenge: Pubkey::new_from_array(hash(self.challenge)?),
+      root_hash: hash(self.root_hash)?,
+      winner_count: count(self.winner_count),
+      winner_list_uri: fields[self.winner_list_uri as usize].clone(), // first 4 bytes are length
+      prize_allocated: self.prize_allocated
+        .map(|index| u64::from_le_bytes(fields[index as usize].as_slice().try_into().unwrap())),
+    })
+  }
+}
 
 #[account]
 #[derive(InitSpace)]
@@ -7,12 +108,76 @@ pub struct Challenge {
   #[max_len(32)]
   pub name: String, // The name as seeds for deriving Challenge PDA.
   pub initiator: Pubkey,   
-  pub token_allowed: TokenAllowed, // It could be wrapped Sol or SPL token.
+  pub id: u64, // Taken from the credential authority's counter, a challenge recreated under the same name gets a new one.
+  pub stake_mint: Pubkey, // Any mint in the AllowedMint registry when created, WSOL is staked as native SOL.
   pub stake_amount: u64,
-  pub fee: u16, // Inactive feat. The fee will be taken by initiator, minumum denomination is 1 / 10000.
+  pub allow_top_up: bool, // Stakers may stake stake_amount again, otherwise one stake per staker.
+  pub payout_mode: PayoutMode,
+  pub fee: u16, // Taken from each prize payout for initiator and protocol, minumum denomination is 1 / 10000.
   pub stake_end_at: i64,
   pub claim_start_from: i64,
+  pub claim_end_at: i64, // Claims stop, anyone can sweep what was not claimed.
+  pub sweep_mode: SweepMode,
+  pub zero_winner_policy: ZeroWinnerPolicy, // Settles a resolution without winners.
+  pub resolve_deadline: i64, // Stakers can refund if no resolution is written by then.
+  pub dispute_window: i64, // Seconds after resolution during which stakers can dispute it, claims wait until it ends.
+  pub dispute_bond: u64, // Posted by each disputer, forfeited to the pool if the resolution is upheld.
+  pub max_relayer_tip: u64, // Most a relayer may keep from a prize it claims for a winner, zero disables tips.
+  pub participant_count: u32,
   pub schema: Pubkey, // The schema will be attested by authorized signers.
-  pub attestor: Pubkey,
+  pub credential: Pubkey, // The credential the schema and its attestations belong to.
+  pub resolution_fields: ResolutionFields, // Where the resolution is found in the schema layout.
+  #[max_len(MAX_ATTESTORS)]
+  pub attestors: Vec<Pubkey>, // The credential's authorized signers.
+  pub threshold: u8, // Attestations from distinct attestors required to resolve.
+  pub status: ChallengeStatus,
+  pub vault_bump: u8, // Only set for the sol vault of native SOL challenges.
   pub bump: u8
-} 
\ No newline at end of file
+} 
+
+impl Challenge {
+  // An open challenge is locked as soon as its stake window has passed,
+  // whether or not an instruction has touched it since.
+  pub fn refresh_status(&mut self, now: i64) {
+    if self.status == ChallengeStatus::Open && now >= self.stake_end_at {
+      self.status = ChallengeStatus::Locked;
+    }
+  }
+
+  pub fn require_status(&self, status: ChallengeStatus) -> Result<()> {
+    require!(self.status == status, DesciplineError::InvalidChallengeStatus);
+    Ok(())
+  }
+
+  pub fn require_claim_window(&self, now: i64) -> Result<()> {
+    require!(now >= self.claim_start_from, DesciplineError::ClaimNotStarted);
+    require!(now < self.claim_end_at, DesciplineError::ClaimEnded);
+    Ok(())
+  }
+
+  pub fn is_native(&self) -> bool {
+    self.stake_mint == native_mint::ID
+  }
+
+  // Amount leaves prove the prize of the claimer, equal splits only prove the address.
+  pub fn require_payout_amount(&self, amount: Option<u64>) -> Result<()> {
+    require!(
+      amount.is_some() == (self.payout_mode == PayoutMode::Amount),
+      DesciplineError::InvalidPayoutAmount
+    );
+    Ok(())
+  }
+
+  pub fn fee_of(&self, payout: u64) -> u64 {
+    (payout as u128 * self.fee as u128 / FEE_DENOMINATOR as u128) as u64
+  }
+
+  // A relayer's tip stays within the cap and what is left of the prize after the fee.
+  pub fn verify_relayer_tip(&self, tip: u64, payout: u64, fee: u64) -> Result<()> {
+    require!(
+      tip <= self.max_relayer_tip && tip <= payout.saturating_sub(fee),
+      DesciplineError::InvalidRelayerTip
+    );
+    Ok(())
+  }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::error::DesciplineError;
+
+#[account]
+pub struct ClaimedBitmap {
+    pub bits: Vec<u8>, // one bit per winner leaf index, set once its prize is claimed
+    pub bump: u8, // closed with the resolution
+}
+
+impl ClaimedBitmap {
+    pub fn len(winner_count: u32) -> usize {
+        (winner_count as usize).div_ceil(8)
+    }
+
+    pub fn space(winner_count: u32) -> usize {
+        8 + 4 + Self::len(winner_count) + 1
+    }
+
+    // Each leaf index can be claimed exactly once.
+    pub fn claim(&mut self, index: u32) -> Result<()> {
+        let byte = self.bits
+            .get_mut(index as usize / 8)
+            .ok_or(DesciplineError::InvalidProofIndex)?;
+        let mask = 1u8 << (index % 8);
+        require!(*byte & mask == 0, DesciplineError::AlreadyClaimed);
+        *byte |= mask;
+        Ok(())
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        self.bits
+            .get(index as usize / 8)
+            .is_some_and(|byte| byte & (1u8 << (index % 8)) != 0)
+    }
+}
@@ -1,8 +1,19 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::FEE_DENOMINATOR;
+
 #[account]
 #[derive(InitSpace)]
 pub struct CredentialAuthority{
   pub signer: Pubkey,
+  pub treasury: Pubkey, // Owner of the token accounts receiving the protocol fee.
+  pub protocol_fee: u16, // Share of every challenge fee sent to treasury, minumum denomination is 1 / 10000.
+  pub challenge_count: u64, // Challenges created so far, the next one takes it as its id.
   pub bump: u8
-} 
\ No newline at end of file
+} 
+
+impl CredentialAuthority {
+  pub fn protocol_share(&self, fee: u64) -> u64 {
+    (fee as u128 * self.protocol_fee as u128 / FEE_DENOMINATOR as u128) as u64
+  }
+}
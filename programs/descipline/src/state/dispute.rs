@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Dispute {
+    pub disputer: Pubkey,
+    pub bond: u64, // received by vault, forfeited to the pool if the resolution is upheld
+    pub bump: u8, // closed when settled
+}
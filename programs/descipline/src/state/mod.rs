@@ -2,8 +2,14 @@ pub mod challenge;
 pub mod receipt;
 pub mod credential_authority;
 pub mod resolution;
+pub mod allowed_mint;
+pub mod dispute;
+pub mod claimed_bitmap;
 
 pub use challenge::*;
 pub use credential_authority::*;
 pub use receipt::*;
-pub use resolution::*;
\ No newline at end of file
+pub use resolution::*;
+pub use allowed_mint::*;
+pub use dispute::*;
+pub use claimed_bitmap::*;
\ No newline at end of file
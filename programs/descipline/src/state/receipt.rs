@@ -1,7 +1,61 @@
 use anchor_lang::prelude::*;
 
+use crate::error::DesciplineError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ReceiptStatus {
+    Staked,
+    Disputed, // Opened a dispute against the resolution, kept after it is settled.
+    Claimed,  // Claimed a prize, kept open for a sweep to winners.
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Receipt {
-    pub bump: u8, // closed when claimed
+    pub staker: Pubkey,
+    pub challenge_id: u64, // the challenge it was staked in, its address is reused when a challenge is recreated
+    pub amount: u64, // received by vault, net of any transfer fee, summed over top-ups
+    pub staked_at: i64, // first stake, top-ups keep it
+    pub status: ReceiptStatus,
+    pub prize: u64, // payout of a claimer, fees included, weighs its share of a sweep to winners
+    pub bump: u8, // closed when claimed or refunded, claimers keep it when unclaimed prizes are swept to winners
+}
+
+impl Receipt {
+    // A freshly initialized receipt still has a zeroed bump,
+    // one left behind by an earlier challenge at the same address starts over.
+    pub fn is_new(&self, challenge_id: u64) -> bool {
+        self.bump == 0 || self.challenge_id != challenge_id
+    }
+
+    // Repeat stakes only add up when the challenge allows top-ups.
+    pub fn record_stake(
+        &mut self,
+        staker: Pubkey,
+        challenge_id: u64,
+        received: u64,
+        now: i64,
+        allow_top_up: bool,
+    ) -> Result<()> {
+        if self.is_new(challenge_id) {
+            self.staker = staker;
+            self.challenge_id = challenge_id;
+            self.amount = 0;
+            self.staked_at = now;
+            self.status = ReceiptStatus::Staked;
+            self.prize = 0;
+        } else {
+            require!(allow_top_up, DesciplineError::AlreadyStaked);
+        }
+        self.amount = self.amount.checked_add(received).ok_or(DesciplineError::StakeFailed)?;
+        Ok(())
+    }
+
+    // A winner is paid once, even when the winner list holds several amount leaves for it.
+    pub fn record_claim(&mut self, prize: u64) -> Result<()> {
+        require!(self.status != ReceiptStatus::Claimed, DesciplineError::AlreadyClaimed);
+        self.status = ReceiptStatus::Claimed;
+        self.prize = prize;
+        Ok(())
+    }
 }
@@ -1,12 +1,80 @@
 use anchor_lang::prelude::*;
+use crate::{constants::{MAX_ATTESTORS, MAX_URI_LEN}, error::DesciplineError};
 
 #[account]
 #[derive(InitSpace)]
 pub struct Resolution {
     pub root_hash: [u8; 32],
-    pub winner_count: u8,
-    pub winner_notclaim_count: u8,
-    #[max_len(128)]
+    pub winner_count: u32,
+    pub winner_notclaim_count: u32,
+    #[max_len(MAX_URI_LEN)]
     pub winner_list_uri: Vec<u8>,
+    pub prize_pool: u64, // vault balance at resolve plus forfeited dispute bonds, later donations go to the initiator
+    pub prize_allocated: u64, // owed to winners: the whole pool for equal payouts, the attested total of amount leaves
+    pub prize_share: u64, // equal split of the prize pool, the last claimer also takes the rounding dust
+    pub prize_paid: u64, // payouts so far, fees included
+    pub fee_accrued: u64, // kept in vault until the last claim
+    pub attestor: Pubkey, // paid the rent, refunded when closed
+    #[max_len(MAX_ATTESTORS)]
+    pub attestations: Vec<Pubkey>, // resolution is invalidated if any of them is revoked
+    pub resolved_at: i64, // start of the dispute window
+    pub open_disputes: u32, // claims are frozen until every dispute is settled
+    pub swept_count: u32, // receipts paid by a sweep to winners or a zero-winner refund
     pub bump: u8, // closed when claimed
-}
\ No newline at end of file
+}
+
+impl Resolution {
+    // Whatever amount leaves leave unallocated goes to the initiator when the challenge closes.
+    pub fn set_prize_pool(&mut self, prize_pool: u64, prize_allocated: Option<u64>) -> Result<()> {
+        let prize_allocated = prize_allocated.unwrap_or(prize_pool);
+        require!(prize_allocated <= prize_pool, DesciplineError::PrizePoolExceeded);
+        self.prize_pool = prize_pool;
+        self.prize_allocated = prize_allocated;
+        self.prize_share = prize_allocated.checked_div(self.winner_count as u64).unwrap_or(0);
+        Ok(())
+    }
+
+    // Allocated prizes not claimed yet.
+    pub fn remaining_prize(&self) -> u64 {
+        self.prize_allocated - self.prize_paid
+    }
+
+    pub fn is_last_claim(&self) -> bool {
+        self.winner_notclaim_count == 1
+    }
+
+    // Amount leaves carry their own prize, the last equal share also takes the rounding dust.
+    pub fn prize_of(&self, amount: Option<u64>) -> Result<u64> {
+        let prize = match amount {
+            Some(amount) => amount,
+            None if self.is_last_claim() => self.remaining_prize(),
+            None => self.prize_share,
+        };
+        require!(prize <= self.remaining_prize(), DesciplineError::PrizePoolExceeded);
+        Ok(prize)
+    }
+
+    // Fees stay in vault until the last claim.
+    pub fn record_claim(&mut self, prize: u64, fee: u64) {
+        self.winner_notclaim_count -= 1;
+        self.prize_paid += prize;
+        self.fee_accrued += fee;
+    }
+
+    pub fn claimer_count(&self) -> u32 {
+        self.winner_count - self.winner_notclaim_count
+    }
+
+    // Unclaimed prizes split pro-rata to what each claimer was paid, the dust stays in vault.
+    pub fn sweep_share(&self, prize: u64) -> u64 {
+        (self.remaining_prize() as u128 * prize as u128)
+            .checked_div(self.prize_paid as u128)
+            .unwrap_or(0) as u64
+    }
+
+    pub fn require_claimable(&self, now: i64, dispute_window: i64) -> Result<()> {
+        require!(now >= self.resolved_at + dispute_window, DesciplineError::DisputeWindowOpen);
+        require!(self.open_disputes == 0, DesciplineError::DisputeOpen);
+        Ok(())
+    }
+}
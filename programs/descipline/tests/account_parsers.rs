@@ -0,0 +1,257 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_error::ProgramError;
+use descipline::{
+    constants::{SchemaDataTypes, ATTESTATION_PROGRAM_ID},
+    error::DesciplineError,
+    interfaces::{AttestationInterface, CredentialInterface, SchemaInterface},
+};
+use proptest::prelude::*;
+
+use common::*;
+
+// A valid borsh encoding of one value of `data_type`.
+fn field_value(data_type: SchemaDataTypes) -> BoxedStrategy<Vec<u8>> {
+    let size = data_type.element_size();
+    let element: BoxedStrategy<Vec<u8>> = match data_type {
+        SchemaDataTypes::Bool | SchemaDataTypes::VecBool => any::<bool>().prop_map(|b| vec![b as u8]).boxed(),
+        SchemaDataTypes::Char | SchemaDataTypes::VecChar => {
+            any::<char>().prop_map(|c| (c as u32).to_le_bytes().to_vec()).boxed()
+        }
+        SchemaDataTypes::String | SchemaDataTypes::VecString => {
+            ".{0,8}".prop_map(|s: String| s.into_bytes()).boxed()
+        }
+        _ => proptest::collection::vec(any::<u8>(), size).boxed(),
+    };
+
+    match data_type {
+        SchemaDataTypes::String => element
+            .prop_map(|bytes| {
+                let mut out = Vec::new();
+                put_vec(&mut out, &bytes);
+                out
+            })
+            .boxed(),
+        SchemaDataTypes::VecString => proptest::collection::vec(element, 0..4)
+            .prop_map(|strings| {
+                let mut out = (strings.len() as u32).to_le_bytes().to_vec();
+                for string in strings {
+                    put_vec(&mut out, &string);
+                }
+                out
+            })
+            .boxed(),
+        _ if data_type.is_vec() => proptest::collection::vec(element, 0..4)
+            .prop_map(|elements| {
+                let mut out = (elements.len() as u32).to_le_bytes().to_vec();
+                out.extend(elements.concat());
+                out
+            })
+            .boxed(),
+        _ => element,
+    }
+}
+
+fn layout_and_fields() -> impl Strategy<Value = (Vec<u8>, Vec<Vec<u8>>)> {
+    proptest::collection::vec(0u8..=25, 0..8).prop_flat_map(|layout| {
+        let fields: Vec<_> = layout
+            .iter()
+            .map(|byte| field_value(SchemaDataTypes::try_from(*byte).unwrap()))
+            .collect();
+        (Just(layout), fields)
+    })
+}
+
+fn pubkey() -> impl Strategy<Value = Pubkey> {
+    any::<[u8; 32]>().prop_map(Pubkey::new_from_array)
+}
+
+proptest! {
+    #[test]
+    fn credential_round_trip(
+        authority in pubkey(),
+        name in ".{0,16}",
+        signers in proptest::collection::vec(pubkey(), 0..6),
+    ) {
+        let data = encode_credential(&authority, &name, &signers);
+        let credential = CredentialInterface::new(&data).unwrap();
+
+        prop_assert_eq!(credential.authority, authority);
+        prop_assert_eq!(credential.name, name);
+        prop_assert_eq!(credential.authorized_signers, signers);
+
+        for len in 0..data.len() {
+            prop_assert!(CredentialInterface::new(&data[..len]).is_err());
+        }
+    }
+
+    #[test]
+    fn schema_round_trip(
+        credential in pubkey(),
+        name in ".{0,16}",
+        description in proptest::collection::vec(any::<u8>(), 0..16),
+        layout in proptest::collection::vec(0u8..=25, 0..8),
+        field_names in proptest::collection::vec(any::<u8>(), 0..16),
+    ) {
+        let data = encode_schema(&credential, &name, &description, &layout, &field_names);
+        let schema = SchemaInterface::new(&data).unwrap();
+
+        prop_assert_eq!(schema.credential, credential);
+        prop_assert_eq!(schema.name, name);
+        prop_assert_eq!(schema.layout, layout);
+
+        for len in 0..data.len() {
+            prop_assert!(SchemaInterface::new(&data[..len]).is_err());
+        }
+        let mut trailing = data.clone();
+        trailing.push(0);
+        prop_assert!(SchemaInterface::new(&trailing).is_err());
+    }
+
+    #[test]
+    fn attestation_round_trip(
+        credential in pubkey(),
+        schema in pubkey(),
+        signer in pubkey(),
+        expiry in any::<i64>(),
+        (layout, fields) in layout_and_fields(),
+    ) {
+        let data = encode_attestation(&credential, &schema, &fields.concat(), &signer, expiry);
+        let attestation = AttestationInterface::new(&data).unwrap();
+
+        prop_assert_eq!(attestation.credential, credential);
+        prop_assert_eq!(attestation.schema, schema);
+        prop_assert_eq!(attestation.signer, signer);
+        prop_assert_eq!(attestation.expiry, expiry);
+        prop_assert_eq!(&attestation.verify_layout_and_parse(layout.clone()).unwrap(), &fields);
+
+        for len in 0..data.len() {
+            prop_assert!(AttestationInterface::new(&data[..len]).is_err());
+        }
+        // dropping any trailing byte of the attested data breaks the layout
+        if let Some(last) = fields.last().filter(|field| !field.is_empty()) {
+            let mut short = fields.clone();
+            short.last_mut().unwrap().truncate(last.len() - 1);
+            let data = encode_attestation(&credential, &schema, &short.concat(), &signer, expiry);
+            let attestation = AttestationInterface::new(&data).unwrap();
+            prop_assert!(attestation.verify_layout_and_parse(layout).is_err());
+        }
+    }
+
+    #[test]
+    fn arbitrary_bytes_never_panic(
+        data in proptest::collection::vec(any::<u8>(), 0..256),
+        layout in proptest::collection::vec(any::<u8>(), 0..8),
+    ) {
+        let _ = CredentialInterface::new(&data);
+        let _ = SchemaInterface::new(&data);
+        if let Ok(attestation) = AttestationInterface::new(&data) {
+            let _ = attestation.verify_layout_and_parse(layout.clone());
+        }
+        let attestation = AttestationInterface {
+            credential: Pubkey::default(),
+            schema: Pubkey::default(),
+            data,
+            signer: Pubkey::default(),
+            expiry: 0,
+        };
+        let _ = attestation.verify_layout_and_parse(layout);
+    }
+}
+
+#[test]
+fn rejects_empty_and_foreign_accounts() {
+    assert_error(CredentialInterface::new(&[]), DesciplineError::InvalidCredentialData);
+    assert_error(SchemaInterface::new(&[]), DesciplineError::InvalidSchemaData);
+    assert_error(AttestationInterface::new(&[]), DesciplineError::InvalidAttestationData);
+
+    let schema = encode_schema(&Pubkey::new_unique(), "schema", b"", &[13], b"");
+    assert_error(CredentialInterface::new(&schema), DesciplineError::InvalidAccountData);
+    assert_error(AttestationInterface::new(&schema), DesciplineError::InvalidAccountData);
+}
+
+#[test]
+fn rejects_oversized_lengths() {
+    let mut credential = encode_credential(&Pubkey::new_unique(), "credential", &[Pubkey::new_unique()]);
+    // signer count claims far more signers than the account holds
+    let count_at = 1 + 32 + 4 + 10;
+    credential[count_at..count_at + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_error(CredentialInterface::new(&credential), DesciplineError::InvalidCredentialData);
+
+    let mut schema = encode_schema(&Pubkey::new_unique(), "schema", b"", &[13], b"");
+    schema[33..37].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_error(SchemaInterface::new(&schema), DesciplineError::InvalidSchemaData);
+
+    let mut attestation = encode_attestation(&Pubkey::new_unique(), &Pubkey::new_unique(), &[1], &Pubkey::new_unique(), 0);
+    attestation[97..101].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_error(AttestationInterface::new(&attestation), DesciplineError::InvalidAttestationData);
+
+    let attestation = AttestationInterface {
+        credential: Pubkey::default(),
+        schema: Pubkey::default(),
+        data: u32::MAX.to_le_bytes().to_vec(),
+        signer: Pubkey::default(),
+        expiry: 0,
+    };
+    for data_type in [SchemaDataTypes::VecU128, SchemaDataTypes::VecString, SchemaDataTypes::String] {
+        assert_error(
+            attestation.verify_layout_and_parse(vec![data_type as u8]),
+            DesciplineError::InvalidAttestationData,
+        );
+    }
+}
+
+#[test]
+fn reads_layout_after_its_length_prefix() {
+    let data = encode_schema(&Pubkey::new_unique(), "OFFICIAL-PROOF-SCHEMA", b"proof", &[13, 13, 0, 13], b"names");
+    assert_eq!(SchemaInterface::new(&data).unwrap().layout, vec![13, 13, 0, 13]);
+}
+
+#[test]
+fn loads_only_attestation_service_accounts() {
+    let credential = Pubkey::new_unique();
+    let data = encode_schema(&credential, "schema", b"", &[13], b"");
+    let key = Pubkey::new_unique();
+
+    for (owner, loaded) in [(ATTESTATION_PROGRAM_ID, true), (Pubkey::new_unique(), false)] {
+        let (mut lamports, mut data) = (1, data.clone());
+        let account = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+
+        match SchemaInterface::load(&account) {
+            Ok(schema) => {
+                assert!(loaded);
+                assert_eq!(schema.credential, credential);
+            }
+            Err(err) => {
+                assert!(!loaded);
+                assert_eq!(
+                    ProgramError::from(err),
+                    ProgramError::Custom(DesciplineError::InvalidAccountOwner.into())
+                );
+            }
+        }
+        assert_error(CredentialInterface::load(&account), if loaded {
+            DesciplineError::InvalidAccountData
+        } else {
+            DesciplineError::InvalidAccountOwner
+        });
+        assert_error(AttestationInterface::load(&account), if loaded {
+            DesciplineError::InvalidAccountData
+        } else {
+            DesciplineError::InvalidAccountOwner
+        });
+    }
+}
+
+#[test]
+fn rejects_expired_attestations() {
+    let data = encode_attestation(&Pubkey::new_unique(), &Pubkey::new_unique(), &[], &Pubkey::new_unique(), 100);
+    let attestation = AttestationInterface::new(&data).unwrap();
+
+    attestation.verify_expiry(99).unwrap();
+    assert_error(attestation.verify_expiry(100), DesciplineError::AttestationExpired);
+
+    let data = encode_attestation(&Pubkey::new_unique(), &Pubkey::new_unique(), &[], &Pubkey::new_unique(), 0);
+    AttestationInterface::new(&data).unwrap().verify_expiry(i64::MAX).unwrap();
+}
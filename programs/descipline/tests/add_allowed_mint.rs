@@ -0,0 +1,122 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use anchor_lang::{prelude::*, system_program, Accounts};
+use anchor_spl::{
+    token::spl_token,
+    token_2022::spl_token_2022::{self, extension::ExtensionType},
+};
+use descipline::{
+    error::DesciplineError,
+    instructions::{AddAllowedMint, AddAllowedMintBumps},
+    state::{AllowedMint, CredentialAuthority},
+};
+
+use common::*;
+
+const MIN_STAKE: u64 = 100;
+const MAX_STAKE: u64 = 10_000;
+
+fn allowed_mint_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"allowed_mint", mint.as_ref()], &descipline::ID)
+}
+
+// The authority signer registering `stake_mint`, already allowed with `registered` when given.
+fn add(
+    stake_mint: &'static AccountInfo<'static>,
+    registered: Option<AllowedMint>,
+) -> (AddAllowedMint<'static>, AddAllowedMintBumps) {
+    let signer = signer(wallet(1_000_000_000));
+    let (authority_key, authority_bump) = Pubkey::find_program_address(&[b"authority"], &descipline::ID);
+    let authority = CredentialAuthority {
+        signer: *signer.key,
+        treasury: *signer.key,
+        protocol_fee: 0,
+        challenge_count: 0,
+        bump: authority_bump,
+    };
+    let (allowed_mint, _) = allowed_mint_address(stake_mint.key);
+    let allowed_mint = match registered {
+        Some(registered) => program_account(allowed_mint, &registered),
+        None => account(allowed_mint, system_program::ID, 0, vec![]),
+    };
+
+    let mut infos = accounts(&[
+        signer,
+        program_account(authority_key, &authority),
+        allowed_mint,
+        stake_mint,
+        program(system_program::ID),
+    ]);
+    let mut bumps = AddAllowedMintBumps::default();
+    let accounts = AddAllowedMint::try_accounts(&descipline::ID, &mut infos, &[], &mut bumps, &mut BTreeSet::new())
+        .unwrap();
+    (accounts, bumps)
+}
+
+fn stake_mint(program_id: Pubkey) -> &'static AccountInfo<'static> {
+    setup();
+    mint(program_id, Pubkey::new_unique(), Pubkey::new_unique(), None)
+}
+
+#[test]
+fn registers_new_mint() {
+    let (mut accounts, bumps) = add(stake_mint(spl_token::ID), None);
+
+    accounts.add_allowed_mint(MIN_STAKE, MAX_STAKE, &bumps).unwrap();
+
+    let allowed_mint = &accounts.allowed_mint;
+    let (_, bump) = allowed_mint_address(&accounts.stake_mint.key());
+    assert_eq!(allowed_mint.mint, accounts.stake_mint.key());
+    assert_eq!((allowed_mint.min_stake, allowed_mint.max_stake), (MIN_STAKE, MAX_STAKE));
+    assert_eq!(allowed_mint.bump, bump);
+    assert_eq!(allowed_mint.to_account_info().lamports(), rent_exempt(8 + AllowedMint::INIT_SPACE));
+}
+
+#[test]
+fn updates_limits_of_registered_mint() {
+    let stake_mint = stake_mint(spl_token::ID);
+    let (_, bump) = allowed_mint_address(stake_mint.key);
+    let registered = AllowedMint { mint: *stake_mint.key, min_stake: 1, max_stake: 2, bump };
+    let (mut accounts, bumps) = add(stake_mint, Some(registered));
+    let rent = accounts.allowed_mint.to_account_info().lamports();
+
+    accounts.add_allowed_mint(MIN_STAKE, MAX_STAKE, &bumps).unwrap();
+
+    let allowed_mint = &accounts.allowed_mint;
+    assert_eq!((allowed_mint.min_stake, allowed_mint.max_stake), (MIN_STAKE, MAX_STAKE));
+    assert_eq!(allowed_mint.to_account_info().lamports(), rent);
+    assert_eq!(accounts.signer.lamports(), 1_000_000_000);
+}
+
+#[test]
+fn rejects_empty_stake_limits() {
+    let (mut accounts, bumps) = add(stake_mint(spl_token::ID), None);
+
+    assert_error(accounts.add_allowed_mint(0, MAX_STAKE, &bumps), DesciplineError::InvalidStakeAmount);
+    assert_error(accounts.add_allowed_mint(MAX_STAKE + 1, MAX_STAKE, &bumps), DesciplineError::InvalidStakeAmount);
+    accounts.add_allowed_mint(MAX_STAKE, MAX_STAKE, &bumps).unwrap();
+}
+
+#[test]
+fn registers_token_2022_mint_with_transfer_fee() {
+    setup();
+    let stake_mint = mint(spl_token_2022::ID, Pubkey::new_unique(), Pubkey::new_unique(), Some(100));
+    let (mut accounts, bumps) = add(stake_mint, None);
+
+    accounts.add_allowed_mint(MIN_STAKE, MAX_STAKE, &bumps).unwrap();
+}
+
+#[test]
+fn rejects_token_2022_extensions_the_vault_cant_hold() {
+    for extension in [ExtensionType::PermanentDelegate, ExtensionType::TransferHook, ExtensionType::NonTransferable] {
+        setup();
+        let stake_mint = extension_mint(Pubkey::new_unique(), Pubkey::new_unique(), extension);
+        let (mut accounts, bumps) = add(stake_mint, None);
+
+        let result = accounts.add_allowed_mint(MIN_STAKE, MAX_STAKE, &bumps);
+
+        assert_error(result, DesciplineError::UnsupportedMintExtension);
+    }
+}
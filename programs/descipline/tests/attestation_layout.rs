@@ -0,0 +1,220 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use descipline::{
+    constants::SchemaDataTypes, error::DesciplineError, interfaces::AttestationInterface,
+    state::ResolutionFields,
+};
+
+use common::*;
+
+fn attestation(data: Vec<u8>) -> AttestationInterface {
+    AttestationInterface {
+        credential: Pubkey::new_unique(),
+        schema: Pubkey::new_unique(),
+        data,
+        signer: Pubkey::new_unique(),
+        expiry: 0,
+    }
+}
+
+fn vec_field(len: u32, body: &[u8]) -> Vec<u8> {
+    let mut field = len.to_le_bytes().to_vec();
+    field.extend_from_slice(body);
+    field
+}
+
+#[test]
+fn parses_every_data_type() {
+    let fields: Vec<(u8, Vec<u8>)> = vec![
+        (0, vec![7]),
+        (1, 7u16.to_le_bytes().to_vec()),
+        (2, 7u32.to_le_bytes().to_vec()),
+        (3, 7u64.to_le_bytes().to_vec()),
+        (4, 7u128.to_le_bytes().to_vec()),
+        (5, (-7i8).to_le_bytes().to_vec()),
+        (6, (-7i16).to_le_bytes().to_vec()),
+        (7, (-7i32).to_le_bytes().to_vec()),
+        (8, (-7i64).to_le_bytes().to_vec()),
+        (9, (-7i128).to_le_bytes().to_vec()),
+        (10, vec![1]),
+        (11, ('d' as u32).to_le_bytes().to_vec()),
+        (12, vec_field(4, b"done")),
+        (13, vec_field(2, &[1, 2])),
+        (14, vec_field(2, &[1, 0, 2, 0])),
+        (15, vec_field(1, &7u32.to_le_bytes())),
+        (16, vec_field(1, &7u64.to_le_bytes())),
+        (17, vec_field(1, &7u128.to_le_bytes())),
+        (18, vec_field(2, &[0xff, 1])),
+        (19, vec_field(1, &(-7i16).to_le_bytes())),
+        (20, vec_field(1, &(-7i32).to_le_bytes())),
+        (21, vec_field(1, &(-7i64).to_le_bytes())),
+        (22, vec_field(1, &(-7i128).to_le_bytes())),
+        (23, vec_field(3, &[1, 0, 1])),
+        (24, vec_field(1, &('d' as u32).to_le_bytes())),
+        (25, [vec_field(2, &[]), vec_field(2, b"hi"), vec_field(0, &[])].concat()),
+    ];
+
+    let layout: Vec<u8> = fields.iter().map(|(data_type, _)| *data_type).collect();
+    let data: Vec<u8> = fields.iter().flat_map(|(_, field)| field.clone()).collect();
+
+    let parsed = attestation(data).verify_layout_and_parse(layout).unwrap();
+    let expected: Vec<Vec<u8>> = fields.into_iter().map(|(_, field)| field).collect();
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn parses_official_layout() {
+    let data = [
+        vec_field(32, &[1; 32]),
+        vec_field(32, &[2; 32]),
+        vec![3],
+        vec_field(3, b"uri"),
+    ]
+    .concat();
+
+    let parsed = attestation(data).verify_layout_and_parse(vec![13, 13, 0, 13]).unwrap();
+    assert_eq!(parsed.len(), 4);
+    assert_eq!(parsed[2], vec![3]);
+}
+
+#[test]
+fn rejects_unknown_data_type() {
+    assert_error(SchemaDataTypes::try_from(26), DesciplineError::InvalidDataLayout);
+    assert_error(
+        attestation(vec![1]).verify_layout_and_parse(vec![200]),
+        DesciplineError::InvalidDataLayout,
+    );
+}
+
+#[test]
+fn rejects_invalid_values() {
+    assert_error(
+        attestation(vec![2]).verify_layout_and_parse(vec![10]),
+        DesciplineError::InvalidAttestationData,
+    );
+    assert_error(
+        attestation(0xd800u32.to_le_bytes().to_vec()).verify_layout_and_parse(vec![11]),
+        DesciplineError::InvalidAttestationData,
+    );
+    assert_error(
+        attestation(vec_field(2, &[0xc3, 0x28])).verify_layout_and_parse(vec![12]),
+        DesciplineError::InvalidAttestationData,
+    );
+}
+
+#[test]
+fn rejects_trailing_and_missing_data() {
+    assert_error(
+        attestation(vec![1, 2]).verify_layout_and_parse(vec![0]),
+        DesciplineError::InvalidAttestationData,
+    );
+    assert_error(
+        attestation(vec![1]).verify_layout_and_parse(vec![1]),
+        DesciplineError::InvalidAttestationData,
+    );
+    assert_error(
+        attestation(vec_field(2, b"a")).verify_layout_and_parse(vec![12]),
+        DesciplineError::InvalidAttestationData,
+    );
+}
+
+#[test]
+fn reads_resolution_from_custom_layout() {
+    let challenge = Pubkey::new_unique();
+    // label, uri, winner_count, score, root_hash, challenge
+    let layout = vec![12, 12, 0, 3, 13, 13];
+    let data = [
+        vec_field(5, b"label"),
+        vec_field(3, b"uri"),
+        vec![4],
+        9u64.to_le_bytes().to_vec(),
+        vec_field(32, &[7; 32]),
+        vec_field(32, challenge.as_ref()),
+    ]
+    .concat();
+    let fields = ResolutionFields {
+        challenge: 5,
+        root_hash: 4,
+        winner_count: 2,
+        winner_list_uri: 1,
+        prize_allocated: Some(3),
+    };
+
+    fields.verify_layout(&layout).unwrap();
+    let parsed = attestation(data).verify_layout_and_parse(layout).unwrap();
+    let resolution = fields.read(&parsed).unwrap();
+
+    assert_eq!(resolution.challenge, challenge);
+    assert_eq!(resolution.root_hash, [7; 32]);
+    assert_eq!(resolution.winner_count, 4);
+    assert_eq!(resolution.winner_list_uri, vec_field(3, b"uri"));
+    assert_eq!(resolution.prize_allocated, Some(9));
+}
+
+#[test]
+fn rejects_incompatible_resolution_fields() {
+    let layout = [13, 13, 0, 13];
+    let official = ResolutionFields {
+        challenge: 0,
+        root_hash: 1,
+        winner_count: 2,
+        winner_list_uri: 3,
+        prize_allocated: None,
+    };
+    official.verify_layout(&layout).unwrap();
+
+    for fields in [
+        ResolutionFields { winner_count: 3, winner_list_uri: 2, ..official },
+        ResolutionFields { root_hash: 0, ..official },
+        ResolutionFields { winner_list_uri: 4, ..official },
+        // the attested total must be its own u64 field
+        ResolutionFields { prize_allocated: Some(2), ..official },
+        ResolutionFields { prize_allocated: Some(0), ..official },
+    ] {
+        assert_error(fields.verify_layout(&layout), DesciplineError::InvalidResolutionFields);
+    }
+}
+
+#[test]
+fn rejects_short_hash_field() {
+    let fields = ResolutionFields {
+        challenge: 0,
+        root_hash: 1,
+        winner_count: 2,
+        winner_list_uri: 3,
+        prize_allocated: None,
+    };
+    let data = [vec_field(32, &[1; 32]), vec_field(31, &[2; 31]), vec![1], vec_field(0, &[])].concat();
+    let parsed = attestation(data).verify_layout_and_parse(vec![13, 13, 0, 13]).unwrap();
+
+    assert_error(fields.read(&parsed).map(|_| ()), DesciplineError::InvalidAttestationData);
+}
+
+#[test]
+fn reads_wide_winner_count() {
+    let challenge = Pubkey::new_unique();
+    let data = [
+        vec_field(32, challenge.as_ref()),
+        vec_field(32, &[7; 32]),
+        70_000u32.to_le_bytes().to_vec(),
+        vec_field(3, b"uri"),
+    ]
+    .concat();
+    let layout = vec![13, 13, 2, 13];
+    let fields = ResolutionFields {
+        challenge: 0,
+        root_hash: 1,
+        winner_count: 2,
+        winner_list_uri: 3,
+        prize_allocated: None,
+    };
+
+    fields.verify_layout(&layout).unwrap();
+    let parsed = attestation(data).verify_layout_and_parse(layout).unwrap();
+    assert_eq!(fields.read(&parsed).unwrap().winner_count, 70_000);
+
+    // signed and 64 bit counts are not accepted
+    assert_error(fields.verify_layout(&[13, 13, 3, 13]), DesciplineError::InvalidResolutionFields);
+    assert_error(fields.verify_layout(&[13, 13, 7, 13]), DesciplineError::InvalidResolutionFields);
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token,
+    token::spl_token::{self, native_mint},
+    token_2022::spl_token_2022,
+};
+use descipline::{error::DesciplineError, instructions::CancelChallenge, state::ChallengeStatus};
+
+use common::*;
+
+const DUST: u64 = 5;
+
+// A challenge nobody staked in yet, `participants` pretends otherwise.
+fn cancel(native: bool, participants: u32, dust: u64) -> CancelChallenge<'static> {
+    setup();
+    let initiator = signer(wallet(1));
+    let mint_authority = wallet(0);
+    let (stake_mint, program_id) = if native {
+        (mint(spl_token::ID, native_mint::ID, Pubkey::new_unique(), None), spl_token::ID)
+    } else {
+        let key = Pubkey::new_unique();
+        (mint(spl_token_2022::ID, key, *mint_authority.key, None), spl_token_2022::ID)
+    };
+
+    let mut state = challenge(*initiator.key, *stake_mint.key);
+    state.participant_count = participants;
+    let (key, _) = challenge_address(&state.initiator, &state.name);
+    let (sol_vault, _) = sol_vault_address(&key);
+
+    let (vault, sol_vault, initiator_ata) = if native {
+        let sol_vault = account(sol_vault, system_program::ID, rent_exempt(0) + dust, vec![]);
+        (None, Some(system_account(sol_vault)), None)
+    } else {
+        let vault = token_account(stake_mint, Pubkey::new_unique(), key, mint_authority, dust);
+        let initiator_ata = token_account(stake_mint, Pubkey::new_unique(), *initiator.key, mint_authority, 0);
+        (Some(interface_token_account(vault)), None, Some(interface_token_account(initiator_ata)))
+    };
+
+    CancelChallenge {
+        initiator: Signer::try_from(initiator).unwrap(),
+        vault,
+        sol_vault,
+        challenge: load(program_account(key, &state)),
+        initiator_ata,
+        stake_mint: interface_mint(stake_mint),
+        associated_token_program: Program::try_from(program(associated_token::ID)).unwrap(),
+        token_program: token_program(program_id),
+        system_program: system(),
+    }
+}
+
+#[test]
+fn cancel_with_stakers_leaves_refunds() {
+    let mut accounts = cancel(false, 2, 2_000);
+
+    accounts.cancel_challenge().unwrap();
+
+    assert_eq!(accounts.challenge.status, ChallengeStatus::Cancelled);
+    assert_eq!(token_amount(&accounts.vault.as_ref().unwrap().to_account_info()), 2_000);
+    assert_eq!(*accounts.challenge.to_account_info().owner, descipline::ID);
+}
+
+#[test]
+fn cancel_without_stakers_sweeps_native_dust() {
+    let mut accounts = cancel(true, 0, DUST);
+    let challenge_rent = accounts.challenge.to_account_info().lamports();
+
+    accounts.cancel_challenge().unwrap();
+
+    assert_eq!(accounts.sol_vault.as_ref().unwrap().lamports(), 0);
+    assert_eq!(accounts.challenge.to_account_info().lamports(), 0);
+    assert_eq!(
+        accounts.initiator.lamports(),
+        1 + rent_exempt(0) + DUST + challenge_rent
+    );
+}
+
+#[test]
+fn cancel_without_stakers_sweeps_token_dust() {
+    let mut accounts = cancel(false, 0, DUST);
+    let vault_rent = accounts.vault.as_ref().unwrap().to_account_info().lamports();
+    let challenge_rent = accounts.challenge.to_account_info().lamports();
+
+    accounts.cancel_challenge().unwrap();
+
+    assert_eq!(token_amount(&accounts.initiator_ata.as_ref().unwrap().to_account_info()), DUST);
+    assert_eq!(accounts.vault.as_ref().unwrap().to_account_info().lamports(), 0);
+    assert_eq!(accounts.initiator.lamports(), 1 + vault_rent + challenge_rent);
+}
+
+#[test]
+fn cancel_without_stakers_after_stake_end_closes() {
+    let mut accounts = cancel(true, 0, DUST);
+    set_now(accounts.challenge.resolve_deadline + 1);
+
+    accounts.cancel_challenge().unwrap();
+
+    assert_eq!(accounts.challenge.status, ChallengeStatus::Closed);
+    assert_eq!(accounts.sol_vault.as_ref().unwrap().lamports(), 0);
+    assert_eq!(accounts.challenge.to_account_info().lamports(), 0);
+}
+
+#[test]
+fn cancel_with_stakers_after_stake_end_is_rejected() {
+    let mut accounts = cancel(false, 2, 2_000);
+    set_now(accounts.challenge.stake_end_at);
+
+    assert_error(accounts.cancel_challenge(), DesciplineError::StakeEnded);
+}
+
+#[test]
+fn cancel_of_resolved_challenge_is_rejected() {
+    let mut accounts = cancel(true, 0, DUST);
+    accounts.challenge.status = ChallengeStatus::Resolved;
+
+    assert_error(accounts.cancel_challenge(), DesciplineError::InvalidChallengeStatus);
+}
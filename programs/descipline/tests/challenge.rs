@@ -0,0 +1,88 @@
+mod common;
+
+use anchor_lang::{prelude::*, AccountSerialize};
+use descipline::{
+    constants::MAX_ATTESTORS,
+    error::DesciplineError,
+    state::{Challenge, ChallengeStatus, ZeroWinnerPolicy},
+};
+
+use common::*;
+
+fn challenge() -> Challenge {
+    common::challenge(Pubkey::new_unique(), Pubkey::new_unique())
+}
+
+#[test]
+fn claims_only_within_claim_window() {
+    let challenge = challenge();
+
+    assert_error(challenge.require_claim_window(199), DesciplineError::ClaimNotStarted);
+    challenge.require_claim_window(200).unwrap();
+    challenge.require_claim_window(299).unwrap();
+    assert_error(challenge.require_claim_window(300), DesciplineError::ClaimEnded);
+}
+
+#[test]
+fn caps_relayer_tip() {
+    let mut challenge = challenge();
+    challenge.max_relayer_tip = 50;
+
+    challenge.verify_relayer_tip(50, 1_000, 0).unwrap();
+    assert_error(challenge.verify_relayer_tip(51, 1_000, 0), DesciplineError::InvalidRelayerTip);
+}
+
+#[test]
+fn relayer_tip_fits_in_prize_after_fee() {
+    let mut challenge = challenge();
+    challenge.max_relayer_tip = 1_000;
+
+    // 100 payout with a 10 fee leaves at most 90 to tip
+    challenge.verify_relayer_tip(90, 100, 10).unwrap();
+    assert_error(challenge.verify_relayer_tip(91, 100, 10), DesciplineError::InvalidRelayerTip);
+    assert_error(challenge.verify_relayer_tip(1, 0, 0), DesciplineError::InvalidRelayerTip);
+}
+
+#[test]
+fn fee_and_net_add_up_to_prize() {
+    let mut challenge = challenge();
+    challenge.fee = 250;
+
+    for prize in [0, 1, 39, 40, 1_000, 12_345, u64::MAX] {
+        // the winner is paid prize - fee
+        let fee = challenge.fee_of(prize);
+        let net = prize.checked_sub(fee).unwrap();
+        assert_eq!(fee + net, prize);
+        assert_eq!(fee, prize / 40);
+    }
+    assert_eq!(challenge.fee_of(1_000), 25);
+}
+
+#[test]
+fn locks_open_challenge_after_stake_end() {
+    let mut challenge = challenge();
+    challenge.status = ChallengeStatus::Open;
+
+    challenge.refresh_status(99);
+    assert_eq!(challenge.status, ChallengeStatus::Open);
+    challenge.refresh_status(100);
+    assert_eq!(challenge.status, ChallengeStatus::Locked);
+}
+
+#[test]
+fn fits_its_space_with_every_policy() {
+    let mut challenge = challenge();
+    challenge.name = "n".repeat(32);
+    challenge.attestors = vec![Pubkey::new_unique(); MAX_ATTESTORS];
+
+    for zero_winner_policy in [
+        ZeroWinnerPolicy::Refund,
+        ZeroWinnerPolicy::Initiator,
+        ZeroWinnerPolicy::Donate { beneficiary: Pubkey::new_unique() },
+    ] {
+        challenge.zero_winner_policy = zero_winner_policy;
+        let mut data = Vec::new();
+        challenge.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= 8 + Challenge::INIT_SPACE);
+    }
+}
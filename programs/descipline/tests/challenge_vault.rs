@@ -0,0 +1,187 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::spl_token::{self, native_mint},
+    token_2022::spl_token_2022::{
+        self,
+        extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+    },
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use descipline::{error::DesciplineError, instructions::ChallengeVault, state::Challenge};
+
+use common::*;
+
+const STAKES: u64 = 3_000;
+
+struct NativeVault {
+    challenge: Account<'static, Challenge>,
+    sol_vault: SystemAccount<'static>,
+    stake_mint: InterfaceAccount<'static, Mint>,
+    token_program: Interface<'static, TokenInterface>,
+    system_program: Program<'static, System>,
+}
+
+impl NativeVault {
+    fn new(stakes: u64) -> Self {
+        setup();
+        let state = challenge(Pubkey::new_unique(), native_mint::ID);
+        let (key, _) = challenge_address(&state.initiator, &state.name);
+        let (sol_vault, _) = sol_vault_address(&key);
+        Self {
+            challenge: load(program_account(key, &state)),
+            sol_vault: system_account(account(
+                sol_vault,
+                anchor_lang::system_program::ID,
+                rent_exempt(0) + stakes,
+                vec![],
+            )),
+            stake_mint: interface_mint(mint(spl_token::ID, native_mint::ID, Pubkey::new_unique(), None)),
+            token_program: token_program(spl_token::ID),
+            system_program: system(),
+        }
+    }
+
+    fn vault(&self) -> ChallengeVault<'_, 'static> {
+        ChallengeVault::new(
+            &self.challenge,
+            None,
+            Some(&self.sol_vault),
+            &self.stake_mint,
+            &self.token_program,
+            &self.system_program,
+        )
+    }
+}
+
+#[test]
+fn native_balance_keeps_rent_reserve() {
+    let native = NativeVault::new(STAKES);
+
+    assert_eq!(native.vault().balance().unwrap(), STAKES);
+}
+
+#[test]
+fn native_payout_leaves_rent_reserve() {
+    let native = NativeVault::new(STAKES);
+    let winner = wallet(0);
+
+    native.vault().pay(winner, STAKES).unwrap();
+
+    assert_eq!(winner.lamports(), STAKES);
+    assert_eq!(native.vault().balance().unwrap(), 0);
+    assert_eq!(native.sol_vault.lamports(), rent_exempt(0));
+}
+
+#[test]
+fn native_close_pays_out_every_lamport() {
+    let native = NativeVault::new(STAKES);
+    let initiator = wallet(1);
+
+    native.vault().close(initiator).unwrap();
+
+    assert_eq!(native.sol_vault.lamports(), 0);
+    assert_eq!(initiator.lamports(), 1 + rent_exempt(0) + STAKES);
+}
+
+#[test]
+fn native_fee_goes_to_the_wallet() {
+    let native = NativeVault::new(STAKES);
+    let treasury = wallet(0);
+
+    native.vault().pay_fee(Some(treasury.clone()), None, 100).unwrap();
+
+    assert_eq!(treasury.lamports(), 100);
+    assert_eq!(native.vault().balance().unwrap(), STAKES - 100);
+}
+
+#[test]
+fn fee_needs_its_account_unless_zero() {
+    let native = NativeVault::new(STAKES);
+
+    native.vault().pay_fee(None, None, 0).unwrap();
+    assert_error(native.vault().pay_fee(None, None, 1), DesciplineError::MissingFeeAccount);
+}
+
+struct TokenVault {
+    challenge: Account<'static, Challenge>,
+    vault: InterfaceAccount<'static, TokenAccount>,
+    mint_info: &'static AccountInfo<'static>,
+    stake_mint: InterfaceAccount<'static, Mint>,
+    token_program: Interface<'static, TokenInterface>,
+    system_program: Program<'static, System>,
+}
+
+impl TokenVault {
+    // A Token-2022 vault of a mint charging `fee_bps` on transfers, staked once with `stakes`.
+    fn new(fee_bps: u16, stakes: u64) -> Self {
+        setup();
+        let mint_authority = wallet(0);
+        let stake_mint = mint(spl_token_2022::ID, Pubkey::new_unique(), *mint_authority.key, Some(fee_bps));
+        let state = challenge(Pubkey::new_unique(), *stake_mint.key);
+        let (key, _) = challenge_address(&state.initiator, &state.name);
+
+        let staker = wallet(0);
+        let staker_ata = token_account(stake_mint, Pubkey::new_unique(), *staker.key, mint_authority, stakes);
+        let vault = token_account(stake_mint, Pubkey::new_unique(), key, mint_authority, 0);
+        transfer(stake_mint, staker_ata, vault, staker, stakes);
+
+        Self {
+            challenge: load(program_account(key, &state)),
+            vault: interface_token_account(vault),
+            mint_info: stake_mint,
+            stake_mint: interface_mint(stake_mint),
+            token_program: token_program(spl_token_2022::ID),
+            system_program: system(),
+        }
+    }
+
+    fn vault(&self) -> ChallengeVault<'_, 'static> {
+        ChallengeVault::new(
+            &self.challenge,
+            Some(&self.vault),
+            None,
+            &self.stake_mint,
+            &self.token_program,
+            &self.system_program,
+        )
+    }
+}
+
+fn withheld_by_mint(mint: &AccountInfo) -> u64 {
+    let data = mint.try_borrow_data().unwrap();
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data).unwrap();
+    u64::from(mint.get_extension::<TransferFeeConfig>().unwrap().withheld_amount)
+}
+
+#[test]
+fn token_close_harvests_withheld_transfer_fees() {
+    // 1% of the stakes stays withheld in the vault
+    let token = TokenVault::new(100, STAKES);
+    let vault = token.vault();
+    let initiator = wallet(0);
+    let winner = token_account(token.mint_info, Pubkey::new_unique(), Pubkey::new_unique(), wallet(0), 0);
+
+    let balance = vault.balance().unwrap();
+    assert_eq!(balance, STAKES - 30);
+    vault.pay(winner, balance).unwrap();
+    vault.close(initiator).unwrap();
+
+    assert_eq!(token.vault.to_account_info().lamports(), 0);
+    assert_eq!(withheld_by_mint(token.mint_info), 30);
+}
+
+#[test]
+fn token_fee_goes_to_the_token_account() {
+    let token = TokenVault::new(0, STAKES);
+    let treasury = wallet(0);
+    let treasury_ata = token_account(token.mint_info, Pubkey::new_unique(), *treasury.key, wallet(0), 0);
+    let treasury_ata = interface_token_account(treasury_ata);
+
+    token.vault().pay_fee(Some(treasury.clone()), Some(&treasury_ata), 100).unwrap();
+
+    assert_eq!(token_amount(&treasury_ata.to_account_info()), 100);
+    assert_eq!(treasury.lamports(), 0);
+    assert_error(token.vault().pay_fee(Some(treasury.clone()), None, 1), DesciplineError::MissingFeeAccount);
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use descipline::{
+    error::DesciplineError,
+    state::{Challenge, ClaimedBitmap, CredentialAuthority, Receipt, ReceiptStatus, Resolution, SweepMode},
+};
+
+use common::*;
+
+const SHARE: u64 = 1_500;
+
+// 2 winners out of 3 stakers, each winner's equal share is SHARE.
+fn resolved() -> Resolved {
+    let resolved = Resolved::new(3, 2);
+    set_now(200);
+    resolved
+}
+
+#[test]
+fn first_claim_pays_its_share() {
+    let resolved = resolved();
+    let receipt_rent = resolved.receipts[0].lamports();
+
+    resolved.claim(0).unwrap();
+
+    let resolution = state::<Resolution>(resolved.resolution);
+    assert_eq!(resolution.winner_notclaim_count, 1);
+    assert_eq!(resolution.prize_paid, SHARE);
+    assert!(state::<ClaimedBitmap>(resolved.claimed).is_claimed(0));
+    assert_eq!(resolved.stakers[0].lamports(), SHARE + receipt_rent);
+    assert!(is_closed(resolved.receipts[0]));
+    assert_eq!(resolved.vault_balance(), 3 * STAKE - SHARE);
+}
+
+#[test]
+fn second_claim_of_a_winner_is_rejected() {
+    let resolved = resolved();
+    // the receipt outlives the claim
+    update::<Challenge>(resolved.challenge, |challenge| challenge.sweep_mode = SweepMode::Winners);
+
+    resolved.claim(0).unwrap();
+    assert_error(resolved.claim(0), DesciplineError::AlreadyClaimed);
+
+    let receipt = state::<Receipt>(resolved.receipts[0]);
+    assert!(receipt.status == ReceiptStatus::Claimed);
+    assert_eq!(receipt.prize, SHARE);
+    assert_eq!(resolved.stakers[0].lamports(), SHARE);
+    assert_eq!(resolved.vault_balance(), 3 * STAKE - SHARE);
+}
+
+#[test]
+fn claims_only_within_claim_window() {
+    let resolved = resolved();
+
+    set_now(199);
+    assert_error(resolved.claim(0), DesciplineError::ClaimNotStarted);
+    set_now(300);
+    assert_error(resolved.claim(0), DesciplineError::ClaimEnded);
+    assert_eq!(resolved.vault_balance(), 3 * STAKE);
+}
+
+#[test]
+fn last_claim_closes_everything() {
+    let resolved = resolved();
+    let challenge_rent = resolved.challenge.lamports();
+    let attestor_rent = resolved.resolution.lamports() + resolved.claimed.lamports();
+
+    resolved.claim(0).unwrap();
+    resolved.claim(1).unwrap();
+
+    for account in [resolved.challenge, resolved.resolution, resolved.claimed, resolved.sol_vault] {
+        assert!(is_closed(account));
+    }
+    assert_eq!(resolved.initiator.lamports(), challenge_rent + rent_exempt(0));
+    assert_eq!(resolved.attestor.lamports(), attestor_rent);
+    // the receipt of the staker who didn't win stays for close_receipt
+    assert!(!is_closed(resolved.receipts[2]));
+}
+
+#[test]
+fn last_claim_splits_the_fees() {
+    let resolved = resolved();
+    let challenge_rent = resolved.challenge.lamports();
+    // 10% of each prize, half of it to the protocol
+    update::<Challenge>(resolved.challenge, |challenge| challenge.fee = 1_000);
+    update::<CredentialAuthority>(resolved.credential_authority, |authority| authority.protocol_fee = 5_000);
+
+    resolved.claim(0).unwrap();
+    assert_eq!(resolved.vault_balance(), 3 * STAKE - SHARE + 150);
+    assert_eq!(state::<Resolution>(resolved.resolution).fee_accrued, 150);
+    assert_eq!(resolved.treasury.lamports(), 0);
+
+    resolved.claim(1).unwrap();
+    let receipt_rent = rent_exempt(8 + Receipt::INIT_SPACE);
+    assert_eq!(resolved.stakers[0].lamports(), SHARE - 150 + receipt_rent);
+    assert_eq!(resolved.stakers[1].lamports(), SHARE - 150 + receipt_rent);
+    assert_eq!(resolved.treasury.lamports(), 150);
+    assert_eq!(resolved.initiator.lamports(), 150 + challenge_rent + rent_exempt(0));
+}
@@ -0,0 +1,239 @@
+mod common;
+
+use anchor_lang::{prelude::*, AccountDeserialize, AccountSerialize};
+use descipline::{
+    error::DesciplineError,
+    instructions::{verify_address, verify_leaf, winner_leaf},
+    state::Resolution,
+};
+use svm_merkle_tree::{HashingAlgorithm, MerkleTree};
+
+use common::*;
+
+struct Whitelist {
+    winners: Vec<Pubkey>,
+    tree: MerkleTree,
+}
+
+impl Whitelist {
+    fn new(size: usize) -> Self {
+        let winners: Vec<Pubkey> = (0..size).map(|_| Pubkey::new_unique()).collect();
+        let mut tree = MerkleTree::new(HashingAlgorithm::Keccak, 32);
+        for winner in &winners {
+            tree.add_leaf(winner.as_ref());
+        }
+        tree.merklize().unwrap();
+
+        Self { winners, tree }
+    }
+
+    fn proof(&self, index: usize) -> Vec<u8> {
+        self.tree.merkle_proof_index(index).unwrap().get_pairing_hashes()
+    }
+
+    // Store the root the same way `resolve` does and read it back from account data.
+    fn resolution(&self) -> Resolution {
+        let mut resolution = resolution(self.winners.len() as u32, 0);
+        resolution.root_hash = self.tree.get_merkle_root().unwrap().try_into().unwrap();
+        resolution.winner_list_uri = b"https://arweave.net/winners".to_vec();
+        resolution.attestations = vec![Pubkey::new_unique()];
+
+        let mut data = Vec::new();
+        resolution.try_serialize(&mut data).unwrap();
+        Resolution::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+}
+
+fn verify(address: Pubkey, proof: Vec<u8>, index: u32, resolution: &Resolution) -> Result<()> {
+    verify_address(address, proof, index, resolution.winner_count, resolution.root_hash)
+}
+
+#[test]
+fn accepts_every_winner() {
+    let whitelist = Whitelist::new(5);
+    let resolution = whitelist.resolution();
+
+    for (index, winner) in whitelist.winners.iter().enumerate() {
+        verify(*winner, whitelist.proof(index), index as u32, &resolution).unwrap();
+    }
+}
+
+#[test]
+fn accepts_single_winner_without_proof() {
+    let whitelist = Whitelist::new(1);
+    let resolution = whitelist.resolution();
+
+    verify(whitelist.winners[0], whitelist.proof(0), 0, &resolution).unwrap();
+}
+
+#[test]
+fn rejects_non_winner_with_valid_proof() {
+    let whitelist = Whitelist::new(4);
+    let resolution = whitelist.resolution();
+
+    assert_error(
+        verify(Pubkey::new_unique(), whitelist.proof(1), 1, &resolution),
+        DesciplineError::MerkleRootMismatch,
+    );
+}
+
+#[test]
+fn rejects_proof_of_another_winner() {
+    let whitelist = Whitelist::new(4);
+    let resolution = whitelist.resolution();
+
+    assert_error(
+        verify(whitelist.winners[0], whitelist.proof(2), 0, &resolution),
+        DesciplineError::MerkleRootMismatch,
+    );
+}
+
+#[test]
+fn rejects_wrong_index() {
+    let whitelist = Whitelist::new(4);
+    let resolution = whitelist.resolution();
+
+    assert_error(
+        verify(whitelist.winners[1], whitelist.proof(1), 2, &resolution),
+        DesciplineError::MerkleRootMismatch,
+    );
+}
+
+#[test]
+fn rejects_index_outside_winner_count() {
+    let whitelist = Whitelist::new(3);
+    let resolution = whitelist.resolution();
+
+    assert_error(
+        verify(whitelist.winners[2], whitelist.proof(2), 3, &resolution),
+        DesciplineError::InvalidProofIndex,
+    );
+}
+
+#[test]
+fn rejects_index_deeper_than_proof() {
+    let whitelist = Whitelist::new(8);
+    let resolution = whitelist.resolution();
+
+    // a two level proof can only address leaves 0..4
+    let mut proof = whitelist.proof(5);
+    proof.truncate(64);
+
+    assert_error(
+        verify(whitelist.winners[5], proof, 5, &resolution),
+        DesciplineError::InvalidProofIndex,
+    );
+}
+
+#[test]
+fn rejects_truncated_proof() {
+    let whitelist = Whitelist::new(4);
+    let resolution = whitelist.resolution();
+
+    let mut proof = whitelist.proof(1);
+    proof.pop();
+
+    assert_error(
+        verify(whitelist.winners[1], proof, 1, &resolution),
+        DesciplineError::InvalidProof,
+    );
+}
+
+#[test]
+fn rejects_tampered_proof() {
+    let whitelist = Whitelist::new(4);
+    let resolution = whitelist.resolution();
+
+    let mut proof = whitelist.proof(3);
+    proof[0] ^= 1;
+
+    assert_error(
+        verify(whitelist.winners[3], proof, 3, &resolution),
+        DesciplineError::MerkleRootMismatch,
+    );
+}
+
+#[test]
+fn rejects_empty_proof_for_multi_winner_root() {
+    let whitelist = Whitelist::new(2);
+    let resolution = whitelist.resolution();
+
+    assert_error(
+        verify(whitelist.winners[0], Vec::new(), 0, &resolution),
+        DesciplineError::MerkleRootMismatch,
+    );
+}
+
+#[test]
+fn rejects_proof_against_another_root() {
+    let whitelist = Whitelist::new(4);
+    let other = Whitelist::new(4);
+
+    assert_error(
+        verify(whitelist.winners[0], whitelist.proof(0), 0, &other.resolution()),
+        DesciplineError::MerkleRootMismatch,
+    );
+}
+
+#[test]
+fn accepts_amount_leaves() {
+    let winners: Vec<(Pubkey, u64)> = [500, 300, 200].iter().map(|amount| (Pubkey::new_unique(), *amount)).collect();
+    let mut tree = MerkleTree::new(HashingAlgorithm::Keccak, 32);
+    for (winner, amount) in &winners {
+        tree.add_leaf(&winner_leaf(*winner, Some(*amount)));
+    }
+    tree.merklize().unwrap();
+    let root: [u8; 32] = tree.get_merkle_root().unwrap().try_into().unwrap();
+    let proof = |index: usize| tree.merkle_proof_index(index).unwrap().get_pairing_hashes();
+
+    for (index, (winner, amount)) in winners.iter().enumerate() {
+        verify_leaf(&winner_leaf(*winner, Some(*amount)), proof(index), index as u32, 3, root).unwrap();
+    }
+
+    // another prize, or no prize at all, does not match the leaf
+    let (winner, amount) = winners[2];
+    assert_error(
+        verify_leaf(&winner_leaf(winner, Some(amount + 1)), proof(2), 2, 3, root),
+        DesciplineError::MerkleRootMismatch,
+    );
+    assert_error(
+        verify_leaf(&winner_leaf(winner, None), proof(2), 2, 3, root),
+        DesciplineError::MerkleRootMismatch,
+    );
+}
+
+#[test]
+fn accepts_winners_of_large_tree() {
+    let whitelist = Whitelist::new(10_001);
+    let resolution = whitelist.resolution();
+
+    for index in [0, 255, 256, 4_096, 9_999, 10_000] {
+        // 14 levels, small enough for a single transaction
+        let proof = whitelist.proof(index);
+        assert_eq!(proof.len(), 14 * 32);
+        verify(whitelist.winners[index], proof, index as u32, &resolution).unwrap();
+    }
+
+    assert_error(
+        verify(whitelist.winners[300], whitelist.proof(300), 301, &resolution),
+        DesciplineError::MerkleRootMismatch,
+    );
+    assert_error(
+        verify(whitelist.winners[10_000], whitelist.proof(10_000), 10_001, &resolution),
+        DesciplineError::InvalidProofIndex,
+    );
+}
+
+#[test]
+fn rejects_proof_deeper_than_u32_index() {
+    let whitelist = Whitelist::new(2);
+    let resolution = whitelist.resolution();
+
+    let mut proof = whitelist.proof(0);
+    proof.resize(33 * 32, 0);
+
+    assert_error(
+        verify(whitelist.winners[0], proof, 0, &resolution),
+        DesciplineError::InvalidProof,
+    );
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use anchor_lang::{AccountDeserialize, AccountSerialize};
+use descipline::{constants::MAX_WINNER_COUNT, error::DesciplineError, state::ClaimedBitmap};
+
+use common::*;
+
+fn bitmap(winner_count: u32) -> ClaimedBitmap {
+    ClaimedBitmap {
+        bits: vec![0; ClaimedBitmap::len(winner_count)],
+        bump: 255,
+    }
+}
+
+#[test]
+fn claims_each_index_once() {
+    let mut claimed = bitmap(20);
+
+    for index in 0..20 {
+        assert!(!claimed.is_claimed(index));
+        claimed.claim(index).unwrap();
+        assert!(claimed.is_claimed(index));
+        assert_error(claimed.claim(index), DesciplineError::AlreadyClaimed);
+    }
+}
+
+#[test]
+fn claims_do_not_touch_other_indices() {
+    let mut claimed = bitmap(16);
+    claimed.claim(9).unwrap();
+
+    let set: Vec<u32> = (0..16).filter(|index| claimed.is_claimed(*index)).collect();
+    assert_eq!(set, vec![9]);
+}
+
+#[test]
+fn rejects_index_outside_bitmap() {
+    let mut claimed = bitmap(9);
+    claimed.claim(15).unwrap(); // padding bits of the last byte are never reachable through a valid proof
+    assert_error(claimed.claim(16), DesciplineError::InvalidProofIndex);
+}
+
+#[test]
+fn fits_its_space() {
+    for winner_count in [0, 1, 8, 9, 10_001, MAX_WINNER_COUNT] {
+        let mut claimed = bitmap(winner_count);
+        if winner_count > 0 {
+            claimed.claim(winner_count - 1).unwrap();
+        }
+
+        let mut data = Vec::new();
+        claimed.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), ClaimedBitmap::space(winner_count));
+
+        let stored = ClaimedBitmap::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(stored.bits, claimed.bits);
+    }
+    assert!(ClaimedBitmap::space(MAX_WINNER_COUNT) <= 10_240);
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use anchor_lang::{prelude::*, system_program};
+use anchor_lang::solana_program::program_error::ProgramError;
+use descipline::{
+    error::DesciplineError,
+    instructions::CloseReceipt,
+    state::{Receipt, ReceiptStatus},
+};
+
+use common::*;
+
+// A receipt staked in the challenge with `receipt_id`, the challenge at its address now has `challenge_id`.
+fn close_receipt(receipt_id: u64, challenge_id: u64) -> CloseReceipt<'static> {
+    setup();
+    let staker = signer(wallet(0));
+    let mut state = challenge(Pubkey::new_unique(), Pubkey::new_unique());
+    state.id = challenge_id;
+    let (key, _) = challenge_address(&state.initiator, &state.name);
+    let (address, bump) = Pubkey::find_program_address(
+        &[b"receipt", key.as_ref(), staker.key.as_ref()],
+        &descipline::ID,
+    );
+    let receipt = Receipt {
+        staker: *staker.key,
+        challenge_id: receipt_id,
+        amount: 1_000,
+        staked_at: 0,
+        status: ReceiptStatus::Staked,
+        prize: 0,
+        bump,
+    };
+
+    CloseReceipt {
+        staker: Signer::try_from(staker).unwrap(),
+        challenge: UncheckedAccount::try_from(program_account(key, &state)),
+        receipt: load(program_account(address, &receipt)),
+    }
+}
+
+#[test]
+fn closes_receipt_left_by_earlier_challenge() {
+    let mut accounts = close_receipt(0, 1);
+
+    accounts.close_receipt().unwrap();
+}
+
+#[test]
+fn closes_receipt_of_closed_challenge() {
+    let mut accounts = close_receipt(0, 0);
+    let challenge = accounts.challenge.to_account_info();
+    challenge.assign(&system_program::ID);
+    challenge.realloc(0, false).unwrap();
+
+    accounts.close_receipt().unwrap();
+}
+
+#[test]
+fn keeps_receipt_of_live_challenge() {
+    let mut accounts = close_receipt(1, 1);
+
+    let err = accounts.close_receipt().expect_err("the challenge is still open");
+
+    assert_eq!(ProgramError::from(err), ProgramError::Custom(DesciplineError::ChallengeNotClosed.into()));
+}
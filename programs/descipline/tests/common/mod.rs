@@ -0,0 +1,772 @@
+// Runs instruction handlers off chain: accounts live in leaked buffers laid out like the
+// runtime's input so `realloc` works, and CPIs to the system and token programs are
+// executed in process by the syscall stubs below.
+#![allow(dead_code)]
+
+use std::{cell::Cell, sync::Once};
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE, SUCCESS},
+        instruction::Instruction,
+        program_pack::Pack,
+        program_stubs::{set_syscall_stubs, SyscallStubs},
+    },
+    system_program, AccountsExit,
+};
+use anchor_spl::{
+    associated_token,
+    token::spl_token::{self, native_mint},
+    token_2022::spl_token_2022::{
+        self,
+        extension::{transfer_fee, transfer_hook, ExtensionType},
+    },
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use descipline::{
+    constants::Discriminators,
+    error::DesciplineError,
+    instructions::{winner_leaf, Claim},
+    state::{
+        Challenge, ChallengeStatus, ClaimedBitmap, CredentialAuthority, PayoutMode, Receipt,
+        ReceiptStatus, Resolution, ResolutionFields, SweepMode, ZeroWinnerPolicy,
+    },
+};
+use svm_merkle_tree::{HashingAlgorithm, MerkleTree};
+
+pub const DECIMALS: u8 = 6;
+
+thread_local! {
+    static NOW: Cell<i64> = const { Cell::new(0) };
+}
+
+pub fn set_now(now: i64) {
+    NOW.with(|cell| cell.set(now));
+}
+
+struct Runtime;
+
+impl SyscallStubs for Runtime {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: NOW.with(Cell::get),
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let signers = signers_seeds
+            .iter()
+            .map(|seeds| Pubkey::create_program_address(seeds, &descipline::ID).unwrap())
+            .collect::<Vec<_>>();
+        let accounts = instruction
+            .accounts
+            .iter()
+            .map(|meta| {
+                let mut info = account_infos
+                    .iter()
+                    .find(|info| info.key == &meta.pubkey)
+                    .expect("account missing from cpi")
+                    .clone();
+                info.is_signer |= signers.contains(info.key);
+                info
+            })
+            .collect::<Vec<_>>();
+
+        match instruction.program_id {
+            id if id == system_program::ID => system_instruction(&accounts, &instruction.data),
+            id if id == spl_token::ID => {
+                spl_token::processor::Processor::process(&id, &accounts, &instruction.data)
+            }
+            id if id == spl_token_2022::ID => {
+                spl_token_2022::processor::Processor::process(&id, &accounts, &instruction.data)
+            }
+            id => panic!("unexpected cpi to {id}"),
+        }
+    }
+}
+
+// The system instructions the program invokes, `init` creates accounts and vaults take transfers.
+fn system_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    match u32::from_le_bytes(data[..4].try_into().unwrap()) {
+        0 => create_account(accounts, data),
+        2 => system_transfer(accounts, data),
+        instruction => panic!("unexpected system instruction {instruction}"),
+    }
+}
+
+fn create_account(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let space = u64::from_le_bytes(data[12..20].try_into().unwrap());
+    let owner = Pubkey::try_from(&data[20..52]).unwrap();
+    let created = &accounts[1];
+    if !created.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if created.lamports() > 0 || created.owner != &system_program::ID {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+    system_transfer(accounts, data)?;
+    created.realloc(space as usize, true)?;
+    created.assign(&owner);
+    Ok(())
+}
+
+fn system_transfer(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let lamports = u64::from_le_bytes(data[4..12].try_into().unwrap());
+    let (from, to) = (&accounts[0], &accounts[1]);
+    if !from.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if from.owner != &system_program::ID {
+        return Err(ProgramError::IllegalOwner);
+    }
+    **from.try_borrow_mut_lamports()? = from
+        .lamports()
+        .checked_sub(lamports)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    **to.try_borrow_mut_lamports()? += lamports;
+    Ok(())
+}
+
+pub fn setup() {
+    static RUNTIME: Once = Once::new();
+    RUNTIME.call_once(|| {
+        set_syscall_stubs(Box::new(Runtime));
+    });
+    set_now(0);
+}
+
+pub fn assert_error<T>(result: Result<T>, expected: DesciplineError) {
+    match result {
+        Ok(_) => panic!("should be rejected"),
+        Err(err) => assert_eq!(ProgramError::from(err), ProgramError::Custom(expected.into())),
+    }
+}
+
+pub fn rent_exempt(len: usize) -> u64 {
+    Rent::default().minimum_balance(len)
+}
+
+// The runtime keeps the original data length in front of the key and the current one
+// in front of the data, `AccountInfo::realloc` reads and writes both.
+pub fn account(
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+) -> &'static AccountInfo<'static> {
+    let key_buf = Box::leak(Box::new([0u8; 36]));
+    key_buf[..4].copy_from_slice(&(data.len() as u32).to_le_bytes());
+    key_buf[4..].copy_from_slice(key.as_ref());
+    let key = unsafe { &*(key_buf.as_ptr().add(4) as *const Pubkey) };
+
+    let data_buf = vec![0u8; 8 + data.len() + MAX_PERMITTED_DATA_INCREASE].leak();
+    data_buf[..8].copy_from_slice(&(data.len() as u64).to_le_bytes());
+    data_buf[8..8 + data.len()].copy_from_slice(&data);
+
+    Box::leak(Box::new(AccountInfo::new(
+        key,
+        false,
+        true,
+        Box::leak(Box::new(lamports)),
+        &mut data_buf[8..8 + data.len()],
+        Box::leak(Box::new(owner)),
+        false,
+        0,
+    )))
+}
+
+pub fn wallet(lamports: u64) -> &'static AccountInfo<'static> {
+    account(Pubkey::new_unique(), system_program::ID, lamports, vec![])
+}
+
+pub fn signer(info: &'static AccountInfo<'static>) -> &'static AccountInfo<'static> {
+    let mut info = info.clone();
+    info.is_signer = true;
+    Box::leak(Box::new(info))
+}
+
+pub fn program(id: Pubkey) -> &'static AccountInfo<'static> {
+    let mut info = account(id, Pubkey::default(), 1, vec![]).clone();
+    info.executable = true;
+    info.is_writable = false;
+    Box::leak(Box::new(info))
+}
+
+// Anchor reads an optional account passed as the program id as `None`.
+pub fn none() -> &'static AccountInfo<'static> {
+    program(descipline::ID)
+}
+
+// Accounts in instruction order, for `Accounts::try_accounts` to check their constraints.
+pub fn accounts(infos: &[&'static AccountInfo<'static>]) -> &'static [AccountInfo<'static>] {
+    infos.iter().map(|info| (*info).clone()).collect::<Vec<_>>().leak()
+}
+
+pub fn system() -> Program<'static, System> {
+    Program::try_from(program(system_program::ID)).unwrap()
+}
+
+pub fn token_program(id: Pubkey) -> Interface<'static, TokenInterface> {
+    Interface::try_from(program(id)).unwrap()
+}
+
+fn process(program_id: Pubkey, instruction: Instruction, accounts: &[AccountInfo]) {
+    let result = if program_id == spl_token::ID {
+        spl_token::processor::Processor::process(&program_id, accounts, &instruction.data)
+    } else {
+        spl_token_2022::processor::Processor::process(&program_id, accounts, &instruction.data)
+    };
+    result.unwrap();
+}
+
+// A mint whose authority is `authority`, with a transfer fee of `fee_bps` when given.
+pub fn mint(
+    program_id: Pubkey,
+    key: Pubkey,
+    authority: Pubkey,
+    fee_bps: Option<u16>,
+) -> &'static AccountInfo<'static> {
+    let extensions = fee_bps
+        .map(|_| vec![ExtensionType::TransferFeeConfig])
+        .unwrap_or_default();
+    let len = if program_id == spl_token::ID {
+        spl_token::state::Mint::LEN
+    } else {
+        ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extensions)
+            .unwrap()
+    };
+    let mint = account(key, program_id, rent_exempt(len), vec![0; len]);
+
+    if let Some(fee_bps) = fee_bps {
+        let instruction = transfer_fee::instruction::initialize_transfer_fee_config(
+            &program_id,
+            &key,
+            Some(&authority),
+            Some(&authority),
+            fee_bps,
+            u64::MAX,
+        )
+        .unwrap();
+        process(program_id, instruction, std::slice::from_ref(mint));
+    }
+    let instruction = spl_token_2022::instruction::initialize_mint2(
+        &program_id,
+        &key,
+        &authority,
+        None,
+        DECIMALS,
+    )
+    .unwrap();
+    process(program_id, instruction, std::slice::from_ref(mint));
+    mint
+}
+
+// A Token-2022 mint whose authority is `authority`, carrying `extension` the vault can't hold.
+pub fn extension_mint(
+    key: Pubkey,
+    authority: Pubkey,
+    extension: ExtensionType,
+) -> &'static AccountInfo<'static> {
+    let program_id = spl_token_2022::ID;
+    let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[extension])
+        .unwrap();
+    let mint = account(key, program_id, rent_exempt(len), vec![0; len]);
+
+    let instruction = match extension {
+        ExtensionType::PermanentDelegate => {
+            spl_token_2022::instruction::initialize_permanent_delegate(&program_id, &key, &authority)
+        }
+        ExtensionType::TransferHook => transfer_hook::instruction::initialize(
+            &program_id,
+            &key,
+            Some(authority),
+            Some(Pubkey::new_unique()),
+        ),
+        ExtensionType::NonTransferable => {
+            spl_token_2022::instruction::initialize_non_transferable_mint(&program_id, &key)
+        }
+        extension => panic!("unexpected extension {extension:?}"),
+    }
+    .unwrap();
+    process(program_id, instruction, std::slice::from_ref(mint));
+    let instruction = spl_token_2022::instruction::initialize_mint2(
+        &program_id,
+        &key,
+        &authority,
+        None,
+        DECIMALS,
+    )
+    .unwrap();
+    process(program_id, instruction, std::slice::from_ref(mint));
+    mint
+}
+
+// A token account of `owner` holding `amount`, minted by `authority`.
+pub fn token_account(
+    mint: &'static AccountInfo<'static>,
+    key: Pubkey,
+    owner: Pubkey,
+    authority: &'static AccountInfo<'static>,
+    amount: u64,
+) -> &'static AccountInfo<'static> {
+    let program_id = *mint.owner;
+    let len = if program_id == spl_token::ID {
+        spl_token::state::Account::LEN
+    } else {
+        let mint_data = mint.try_borrow_data().unwrap();
+        let mint_state = spl_token_2022::extension::StateWithExtensions::<
+            spl_token_2022::state::Mint,
+        >::unpack(&mint_data)
+        .unwrap();
+        let extensions = ExtensionType::get_required_init_account_extensions(
+            &spl_token_2022::extension::BaseStateWithExtensions::get_extension_types(&mint_state)
+                .unwrap(),
+        );
+        ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&extensions)
+            .unwrap()
+    };
+    let token_account = account(key, program_id, rent_exempt(len), vec![0; len]);
+
+    let instruction = spl_token_2022::instruction::initialize_account3(
+        &program_id,
+        &key,
+        mint.key,
+        &owner,
+    )
+    .unwrap();
+    process(program_id, instruction, &[token_account.clone(), mint.clone()]);
+
+    if amount > 0 {
+        let instruction = spl_token_2022::instruction::mint_to(
+            &program_id,
+            mint.key,
+            &key,
+            authority.key,
+            &[],
+            amount,
+        )
+        .unwrap();
+        process(
+            program_id,
+            instruction,
+            &[mint.clone(), token_account.clone(), signer(authority).clone()],
+        );
+    }
+    token_account
+}
+
+// Moves tokens between token accounts, a transfer fee is withheld in `to`.
+pub fn transfer(
+    mint: &'static AccountInfo<'static>,
+    from: &'static AccountInfo<'static>,
+    to: &'static AccountInfo<'static>,
+    owner: &'static AccountInfo<'static>,
+    amount: u64,
+) {
+    let program_id = *mint.owner;
+    let instruction = spl_token_2022::instruction::transfer_checked(
+        &program_id,
+        from.key,
+        mint.key,
+        to.key,
+        owner.key,
+        &[],
+        amount,
+        DECIMALS,
+    )
+    .unwrap();
+    process(
+        program_id,
+        instruction,
+        &[from.clone(), mint.clone(), to.clone(), signer(owner).clone()],
+    );
+}
+
+pub fn token_amount(token_account: &AccountInfo) -> u64 {
+    let data = token_account.try_borrow_data().unwrap();
+    TokenAccount::try_deserialize(&mut &data[..]).unwrap().amount
+}
+
+pub fn interface_mint(info: &'static AccountInfo<'static>) -> InterfaceAccount<'static, Mint> {
+    InterfaceAccount::try_from(info).unwrap()
+}
+
+pub fn interface_token_account(
+    info: &'static AccountInfo<'static>,
+) -> InterfaceAccount<'static, TokenAccount> {
+    InterfaceAccount::try_from(info).unwrap()
+}
+
+pub fn system_account(info: &'static AccountInfo<'static>) -> SystemAccount<'static> {
+    SystemAccount::try_from(info).unwrap()
+}
+
+// A challenge staking `stake_mint`, at its PDA so the vault can sign for it.
+pub fn challenge(initiator: Pubkey, stake_mint: Pubkey) -> Challenge {
+    let name = "challenge".to_string();
+    let (key, bump) = challenge_address(&initiator, &name);
+    let (_, vault_bump) = sol_vault_address(&key);
+    Challenge {
+        name,
+        initiator,
+        id: 0,
+        stake_mint,
+        stake_amount: 1_000,
+        allow_top_up: false,
+        payout_mode: PayoutMode::Equal,
+        fee: 0,
+        stake_end_at: 100,
+        claim_start_from: 200,
+        claim_end_at: 300,
+        sweep_mode: SweepMode::Initiator,
+        zero_winner_policy: ZeroWinnerPolicy::Refund,
+        resolve_deadline: 150,
+        dispute_window: 0,
+        dispute_bond: 0,
+        max_relayer_tip: 0,
+        participant_count: 0,
+        schema: Pubkey::new_unique(),
+        credential: Pubkey::new_unique(),
+        resolution_fields: ResolutionFields {
+            challenge: 0,
+            root_hash: 1,
+            winner_count: 2,
+            winner_list_uri: 3,
+            prize_allocated: None,
+        },
+        attestors: vec![Pubkey::new_unique()],
+        threshold: 1,
+        status: ChallengeStatus::Open,
+        vault_bump,
+        bump,
+    }
+}
+
+pub fn challenge_address(initiator: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"challenge", initiator.as_ref(), name.as_bytes()],
+        &descipline::ID,
+    )
+}
+
+pub fn sol_vault_address(challenge: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"sol_vault", challenge.as_ref()], &descipline::ID)
+}
+
+pub fn receipt_address(challenge: &Pubkey, staker: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"receipt", challenge.as_ref(), staker.as_ref()],
+        &descipline::ID,
+    )
+}
+
+pub fn dispute_address(challenge: &Pubkey, disputer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"dispute", challenge.as_ref(), disputer.as_ref()],
+        &descipline::ID,
+    )
+}
+
+// A resolution of `winner_count` winners sharing `prize_pool`, resolved at 0.
+pub fn resolution(winner_count: u32, prize_pool: u64) -> Resolution {
+    let mut resolution = Resolution {
+        root_hash: [0; 32],
+        winner_count,
+        winner_notclaim_count: winner_count,
+        winner_list_uri: Vec::new(),
+        prize_pool: 0,
+        prize_allocated: 0,
+        prize_share: 0,
+        prize_paid: 0,
+        fee_accrued: 0,
+        attestor: Pubkey::new_unique(),
+        attestations: Vec::new(),
+        resolved_at: 0,
+        open_disputes: 0,
+        swept_count: 0,
+        bump: 255,
+    };
+    resolution.set_prize_pool(prize_pool, None).unwrap();
+    resolution
+}
+
+// Stores `state` in a program owned account at `key`, the way `init` leaves it.
+pub fn program_account<T: AccountSerialize + Space>(
+    key: Pubkey,
+    state: &T,
+) -> &'static AccountInfo<'static> {
+    program_account_with_space(key, state, 8 + T::INIT_SPACE)
+}
+
+pub fn program_account_with_space<T: AccountSerialize>(
+    key: Pubkey,
+    state: &T,
+    space: usize,
+) -> &'static AccountInfo<'static> {
+    let mut data = Vec::with_capacity(space);
+    state.try_serialize(&mut data).unwrap();
+    data.resize(space, 0);
+    account(key, descipline::ID, rent_exempt(space), data)
+}
+
+pub fn load<T: AccountSerialize + AccountDeserialize + Owner + Clone>(
+    info: &'static AccountInfo<'static>,
+) -> Account<'static, T> {
+    Account::try_from(info).unwrap()
+}
+
+// Reads back the state a handler left in `info`.
+pub fn state<T: AccountDeserialize>(info: &AccountInfo) -> T {
+    T::try_deserialize(&mut &info.try_borrow_data().unwrap()[..]).unwrap()
+}
+
+// Rewrites the state stored in `info`, for tests to set up what earlier instructions would have.
+pub fn update<T: AccountSerialize + AccountDeserialize>(
+    info: &AccountInfo,
+    change: impl FnOnce(&mut T),
+) {
+    let mut value = state::<T>(info);
+    change(&mut value);
+    value
+        .try_serialize(&mut &mut info.try_borrow_mut_data().unwrap()[..])
+        .unwrap();
+}
+
+// Closed accounts are handed back to the system program without lamports.
+pub fn is_closed(info: &AccountInfo) -> bool {
+    info.lamports() == 0 && *info.owner == system_program::ID
+}
+
+// Runs `handler` and writes the accounts back the way the runtime does, closing those marked `close`.
+pub fn run<'info, T: AccountsExit<'info>>(
+    mut accounts: T,
+    handler: impl FnOnce(&mut T) -> Result<()>,
+) -> Result<()> {
+    handler(&mut accounts)?;
+    accounts.exit(&descipline::ID)
+}
+
+pub const STAKE: u64 = 1_000;
+
+// A native SOL challenge of `stakers` stakes of STAKE, resolved at 0 with its first `winners` stakers
+// sharing every stake equally, or allocated `amounts` of it. Accounts sit at their addresses, so
+// handlers can run one after another.
+pub struct Resolved {
+    pub challenge: &'static AccountInfo<'static>,
+    pub sol_vault: &'static AccountInfo<'static>,
+    pub resolution: &'static AccountInfo<'static>,
+    pub claimed: &'static AccountInfo<'static>,
+    pub credential_authority: &'static AccountInfo<'static>,
+    pub authority: &'static AccountInfo<'static>,
+    pub treasury: &'static AccountInfo<'static>,
+    pub initiator: &'static AccountInfo<'static>,
+    pub attestor: &'static AccountInfo<'static>,
+    pub stake_mint: &'static AccountInfo<'static>,
+    pub stakers: Vec<&'static AccountInfo<'static>>,
+    pub receipts: Vec<&'static AccountInfo<'static>>,
+    amounts: Option<Vec<u64>>,
+    tree: MerkleTree,
+}
+
+impl Resolved {
+    pub fn new(stakers: usize, winners: usize) -> Self {
+        Self::build(stakers, winners, None)
+    }
+
+    // Amount leaves of the first stakers, whatever they leave unallocated stays with the initiator.
+    pub fn with_amounts(stakers: usize, amounts: &[u64]) -> Self {
+        Self::build(stakers, amounts.len(), Some(amounts.to_vec()))
+    }
+
+    fn build(stakers: usize, winners: usize, amounts: Option<Vec<u64>>) -> Self {
+        setup();
+        let initiator = wallet(0);
+        let mut challenge = challenge(*initiator.key, native_mint::ID);
+        challenge.status = ChallengeStatus::Resolved;
+        challenge.participant_count = stakers as u32;
+        if amounts.is_some() {
+            challenge.payout_mode = PayoutMode::Amount;
+        }
+        let (key, _) = challenge_address(&challenge.initiator, &challenge.name);
+        let (sol_vault, _) = sol_vault_address(&key);
+        let prize_pool = STAKE * stakers as u64;
+
+        let stakers: Vec<_> = (0..stakers).map(|_| wallet(0)).collect();
+        let receipts = stakers
+            .iter()
+            .map(|staker| {
+                let (address, bump) = receipt_address(&key, staker.key);
+                let receipt = Receipt {
+                    staker: *staker.key,
+                    challenge_id: challenge.id,
+                    amount: STAKE,
+                    staked_at: 0,
+                    status: ReceiptStatus::Staked,
+                    prize: 0,
+                    bump,
+                };
+                program_account(address, &receipt)
+            })
+            .collect();
+
+        let mut tree = MerkleTree::new(HashingAlgorithm::Keccak, 32);
+        for (index, staker) in stakers[..winners].iter().enumerate() {
+            let amount = amounts.as_ref().map(|amounts| amounts[index]);
+            tree.add_leaf(&winner_leaf(*staker.key, amount));
+        }
+        let attestor = wallet(0);
+        let (resolution_key, bump) =
+            Pubkey::find_program_address(&[b"resolution", key.as_ref()], &descipline::ID);
+        let mut resolution = resolution(winners as u32, prize_pool);
+        resolution.attestor = *attestor.key;
+        resolution.bump = bump;
+        let allocated = amounts.as_ref().map(|amounts| amounts.iter().sum());
+        resolution.set_prize_pool(prize_pool, allocated).unwrap();
+        if winners > 0 {
+            tree.merklize().unwrap();
+            resolution.root_hash = tree.get_merkle_root().unwrap().try_into().unwrap();
+        }
+        let (claimed_key, bump) =
+            Pubkey::find_program_address(&[b"claimed", key.as_ref()], &descipline::ID);
+        let claimed = ClaimedBitmap { bits: vec![0; ClaimedBitmap::len(winners as u32)], bump };
+
+        let authority = signer(wallet(0));
+        let treasury = wallet(0);
+        let (authority_key, bump) = Pubkey::find_program_address(&[b"authority"], &descipline::ID);
+        let credential_authority = CredentialAuthority {
+            signer: *authority.key,
+            treasury: *treasury.key,
+            protocol_fee: 0,
+            challenge_count: 1,
+            bump,
+        };
+
+        Self {
+            challenge: program_account(key, &challenge),
+            sol_vault: account(sol_vault, system_program::ID, rent_exempt(0) + prize_pool, vec![]),
+            resolution: program_account(resolution_key, &resolution),
+            claimed: program_account_with_space(
+                claimed_key,
+                &claimed,
+                ClaimedBitmap::space(winners as u32),
+            ),
+            credential_authority: program_account(authority_key, &credential_authority),
+            authority,
+            treasury,
+            initiator,
+            attestor,
+            stake_mint: mint(spl_token::ID, native_mint::ID, Pubkey::new_unique(), None),
+            stakers,
+            receipts,
+            amounts,
+            tree,
+        }
+    }
+
+    // Stakes not paid out yet, besides the vault's rent reserve.
+    pub fn vault_balance(&self) -> u64 {
+        self.sol_vault.lamports() - rent_exempt(0)
+    }
+
+    // The winner at `index` claiming its prize with its own proof.
+    pub fn claim(&self, index: usize) -> Result<()> {
+        let accounts = Claim {
+            winner: Signer::try_from(signer(self.stakers[index])).unwrap(),
+            winner_ata: None,
+            vault: None,
+            sol_vault: Some(system_account(self.sol_vault)),
+            challenge: load(self.challenge),
+            resolution: load(self.resolution),
+            claimed: load(self.claimed),
+            receipt: load(self.receipts[index]),
+            credential_authority: load(self.credential_authority),
+            initiator_fee_ata: None,
+            treasury_ata: None,
+            treasury: Some(UncheckedAccount::try_from(self.treasury)),
+            initiator: Some(system_account(self.initiator)),
+            attestor: Some(system_account(self.attestor)),
+            stake_mint: interface_mint(self.stake_mint),
+            associated_token_program: Program::try_from(program(associated_token::ID)).unwrap(),
+            token_program: token_program(spl_token::ID),
+            system_program: system(),
+        };
+        let proof = self.tree.merkle_proof_index(index).unwrap().get_pairing_hashes();
+        let amount = self.amounts.as_ref().map(|amounts| amounts[index]);
+        run(accounts, |accounts| accounts.claim(proof, index as u32, amount))
+    }
+
+    // Receipts of `stakers` as the [receipt, staker] pairs batched payouts take in remaining accounts.
+    pub fn payouts(&self, stakers: &[usize]) -> Vec<AccountInfo<'static>> {
+        stakers
+            .iter()
+            .flat_map(|&index| [self.receipts[index].clone(), self.stakers[index].clone()])
+            .collect()
+    }
+}
+
+// Borsh encoders for the attestation service account formats.
+
+pub fn put_vec(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    out.extend_from_slice(bytes);
+}
+
+pub fn encode_credential(authority: &Pubkey, name: &str, signers: &[Pubkey]) -> Vec<u8> {
+    let mut out = vec![Discriminators::Credential as u8];
+    out.extend_from_slice(authority.as_ref());
+    put_vec(&mut out, name.as_bytes());
+    out.extend_from_slice(&(signers.len() as u32).to_le_bytes());
+    for signer in signers {
+        out.extend_from_slice(signer.as_ref());
+    }
+    out
+}
+
+pub fn encode_schema(
+    credential: &Pubkey,
+    name: &str,
+    description: &[u8],
+    layout: &[u8],
+    field_names: &[u8],
+) -> Vec<u8> {
+    let mut out = vec![Discriminators::Schema as u8];
+    out.extend_from_slice(credential.as_ref());
+    put_vec(&mut out, name.as_bytes());
+    put_vec(&mut out, description);
+    put_vec(&mut out, layout);
+    put_vec(&mut out, field_names);
+    out.extend_from_slice(&[0, 1]); // is_paused, version
+    out
+}
+
+pub fn encode_attestation(
+    credential: &Pubkey,
+    schema: &Pubkey,
+    data: &[u8],
+    signer: &Pubkey,
+    expiry: i64,
+) -> Vec<u8> {
+    let mut out = vec![Discriminators::Attestation as u8];
+    out.extend_from_slice(&[9; 32]); // nonce
+    out.extend_from_slice(credential.as_ref());
+    out.extend_from_slice(schema.as_ref());
+    put_vec(&mut out, data);
+    out.extend_from_slice(signer.as_ref());
+    out.extend_from_slice(&expiry.to_le_bytes());
+    out.extend_from_slice(&[0; 32]); // token account
+    out
+}
@@ -0,0 +1,232 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use anchor_lang::{error::ErrorOrigin, prelude::*, system_program, Accounts};
+use anchor_spl::{associated_token, token::spl_token::{self, native_mint}};
+use descipline::{
+    constants::{ATTESTATION_PROGRAM_ID, MAX_FEE},
+    error::DesciplineError,
+    instructions::{CreateChallenge, CreateChallengeBumps},
+    state::{AllowedMint, ChallengeStatus, CredentialAuthority, PayoutMode, ResolutionFields, SweepMode, ZeroWinnerPolicy},
+};
+
+use common::*;
+
+const CHALLENGE_COUNT: u64 = 4;
+const MIN_STAKE: u64 = 100;
+const MAX_STAKE: u64 = 10_000;
+
+// Arguments of `create_challenge`, by default those of `common::challenge`.
+struct Params {
+    stake_amount: u64,
+    payout_mode: PayoutMode,
+    fee: u16,
+    stake_end_at: i64,
+    claim_start_from: i64,
+    claim_end_at: i64,
+    resolve_deadline: i64,
+    dispute_window: i64,
+    dispute_bond: u64,
+    resolution_fields: ResolutionFields,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        let challenge = challenge(Pubkey::default(), native_mint::ID);
+        Self {
+            stake_amount: challenge.stake_amount,
+            payout_mode: challenge.payout_mode,
+            fee: challenge.fee,
+            stake_end_at: challenge.stake_end_at,
+            claim_start_from: challenge.claim_start_from,
+            claim_end_at: challenge.claim_end_at,
+            resolve_deadline: challenge.resolve_deadline,
+            dispute_window: challenge.dispute_window,
+            dispute_bond: challenge.dispute_bond,
+            resolution_fields: challenge.resolution_fields,
+        }
+    }
+}
+
+// A native SOL challenge of a credential with two signers, its accounts as `init` leaves them.
+fn accounts() -> CreateChallenge<'static> {
+    setup();
+    let initiator = signer(wallet(1_000_000_000));
+    let state = challenge(*initiator.key, native_mint::ID);
+    let (key, _) = challenge_address(&state.initiator, &state.name);
+    let (sol_vault, _) = sol_vault_address(&key);
+
+    let authority = CredentialAuthority {
+        signer: Pubkey::new_unique(),
+        treasury: Pubkey::new_unique(),
+        protocol_fee: 0,
+        challenge_count: CHALLENGE_COUNT,
+        bump: 255,
+    };
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let credential = encode_credential(&authority.signer, "credential", &signers);
+    let credential = account(state.credential, ATTESTATION_PROGRAM_ID, 1, credential);
+    let schema = encode_schema(credential.key, "schema", &[], &[13, 13, 0, 13], &[]);
+    let schema = account(state.schema, ATTESTATION_PROGRAM_ID, 1, schema);
+    let allowed_mint = AllowedMint { mint: native_mint::ID, min_stake: MIN_STAKE, max_stake: MAX_STAKE, bump: 255 };
+
+    CreateChallenge {
+        initiator: Signer::try_from(initiator).unwrap(),
+        vault: None,
+        sol_vault: Some(system_account(account(sol_vault, system_program::ID, 0, vec![]))),
+        challenge: load(program_account(key, &state)),
+        schema: UncheckedAccount::try_from(schema),
+        credential: UncheckedAccount::try_from(credential),
+        credential_authority: load(program_account(Pubkey::new_unique(), &authority)),
+        allowed_mint: load(program_account(Pubkey::new_unique(), &allowed_mint)),
+        stake_mint: interface_mint(mint(spl_token::ID, native_mint::ID, Pubkey::new_unique(), None)),
+        associated_token_program: Program::try_from(program(associated_token::ID)).unwrap(),
+        token_program: token_program(spl_token::ID),
+        system_program: system(),
+    }
+}
+
+fn create(accounts: &mut CreateChallenge<'static>, params: Params) -> Result<()> {
+    accounts.create_challenge(
+        "challenge".to_string(),
+        params.stake_amount,
+        false,
+        params.payout_mode,
+        params.fee,
+        params.stake_end_at,
+        params.claim_start_from,
+        params.claim_end_at,
+        SweepMode::Initiator,
+        ZeroWinnerPolicy::Refund,
+        params.resolve_deadline,
+        params.dispute_window,
+        params.dispute_bond,
+        0,
+        1,
+        params.resolution_fields,
+        &CreateChallengeBumps { challenge: 255, sol_vault: Some(254) },
+    )
+}
+
+#[test]
+fn creates_open_challenge() {
+    let mut accounts = accounts();
+
+    create(&mut accounts, Params::default()).unwrap();
+
+    let challenge = &accounts.challenge;
+    assert_eq!(challenge.status, ChallengeStatus::Open);
+    assert_eq!(challenge.id, CHALLENGE_COUNT);
+    assert_eq!(challenge.attestors.len(), 2);
+    assert_eq!(challenge.vault_bump, 254);
+    assert_eq!(accounts.credential_authority.challenge_count, CHALLENGE_COUNT + 1);
+    assert_eq!(accounts.sol_vault.as_ref().unwrap().lamports(), rent_exempt(0));
+}
+
+#[test]
+fn rejects_stake_end_not_in_the_future() {
+    let mut accounts = accounts();
+    set_now(100);
+
+    assert_error(create(&mut accounts, Params::default()), DesciplineError::ChallengeEnded);
+}
+
+#[test]
+fn rejects_claim_start_not_after_stake_end() {
+    let mut accounts = accounts();
+    let params = Params { claim_start_from: 100, ..Params::default() };
+
+    assert_error(create(&mut accounts, params), DesciplineError::ChallengeNotStarted);
+}
+
+#[test]
+fn rejects_resolve_deadline_not_after_stake_end() {
+    let mut accounts = accounts();
+    let params = Params { resolve_deadline: 100, ..Params::default() };
+
+    assert_error(create(&mut accounts, params), DesciplineError::InvalidResolveDeadline);
+}
+
+#[test]
+fn rejects_claim_end_not_after_claim_start() {
+    let mut accounts = accounts();
+    let params = Params { claim_end_at: 200, ..Params::default() };
+
+    assert_error(create(&mut accounts, params), DesciplineError::InvalidClaimDeadline);
+}
+
+#[test]
+fn rejects_claim_end_within_dispute_window_of_resolve_deadline() {
+    // resolved at the deadline of 150, claimable at 300 when the claim window closes
+    let params = || Params { dispute_window: 150, dispute_bond: 1, ..Params::default() };
+    assert_error(create(&mut accounts(), params()), DesciplineError::InvalidClaimDeadline);
+
+    create(&mut accounts(), Params { dispute_window: 149, ..params() }).unwrap();
+}
+
+#[test]
+fn rejects_fee_above_max_fee() {
+    let params = || Params { fee: MAX_FEE + 1, ..Params::default() };
+    assert_error(create(&mut accounts(), params()), DesciplineError::InvalidFeePercentage);
+
+    create(&mut accounts(), Params { fee: MAX_FEE, ..params() }).unwrap();
+}
+
+#[test]
+fn stakes_within_allowed_mint_limits() {
+    let params = |stake_amount| Params { stake_amount, ..Params::default() };
+
+    assert_error(create(&mut accounts(), params(MIN_STAKE - 1)), DesciplineError::InvalidStakeAmount);
+    assert_error(create(&mut accounts(), params(MAX_STAKE + 1)), DesciplineError::InvalidStakeAmount);
+    create(&mut accounts(), params(MIN_STAKE)).unwrap();
+    create(&mut accounts(), params(MAX_STAKE)).unwrap();
+}
+
+#[test]
+fn rejects_mint_not_registered() {
+    setup();
+    let initiator = signer(wallet(1_000_000_000));
+    let stake_mint = mint(spl_token::ID, Pubkey::new_unique(), Pubkey::new_unique(), None);
+    let (key, _) = challenge_address(initiator.key, "challenge");
+    let (authority_key, bump) = Pubkey::find_program_address(&[b"authority"], &descipline::ID);
+    let authority = CredentialAuthority {
+        signer: Pubkey::new_unique(),
+        treasury: Pubkey::new_unique(),
+        protocol_fee: 0,
+        challenge_count: CHALLENGE_COUNT,
+        bump,
+    };
+    let (allowed_mint, _) =
+        Pubkey::find_program_address(&[b"allowed_mint", stake_mint.key.as_ref()], &descipline::ID);
+
+    let mut infos = common::accounts(&[
+        initiator,
+        none(),
+        account(Pubkey::new_unique(), system_program::ID, 0, vec![]),
+        account(key, system_program::ID, 0, vec![]),
+        wallet(0),
+        wallet(0),
+        program_account(authority_key, &authority),
+        account(allowed_mint, system_program::ID, 0, vec![]),
+        stake_mint,
+        program(associated_token::ID),
+        program(spl_token::ID),
+        program(system_program::ID),
+    ]);
+    let mut name = Vec::new();
+    put_vec(&mut name, b"challenge");
+    let result = CreateChallenge::try_accounts(
+        &descipline::ID,
+        &mut infos,
+        &name,
+        &mut CreateChallengeBumps::default(),
+        &mut BTreeSet::new(),
+    );
+
+    // the registry entry is read before any account is created
+    let Err(Error::AnchorError(err)) = result.map(|_| ()) else { panic!("should be rejected") };
+    assert_eq!(err.error_code_number, u32::from(ErrorCode::AccountNotInitialized));
+    assert!(matches!(err.error_origin, Some(ErrorOrigin::AccountName(name)) if name == "allowed_mint"));
+    assert_eq!(initiator.lamports(), 1_000_000_000);
+}
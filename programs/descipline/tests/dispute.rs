@@ -0,0 +1,169 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token, token::spl_token};
+use descipline::{
+    error::DesciplineError,
+    instructions::{OpenDispute, OpenDisputeBumps, ReplaceResolution, UpholdResolution},
+    state::{Challenge, Dispute, Receipt, ReceiptStatus, Resolution},
+};
+
+use common::*;
+
+const BOND: u64 = 100;
+const DISPUTE_WINDOW: i64 = 50;
+const DISPUTER: usize = 2;
+
+// 2 winners out of 3 stakers, the staker who lost can dispute until DISPUTE_WINDOW for a bond of BOND.
+struct Disputed {
+    resolved: Resolved,
+    dispute: &'static AccountInfo<'static>,
+    bump: u8,
+}
+
+fn disputed() -> Disputed {
+    let resolved = Resolved::new(3, 2);
+    update::<Challenge>(resolved.challenge, |challenge| {
+        challenge.dispute_window = DISPUTE_WINDOW;
+        challenge.dispute_bond = BOND;
+    });
+    let disputer = resolved.stakers[DISPUTER];
+    **disputer.try_borrow_mut_lamports().unwrap() = BOND;
+    let (address, bump) = dispute_address(resolved.challenge.key, disputer.key);
+    let dispute = Dispute { disputer: Pubkey::default(), bond: 0, bump: 0 };
+    Disputed { dispute: program_account(address, &dispute), resolved, bump }
+}
+
+impl Disputed {
+    fn disputer(&self) -> &'static AccountInfo<'static> {
+        self.resolved.stakers[DISPUTER]
+    }
+
+    fn open(&self) -> Result<()> {
+        let resolved = &self.resolved;
+        let accounts = OpenDispute {
+            disputer: Signer::try_from(signer(self.disputer())).unwrap(),
+            disputer_ata: None,
+            receipt: load(resolved.receipts[DISPUTER]),
+            dispute: load(self.dispute),
+            vault: None,
+            sol_vault: Some(system_account(resolved.sol_vault)),
+            challenge: load(resolved.challenge),
+            resolution: load(resolved.resolution),
+            stake_mint: interface_mint(resolved.stake_mint),
+            associated_token_program: Program::try_from(program(associated_token::ID)).unwrap(),
+            token_program: token_program(spl_token::ID),
+            system_program: system(),
+        };
+        let bumps = OpenDisputeBumps { dispute: self.bump };
+        run(accounts, |accounts| accounts.open_dispute(&bumps))
+    }
+
+    fn uphold(&self) -> Result<()> {
+        let resolved = &self.resolved;
+        let accounts = UpholdResolution {
+            signer: Signer::try_from(resolved.authority).unwrap(),
+            credential_authority: load(resolved.credential_authority),
+            challenge: load(resolved.challenge),
+            resolution: load(resolved.resolution),
+            dispute: load(self.dispute),
+            disputer: system_account(self.disputer()),
+        };
+        run(accounts, |accounts| accounts.uphold_resolution())
+    }
+
+    // The corrected resolution only has the first winner.
+    fn replace(&self) -> Result<()> {
+        let resolved = &self.resolved;
+        let accounts = ReplaceResolution {
+            signer: Signer::try_from(resolved.authority).unwrap(),
+            credential_authority: load(resolved.credential_authority),
+            vault: None,
+            sol_vault: Some(system_account(resolved.sol_vault)),
+            challenge: load(resolved.challenge),
+            resolution: load(resolved.resolution),
+            claimed: load(resolved.claimed),
+            dispute: load(self.dispute),
+            disputer: system_account(self.disputer()),
+            disputer_ata: None,
+            stake_mint: interface_mint(resolved.stake_mint),
+            associated_token_program: Program::try_from(program(associated_token::ID)).unwrap(),
+            token_program: token_program(spl_token::ID),
+            system_program: system(),
+        };
+        run(accounts, |accounts| accounts.replace_resolution([1; 32], 1, b"uri".to_vec(), None, &[]))
+    }
+}
+
+#[test]
+fn open_dispute_escrows_the_bond() {
+    let disputed = disputed();
+    set_now(DISPUTE_WINDOW - 1);
+
+    disputed.open().unwrap();
+
+    let dispute = state::<Dispute>(disputed.dispute);
+    assert_eq!(dispute.disputer, *disputed.disputer().key);
+    assert_eq!(dispute.bond, BOND);
+    assert_eq!(disputed.disputer().lamports(), 0);
+    assert_eq!(disputed.resolved.vault_balance(), 3 * STAKE + BOND);
+    assert_eq!(state::<Resolution>(disputed.resolved.resolution).open_disputes, 1);
+    assert!(state::<Receipt>(disputed.resolved.receipts[DISPUTER]).status == ReceiptStatus::Disputed);
+}
+
+#[test]
+fn open_dispute_after_the_window_is_rejected() {
+    let disputed = disputed();
+    set_now(DISPUTE_WINDOW);
+
+    assert_error(disputed.open(), DesciplineError::DisputeWindowClosed);
+    assert_eq!(disputed.disputer().lamports(), BOND);
+}
+
+#[test]
+fn open_dispute_freezes_claims() {
+    let disputed = disputed();
+    disputed.open().unwrap();
+
+    set_now(200);
+    assert_error(disputed.resolved.claim(0), DesciplineError::DisputeOpen);
+    assert_eq!(disputed.resolved.vault_balance(), 3 * STAKE + BOND);
+}
+
+#[test]
+fn uphold_forfeits_the_bond_to_the_prize_pool() {
+    let disputed = disputed();
+    let dispute_rent = disputed.dispute.lamports();
+    disputed.open().unwrap();
+
+    disputed.uphold().unwrap();
+
+    let resolution = state::<Resolution>(disputed.resolved.resolution);
+    assert_eq!(resolution.open_disputes, 0);
+    assert_eq!(resolution.prize_pool, 3 * STAKE + BOND);
+    assert_eq!(resolution.prize_share, (3 * STAKE + BOND) / 2);
+    assert!(is_closed(disputed.dispute));
+    assert_eq!(disputed.disputer().lamports(), dispute_rent);
+
+    // claims resume with the bond in every share
+    set_now(200);
+    disputed.resolved.claim(0).unwrap();
+    assert_eq!(state::<Resolution>(disputed.resolved.resolution).prize_paid, (3 * STAKE + BOND) / 2);
+}
+
+#[test]
+fn replace_returns_the_bond() {
+    let disputed = disputed();
+    let dispute_rent = disputed.dispute.lamports();
+    disputed.open().unwrap();
+
+    disputed.replace().unwrap();
+
+    let resolution = state::<Resolution>(disputed.resolved.resolution);
+    assert_eq!(resolution.open_disputes, 0);
+    assert_eq!(resolution.winner_count, 1);
+    assert_eq!(resolution.prize_pool, 3 * STAKE);
+    assert!(is_closed(disputed.dispute));
+    assert_eq!(disputed.disputer().lamports(), BOND + dispute_rent);
+    assert_eq!(disputed.resolved.vault_balance(), 3 * STAKE);
+}
@@ -0,0 +1,61 @@
+use anchor_lang::error::ERROR_CODE_OFFSET;
+use descipline::error::DesciplineError;
+
+// Errors of the deployed program, in code order, clients map them by code.
+const DEPLOYED: [DesciplineError; 36] = [
+    DesciplineError::NotAllowedToken,
+    DesciplineError::InvalidCredentialAuthority,
+    DesciplineError::InvalidAttestor,
+    DesciplineError::InvalidCredentialData,
+    DesciplineError::InvalidAuthority,
+    DesciplineError::UnauthorizedSigner,
+    DesciplineError::TooManySigners,
+    DesciplineError::SchemaPaused,
+    DesciplineError::InvalidSchemaHash,
+    DesciplineError::InvalidVerificationKeyHash,
+    DesciplineError::InvalidSchema,
+    DesciplineError::InvalidSchemaData,
+    DesciplineError::NameMismatch,
+    DesciplineError::InvalidLayout,
+    DesciplineError::InvalidAccountData,
+    DesciplineError::InvalidCredential,
+    DesciplineError::InvalidAttestationData,
+    DesciplineError::CredentialMismatch,
+    DesciplineError::ChallengeMismatch,
+    DesciplineError::SchemaMismatch,
+    DesciplineError::InvalidDataLayout,
+    DesciplineError::UnauthorizedSigners,
+    DesciplineError::ChallengeEnded,
+    DesciplineError::ChallengeNotStarted,
+    DesciplineError::InvalidStakeAmount,
+    DesciplineError::InvalidFeePercentage,
+    DesciplineError::ChallengeNotFound,
+    DesciplineError::StakeEnded,
+    DesciplineError::InsufficientToken,
+    DesciplineError::StakeFailed,
+    DesciplineError::ClaimNotStarted,
+    DesciplineError::ClaimFailed,
+    DesciplineError::NotInWhitelist,
+    DesciplineError::ShouldCloseChallenge,
+    DesciplineError::InvalidCloseChallenge,
+    DesciplineError::InvalidDiscriminator,
+];
+
+#[test]
+fn deployed_codes_do_not_move() {
+    for (index, error) in DEPLOYED.into_iter().enumerate() {
+        assert_eq!(
+            u32::from(error),
+            ERROR_CODE_OFFSET + index as u32,
+            "{error:?}"
+        );
+    }
+}
+
+#[test]
+fn new_codes_follow_the_deployed_ones() {
+    assert_eq!(
+        u32::from(DesciplineError::UnsupportedMintExtension),
+        ERROR_CODE_OFFSET + DEPLOYED.len() as u32
+    );
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use anchor_lang::{prelude::*, system_program, Accounts};
+use anchor_spl::{associated_token, token::spl_token};
+use descipline::{
+    constants::ATTESTATION_PROGRAM_ID,
+    error::DesciplineError,
+    instructions::{InvalidateResolution, Refund, Resolve, ResolveBumps},
+    state::{Challenge, ChallengeStatus, Resolution},
+};
+
+use common::*;
+
+// 2 winners out of 3 stakers, resolved by a single attestation.
+struct Attested {
+    resolved: Resolved,
+    attestation: Pubkey,
+}
+
+fn attested() -> Attested {
+    let resolved = Resolved::new(3, 2);
+    let attestation = Pubkey::new_unique();
+    update::<Resolution>(resolved.resolution, |resolution| resolution.attestations = vec![attestation]);
+    set_now(120);
+    Attested { resolved, attestation }
+}
+
+impl Attested {
+    fn invalidate(&self, attestation: &'static AccountInfo<'static>) -> Result<()> {
+        let resolved = &self.resolved;
+        let accounts = InvalidateResolution {
+            signer: Signer::try_from(resolved.authority).unwrap(),
+            credential_authority: load(resolved.credential_authority),
+            challenge: load(resolved.challenge),
+            resolution: load(resolved.resolution),
+            claimed: load(resolved.claimed),
+            attestation: UncheckedAccount::try_from(attestation),
+            attestor: system_account(resolved.attestor),
+        };
+        run(accounts, |accounts| accounts.invalidate_resolution())
+    }
+
+    // Revoking an attestation closes its account.
+    fn revoke(&self) -> Result<()> {
+        self.invalidate(account(self.attestation, system_program::ID, 0, vec![]))
+    }
+}
+
+#[test]
+fn invalidation_locks_the_challenge_again() {
+    let attested = attested();
+    let resolved = &attested.resolved;
+    let attestor_rent = resolved.resolution.lamports() + resolved.claimed.lamports();
+
+    attested.revoke().unwrap();
+
+    assert!(state::<Challenge>(resolved.challenge).status == ChallengeStatus::Locked);
+    assert!(is_closed(resolved.resolution));
+    assert!(is_closed(resolved.claimed));
+    assert_eq!(resolved.attestor.lamports(), attestor_rent);
+    assert_eq!(resolved.vault_balance(), 3 * STAKE);
+}
+
+#[test]
+fn rejects_attestation_not_revoked() {
+    let attested = attested();
+    let attestation = account(attested.attestation, ATTESTATION_PROGRAM_ID, 1, vec![0; 8]);
+
+    assert_error(attested.invalidate(attestation), DesciplineError::AttestationNotRevoked);
+}
+
+#[test]
+fn rejects_invalidation_once_claims_started() {
+    let attested = attested();
+    set_now(200);
+    attested.resolved.claim(0).unwrap();
+
+    assert_error(attested.revoke(), DesciplineError::ClaimStarted);
+}
+
+#[test]
+fn invalidated_challenge_resolves_again_before_resolve_deadline() {
+    let attested = attested();
+    let resolved = &attested.resolved;
+    attested.revoke().unwrap();
+
+    // a new attestation of a single winner, the resolution and claimed bitmap are created anew
+    let challenge = state::<Challenge>(resolved.challenge);
+    let attestor = signer(wallet(1_000_000_000));
+    update::<Challenge>(resolved.challenge, |challenge| challenge.attestors = vec![*attestor.key]);
+    let schema = encode_schema(&challenge.credential, "resolution", &[], &[13, 13, 0, 13], &[]);
+    let mut data = Vec::new();
+    put_vec(&mut data, resolved.challenge.key.as_ref());
+    put_vec(&mut data, &[8; 32]);
+    data.push(1);
+    put_vec(&mut data, b"uri");
+    let attestation = encode_attestation(&challenge.credential, &challenge.schema, &data, attestor.key, 0);
+    let attestation = account(Pubkey::new_unique(), ATTESTATION_PROGRAM_ID, 1, attestation).clone();
+
+    let mut infos = accounts(&[
+        attestor,
+        resolved.challenge,
+        account(challenge.schema, ATTESTATION_PROGRAM_ID, 1, schema),
+        resolved.resolution,
+        resolved.claimed,
+        none(),
+        resolved.sol_vault,
+        resolved.stake_mint,
+        program(associated_token::ID),
+        program(spl_token::ID),
+        program(system_program::ID),
+    ]);
+    let mut bumps = ResolveBumps::default();
+    let accounts = Resolve::try_accounts(
+        &descipline::ID,
+        &mut infos,
+        &1u32.to_le_bytes(),
+        &mut bumps,
+        &mut BTreeSet::new(),
+    )
+    .unwrap();
+    run(accounts, |accounts| accounts.resolve(&[attestation], 1, &bumps)).unwrap();
+
+    let resolution = state::<Resolution>(resolved.resolution);
+    assert!(state::<Challenge>(resolved.challenge).status == ChallengeStatus::Resolved);
+    assert_eq!(resolution.root_hash, [8; 32]);
+    assert_eq!(resolution.winner_count, 1);
+    assert_eq!(resolution.prize_pool, 3 * STAKE);
+}
+
+#[test]
+fn invalidated_challenge_refunds_after_resolve_deadline() {
+    let attested = attested();
+    let resolved = &attested.resolved;
+    let receipt_rent = resolved.receipts[0].lamports();
+    attested.revoke().unwrap();
+
+    set_now(150);
+    let accounts = Refund {
+        challenger: Signer::try_from(signer(resolved.stakers[0])).unwrap(),
+        challenger_ata: None,
+        receipt: load(resolved.receipts[0]),
+        vault: None,
+        sol_vault: Some(system_account(resolved.sol_vault)),
+        challenge: load(resolved.challenge),
+        resolution: UncheckedAccount::try_from(resolved.resolution),
+        initiator: system_account(resolved.initiator),
+        stake_mint: interface_mint(resolved.stake_mint),
+        associated_token_program: Program::try_from(program(associated_token::ID)).unwrap(),
+        token_program: token_program(spl_token::ID),
+        system_program: system(),
+    };
+    run(accounts, |accounts| accounts.refund()).unwrap();
+
+    assert_eq!(resolved.stakers[0].lamports(), STAKE + receipt_rent);
+    assert!(is_closed(resolved.receipts[0]));
+    assert_eq!(state::<Challenge>(resolved.challenge).participant_count, 2);
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use descipline::error::DesciplineError;
+
+use common::*;
+
+#[test]
+fn splits_pool_equally_and_gives_dust_to_last_claimer() {
+    let mut resolution = resolution(3, 1_000);
+    assert_eq!(resolution.prize_share, 333);
+
+    let mut payouts = Vec::new();
+    for _ in 0..3 {
+        let prize = resolution.prize_of(None).unwrap();
+        resolution.record_claim(prize, 0);
+        payouts.push(prize);
+    }
+    assert_eq!(payouts, vec![333, 333, 334]);
+    assert_eq!(resolution.remaining_prize(), 0);
+}
+
+#[test]
+fn payouts_sum_to_pool() {
+    for winner_count in 1..=20u32 {
+        for prize_pool in [0, 1, 7, 999, 1_000_003, u64::MAX / 2] {
+            let mut resolution = resolution(winner_count, prize_pool);
+            let mut paid = 0;
+            while resolution.winner_notclaim_count > 0 {
+                let prize = resolution.prize_of(None).unwrap();
+                resolution.record_claim(prize, 0);
+                paid += prize;
+            }
+            assert_eq!(paid, prize_pool);
+            assert_eq!(resolution.prize_paid, prize_pool);
+        }
+    }
+}
+
+#[test]
+fn forfeited_bond_grows_shares() {
+    let mut resolution = resolution(4, 1_000);
+    let prize_pool = resolution.prize_pool + 200;
+    resolution.set_prize_pool(prize_pool, None).unwrap();
+
+    assert_eq!(resolution.prize_share, 300);
+}
+
+#[test]
+fn leaves_unallocated_stakes_out_of_the_prizes() {
+    let mut resolution = resolution(2, 1_000);
+    resolution.set_prize_pool(1_000, Some(600)).unwrap();
+    assert_eq!(resolution.remaining_prize(), 600);
+
+    // one amount leaf of 400 claimed, only the other allocated 200 is left to sweep
+    let prize = resolution.prize_of(Some(400)).unwrap();
+    resolution.record_claim(prize, 0);
+    assert_eq!(resolution.remaining_prize(), 200);
+    assert_eq!(resolution.sweep_share(400), 200);
+    assert_error(resolution.prize_of(Some(201)), DesciplineError::PrizePoolExceeded);
+
+    assert_error(resolution.set_prize_pool(1_000, Some(1_001)), DesciplineError::PrizePoolExceeded);
+}
+
+#[test]
+fn zero_winners_have_no_share() {
+    let resolution = resolution(0, 1_000);
+    assert_eq!(resolution.prize_share, 0);
+    assert_eq!(resolution.remaining_prize(), 1_000);
+}
+
+#[test]
+fn sweeps_unclaimed_prizes_pro_rata_to_claimers() {
+    let mut resolution = resolution(4, 1_000);
+    // amount leaves: two of four winners claimed 100 and 300, 600 is left unclaimed
+    for amount in [100, 300] {
+        let prize = resolution.prize_of(Some(amount)).unwrap();
+        resolution.record_claim(prize, 0);
+    }
+
+    assert_eq!(resolution.claimer_count(), 2);
+    assert_eq!(resolution.sweep_share(100), 150);
+    assert_eq!(resolution.sweep_share(300), 450);
+}
+
+#[test]
+fn sweep_shares_never_exceed_unclaimed_prizes() {
+    let mut resolution = resolution(3, 1_000);
+    resolution.record_claim(333, 0);
+    assert_eq!(resolution.sweep_share(333), 667);
+
+    let mut resolution = self::resolution(3, 1_000);
+    for _ in 0..3 {
+        resolution.record_claim(111, 0);
+    }
+    let swept: u64 = (0..3).map(|_| resolution.sweep_share(111)).sum();
+    assert!(swept <= resolution.remaining_prize());
+
+    // nothing was paid, nobody can be owed a share
+    let resolution = self::resolution(3, 1_000);
+    assert_eq!(resolution.sweep_share(0), 0);
+}
+
+#[test]
+fn prices_each_claim_from_the_snapshot() {
+    let mut resolution = resolution(3, 1_000);
+    assert_eq!(resolution.prize_of(None).unwrap(), 333);
+
+    resolution.record_claim(333, 3);
+    resolution.record_claim(333, 3);
+    assert!(resolution.is_last_claim());
+    assert_eq!(resolution.prize_of(None).unwrap(), 334);
+    assert_eq!(resolution.fee_accrued, 6);
+
+    // amount leaves cannot take more than what is left
+    assert_eq!(resolution.prize_of(Some(334)).unwrap(), 334);
+    assert_error(resolution.prize_of(Some(335)), DesciplineError::PrizePoolExceeded);
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_error::ProgramError;
+use descipline::{
+    error::DesciplineError,
+    state::{Receipt, ReceiptStatus},
+};
+
+const STAKER: Pubkey = Pubkey::new_from_array([7; 32]);
+const CHALLENGE_ID: u64 = 3;
+
+// The receipt as `init_if_needed` hands it to `stake` the first time.
+fn fresh_receipt() -> Receipt {
+    Receipt {
+        staker: Pubkey::default(),
+        challenge_id: 0,
+        amount: 0,
+        staked_at: 0,
+        status: ReceiptStatus::Staked,
+        prize: 0,
+        bump: 0,
+    }
+}
+
+// Replays `stake` in the challenge with CHALLENGE_ID, which sets the bump after recording.
+fn stake(receipt: &mut Receipt, received: u64, now: i64, allow_top_up: bool) -> Result<()> {
+    receipt.record_stake(STAKER, CHALLENGE_ID, received, now, allow_top_up)?;
+    receipt.bump = 254;
+    Ok(())
+}
+
+#[test]
+fn records_first_stake() {
+    let mut receipt = fresh_receipt();
+    assert!(receipt.is_new(CHALLENGE_ID));
+
+    stake(&mut receipt, 990, 1_000, false).unwrap();
+    assert!(!receipt.is_new(CHALLENGE_ID));
+    assert_eq!(receipt.staker, STAKER);
+    assert_eq!(receipt.challenge_id, CHALLENGE_ID);
+    assert_eq!(receipt.amount, 990);
+    assert_eq!(receipt.staked_at, 1_000);
+    assert!(receipt.status == ReceiptStatus::Staked);
+}
+
+#[test]
+fn rejects_repeat_stake_without_top_ups() {
+    let mut receipt = fresh_receipt();
+    stake(&mut receipt, 1_000, 1_000, false).unwrap();
+
+    let err = stake(&mut receipt, 1_000, 2_000, false).unwrap_err();
+    assert_eq!(ProgramError::from(err), ProgramError::Custom(DesciplineError::AlreadyStaked.into()));
+    assert_eq!(receipt.amount, 1_000);
+}
+
+#[test]
+fn accumulates_top_ups() {
+    let mut receipt = fresh_receipt();
+    stake(&mut receipt, 1_000, 1_000, true).unwrap();
+    stake(&mut receipt, 990, 2_000, true).unwrap();
+
+    assert_eq!(receipt.amount, 1_990);
+    assert_eq!(receipt.staked_at, 1_000);
+}
+
+#[test]
+fn records_claimed_prize() {
+    let mut receipt = fresh_receipt();
+    stake(&mut receipt, 1_000, 1_000, false).unwrap();
+    receipt.record_claim(2_500).unwrap();
+
+    assert!(receipt.status == ReceiptStatus::Claimed);
+    assert_eq!(receipt.prize, 2_500);
+    assert_eq!(receipt.amount, 1_000);
+}
+
+#[test]
+fn rejects_second_claim() {
+    let mut receipt = fresh_receipt();
+    stake(&mut receipt, 1_000, 1_000, false).unwrap();
+    receipt.record_claim(2_500).unwrap();
+
+    // another amount leaf of the same winner
+    let err = receipt.record_claim(500).unwrap_err();
+    assert_eq!(ProgramError::from(err), ProgramError::Custom(DesciplineError::AlreadyClaimed.into()));
+    assert_eq!(receipt.prize, 2_500);
+}
+
+#[test]
+fn restarts_receipt_left_by_earlier_challenge() {
+    let mut receipt = fresh_receipt();
+    stake(&mut receipt, 1_000, 1_000, false).unwrap();
+    receipt.record_claim(2_500).unwrap();
+
+    // the challenge was closed and recreated under the same name
+    assert!(receipt.is_new(CHALLENGE_ID + 1));
+    receipt.record_stake(STAKER, CHALLENGE_ID + 1, 990, 5_000, false).unwrap();
+
+    assert_eq!(receipt.challenge_id, CHALLENGE_ID + 1);
+    assert_eq!(receipt.amount, 990);
+    assert_eq!(receipt.staked_at, 5_000);
+    assert_eq!(receipt.prize, 0);
+    assert!(receipt.status == ReceiptStatus::Staked);
+}
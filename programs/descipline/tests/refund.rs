@@ -0,0 +1,124 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use anchor_lang::{prelude::*, system_program, Accounts};
+use anchor_spl::{associated_token, token::spl_token::{self, native_mint}};
+use descipline::{
+    error::DesciplineError,
+    instructions::{Refund, RefundBumps},
+    state::{ChallengeStatus, Receipt, ReceiptStatus},
+};
+
+use common::*;
+
+const STAKE: u64 = 1_000;
+const CHALLENGE_ID: u64 = 7;
+
+struct Staked {
+    status: ChallengeStatus,
+    participants: u32,
+    // what the vault holds besides the rent reserve
+    vault: u64,
+    receipt_id: u64,
+}
+
+impl Staked {
+    fn new(status: ChallengeStatus, participants: u32) -> Self {
+        Self { status, participants, vault: STAKE * participants as u64, receipt_id: CHALLENGE_ID }
+    }
+}
+
+// A native SOL challenge past its stake window, the refunding challenger staked STAKE.
+fn refund(staked: Staked) -> Result<Refund<'static>> {
+    setup();
+    let challenger = signer(wallet(0));
+    let initiator = wallet(0);
+    let mut state = challenge(*initiator.key, native_mint::ID);
+    state.id = CHALLENGE_ID;
+    state.status = staked.status;
+    state.participant_count = staked.participants;
+    let (key, _) = challenge_address(&state.initiator, &state.name);
+    let (sol_vault, _) = sol_vault_address(&key);
+    let (receipt_key, bump) = receipt_address(&key, challenger.key);
+    let (resolution, _) = Pubkey::find_program_address(&[b"resolution", key.as_ref()], &descipline::ID);
+    let receipt = Receipt {
+        staker: *challenger.key,
+        challenge_id: staked.receipt_id,
+        amount: STAKE,
+        staked_at: 0,
+        status: ReceiptStatus::Staked,
+        prize: 0,
+        bump,
+    };
+
+    let mut infos = accounts(&[
+        challenger,
+        none(),
+        program_account(receipt_key, &receipt),
+        none(),
+        account(sol_vault, system_program::ID, rent_exempt(0) + staked.vault, vec![]),
+        program_account(key, &state),
+        account(resolution, system_program::ID, 0, vec![]),
+        initiator,
+        mint(spl_token::ID, native_mint::ID, Pubkey::new_unique(), None),
+        program(associated_token::ID),
+        program(spl_token::ID),
+        program(system_program::ID),
+    ]);
+    Refund::try_accounts(&descipline::ID, &mut infos, &[], &mut RefundBumps::default(), &mut BTreeSet::new())
+}
+
+#[test]
+fn refunds_stake_after_resolve_deadline() {
+    let mut accounts = refund(Staked::new(ChallengeStatus::Open, 3)).unwrap();
+    set_now(150);
+
+    accounts.refund().unwrap();
+
+    assert_eq!(accounts.challenger.lamports(), STAKE);
+    assert_eq!(accounts.challenge.participant_count, 2);
+    assert_eq!(accounts.challenge.status, ChallengeStatus::Locked);
+    assert_eq!(accounts.sol_vault.as_ref().unwrap().lamports(), rent_exempt(0) + 2 * STAKE);
+}
+
+#[test]
+fn rejects_refund_before_resolve_deadline() {
+    let mut accounts = refund(Staked::new(ChallengeStatus::Open, 3)).unwrap();
+    set_now(149);
+
+    assert_error(accounts.refund(), DesciplineError::RefundNotStarted);
+}
+
+#[test]
+fn cancelled_challenge_refunds_before_resolve_deadline() {
+    let mut accounts = refund(Staked::new(ChallengeStatus::Cancelled, 2)).unwrap();
+    set_now(50);
+
+    accounts.refund().unwrap();
+
+    assert_eq!(accounts.challenger.lamports(), STAKE);
+    assert_eq!(accounts.challenge.participant_count, 1);
+    assert_eq!(accounts.challenge.status, ChallengeStatus::Cancelled);
+}
+
+#[test]
+fn last_refund_sweeps_vault_and_closes_challenge() {
+    // a donation sent to the vault after the other stakers were refunded
+    let mut accounts = refund(Staked { vault: STAKE + 7, ..Staked::new(ChallengeStatus::Cancelled, 1) }).unwrap();
+    let challenge_rent = accounts.challenge.to_account_info().lamports();
+
+    accounts.refund().unwrap();
+
+    assert_eq!(accounts.challenger.lamports(), STAKE + 7);
+    assert_eq!(accounts.sol_vault.as_ref().unwrap().lamports(), 0);
+    assert_eq!(accounts.challenge.to_account_info().lamports(), 0);
+    assert_eq!(accounts.initiator.lamports(), rent_exempt(0) + challenge_rent);
+}
+
+#[test]
+fn rejects_receipt_of_earlier_challenge() {
+    let result = refund(Staked { receipt_id: CHALLENGE_ID - 1, ..Staked::new(ChallengeStatus::Cancelled, 2) });
+
+    assert_error(result.map(|_| ()), DesciplineError::InvalidReceipt);
+}
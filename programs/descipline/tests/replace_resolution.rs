@@ -0,0 +1,142 @@
+mod common;
+
+use anchor_lang::{prelude::*, system_program};
+use anchor_lang::solana_program::program_error::ProgramError;
+use anchor_spl::{associated_token, token::spl_token::{self, native_mint}};
+use descipline::{
+    constants::MAX_URI_LEN,
+    error::DesciplineError,
+    instructions::ReplaceResolution,
+    state::{ChallengeStatus, ClaimedBitmap, CredentialAuthority, Dispute},
+};
+
+use common::*;
+
+const BOND: u64 = 100;
+const PRIZE_POOL: u64 = 3_000;
+
+struct Disputes {
+    accounts: ReplaceResolution<'static>,
+    // the disputes besides `accounts.dispute`, as [dispute, disputer] pairs
+    others: Vec<AccountInfo<'static>>,
+}
+
+// A native SOL challenge resolved with 2 winners out of 3 stakers, disputed `open` times.
+fn disputes(open: u32) -> Disputes {
+    setup();
+    let mut state = challenge(Pubkey::new_unique(), native_mint::ID);
+    state.participant_count = 3;
+    state.status = ChallengeStatus::Resolved;
+    let (key, _) = challenge_address(&state.initiator, &state.name);
+    let (sol_vault, _) = sol_vault_address(&key);
+    let sol_vault = account(
+        sol_vault,
+        system_program::ID,
+        rent_exempt(0) + PRIZE_POOL + BOND * open as u64,
+        vec![],
+    );
+
+    let mut resolved = resolution(2, PRIZE_POOL);
+    resolved.open_disputes = open;
+
+    let mut disputers = (0..open).map(|_| {
+        let disputer = wallet(0);
+        let (address, bump) = dispute_address(&key, disputer.key);
+        let dispute = Dispute { disputer: *disputer.key, bond: BOND, bump };
+        (program_account(address, &dispute), disputer)
+    });
+    let (dispute, disputer) = disputers.next().unwrap();
+    let others = disputers.flat_map(|(dispute, disputer)| [dispute.clone(), disputer.clone()]).collect();
+
+    let signer_key = Pubkey::new_unique();
+    let authority = CredentialAuthority {
+        signer: signer_key,
+        treasury: Pubkey::new_unique(),
+        protocol_fee: 0,
+        challenge_count: 1,
+        bump: 255,
+    };
+    let claimed = ClaimedBitmap { bits: vec![0], bump: 255 };
+
+    let accounts = ReplaceResolution {
+        signer: Signer::try_from(signer(account(signer_key, system_program::ID, 0, vec![]))).unwrap(),
+        credential_authority: load(program_account(Pubkey::new_unique(), &authority)),
+        vault: None,
+        sol_vault: Some(system_account(sol_vault)),
+        challenge: load(program_account(key, &state)),
+        resolution: load(program_account(Pubkey::new_unique(), &resolved)),
+        claimed: load(program_account_with_space(Pubkey::new_unique(), &claimed, ClaimedBitmap::space(2))),
+        dispute: load(dispute),
+        disputer: system_account(disputer),
+        disputer_ata: None,
+        stake_mint: interface_mint(mint(spl_token::ID, native_mint::ID, Pubkey::new_unique(), None)),
+        associated_token_program: Program::try_from(program(associated_token::ID)).unwrap(),
+        token_program: token_program(spl_token::ID),
+        system_program: system(),
+    };
+    Disputes { accounts, others }
+}
+
+fn replace(disputes: &mut Disputes, others: &[AccountInfo<'static>]) -> Result<()> {
+    disputes.accounts.replace_resolution([1; 32], 1, b"uri".to_vec(), None, others)
+}
+
+#[test]
+fn replace_refunds_every_open_dispute() {
+    let mut disputes = disputes(3);
+    let others = disputes.others.clone();
+    let dispute_rent = others[0].lamports();
+
+    replace(&mut disputes, &others).unwrap();
+
+    assert_eq!(disputes.accounts.resolution.open_disputes, 0);
+    assert_eq!(disputes.accounts.disputer.lamports(), BOND);
+    for pair in others.chunks(2) {
+        assert_eq!(pair[0].lamports(), 0);
+        assert_eq!(*pair[0].owner, system_program::ID);
+        assert_eq!(pair[1].lamports(), BOND + dispute_rent);
+    }
+    assert_eq!(disputes.accounts.sol_vault.as_ref().unwrap().lamports(), rent_exempt(0) + PRIZE_POOL);
+    assert_eq!(disputes.accounts.resolution.winner_count, 1);
+    assert_eq!(disputes.accounts.resolution.prize_share, PRIZE_POOL);
+}
+
+#[test]
+fn replace_rejects_disputes_left_open() {
+    let mut disputes = disputes(3);
+    let others = disputes.others[..2].to_vec();
+
+    let err = replace(&mut disputes, &others).expect_err("a dispute is left open");
+
+    assert_eq!(ProgramError::from(err), ProgramError::Custom(DesciplineError::DisputesNotSettled.into()));
+}
+
+#[test]
+fn replace_rejects_settling_a_dispute_twice() {
+    let mut disputes = disputes(2);
+    let dispute = disputes.accounts.dispute.to_account_info();
+    let disputer = disputes.accounts.disputer.to_account_info();
+
+    let err = replace(&mut disputes, &[dispute, disputer]).expect_err("the dispute is settled already");
+
+    assert_eq!(ProgramError::from(err), ProgramError::Custom(DesciplineError::InvalidDispute.into()));
+}
+
+#[test]
+fn replace_restarts_the_dispute_window() {
+    let mut disputes = disputes(1);
+    set_now(500);
+
+    replace(&mut disputes, &[]).unwrap();
+
+    assert_eq!(disputes.accounts.resolution.resolved_at, 500);
+}
+
+#[test]
+fn replace_rejects_long_winner_list_uri() {
+    let mut disputes = disputes(1);
+
+    let result = disputes.accounts.replace_resolution([1; 32], 1, vec![b'a'; MAX_URI_LEN + 1], None, &[]);
+
+    assert_error(result, DesciplineError::InvalidWinnerListUri);
+}
@@ -0,0 +1,149 @@
+mod common;
+
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{associated_token, token::spl_token::{self, native_mint}};
+use descipline::{
+    constants::{ATTESTATION_PROGRAM_ID, MAX_ATTESTORS, MAX_URI_LEN},
+    error::DesciplineError,
+    instructions::{Resolve, ResolveBumps},
+    state::{ChallengeStatus, ClaimedBitmap},
+};
+
+use common::*;
+
+const PRIZE_POOL: u64 = 3_000;
+const WINNER_COUNT: u32 = 2;
+// challenge, root_hash, winner_count, winner_list_uri
+const LAYOUT: [u8; 4] = [13, 13, 0, 13];
+
+// A challenge of `attestors` attestors needing `threshold` of them, locked and before its resolve deadline.
+struct Resolution {
+    accounts: Resolve<'static>,
+    attestors: Vec<Pubkey>,
+}
+
+fn resolution(attestors: usize, threshold: u8) -> Resolution {
+    setup();
+    set_now(120);
+    let attestors: Vec<Pubkey> = (0..attestors).map(|_| Pubkey::new_unique()).collect();
+    let mut state = challenge(Pubkey::new_unique(), native_mint::ID);
+    state.attestors = attestors.clone();
+    state.threshold = threshold;
+    state.participant_count = 3;
+    let (key, _) = challenge_address(&state.initiator, &state.name);
+    let (sol_vault, _) = sol_vault_address(&key);
+    let schema = encode_schema(&state.credential, "resolution", &[], &LAYOUT, &[]);
+    let schema = account(state.schema, ATTESTATION_PROGRAM_ID, 1, schema);
+
+    let accounts = Resolve {
+        attestor: Signer::try_from(signer(wallet(0))).unwrap(),
+        challenge: load(program_account(key, &state)),
+        schema: UncheckedAccount::try_from(schema),
+        resolution: load(program_account(Pubkey::new_unique(), &common::resolution(0, 0))),
+        claimed: load(program_account_with_space(
+            Pubkey::new_unique(),
+            &ClaimedBitmap { bits: vec![], bump: 0 },
+            ClaimedBitmap::space(WINNER_COUNT),
+        )),
+        vault: None,
+        sol_vault: Some(system_account(account(sol_vault, system_program::ID, rent_exempt(0) + PRIZE_POOL, vec![]))),
+        stake_mint: interface_mint(mint(spl_token::ID, native_mint::ID, Pubkey::new_unique(), None)),
+        associated_token_program: Program::try_from(program(associated_token::ID)).unwrap(),
+        token_program: token_program(spl_token::ID),
+        system_program: system(),
+    };
+    Resolution { accounts, attestors }
+}
+
+fn data(challenge: &Pubkey, root_hash: [u8; 32], uri: &[u8]) -> Vec<u8> {
+    let mut data = Vec::new();
+    put_vec(&mut data, challenge.as_ref());
+    put_vec(&mut data, &root_hash);
+    data.push(WINNER_COUNT as u8);
+    put_vec(&mut data, uri);
+    data
+}
+
+impl Resolution {
+    fn attestation(&self, signer: &Pubkey, data: &[u8]) -> AccountInfo<'static> {
+        let challenge = &self.accounts.challenge;
+        let attestation = encode_attestation(&challenge.credential, &challenge.schema, data, signer, 0);
+        account(Pubkey::new_unique(), ATTESTATION_PROGRAM_ID, 1, attestation).clone()
+    }
+
+    // One attestation of the same resolution by each of `signers`.
+    fn attestations(&self, signers: &[Pubkey]) -> Vec<AccountInfo<'static>> {
+        let data = data(&self.accounts.challenge.key(), [7; 32], b"uri");
+        signers.iter().map(|signer| self.attestation(signer, &data)).collect()
+    }
+
+    fn resolve(&mut self, attestations: &[AccountInfo<'static>]) -> Result<()> {
+        let bumps = ResolveBumps { resolution: 255, claimed: 254 };
+        self.accounts.resolve(attestations, WINNER_COUNT, &bumps)
+    }
+}
+
+#[test]
+fn resolves_with_every_number_of_attestors() {
+    for attestors in 1..=MAX_ATTESTORS {
+        let mut resolution = resolution(attestors, attestors as u8);
+        let attestations = resolution.attestations(&resolution.attestors);
+
+        resolution.resolve(&attestations).unwrap();
+
+        let resolved = &resolution.accounts.resolution;
+        assert_eq!(resolution.accounts.challenge.status, ChallengeStatus::Resolved);
+        assert_eq!(resolved.attestations, attestations.iter().map(|account| *account.key).collect::<Vec<_>>());
+        assert_eq!(resolved.root_hash, [7; 32]);
+        assert_eq!(resolved.winner_count, WINNER_COUNT);
+        assert_eq!(resolved.prize_pool, PRIZE_POOL);
+        assert_eq!(resolved.resolved_at, 120);
+    }
+}
+
+#[test]
+fn rejects_fewer_attestations_than_threshold() {
+    for attestors in 1..=MAX_ATTESTORS {
+        let mut resolution = resolution(attestors, attestors as u8);
+        let attestations = resolution.attestations(&resolution.attestors[1..]);
+
+        assert_error(resolution.resolve(&attestations), DesciplineError::ThresholdNotMet);
+    }
+}
+
+#[test]
+fn rejects_two_attestations_of_one_attestor() {
+    let mut resolution = resolution(MAX_ATTESTORS, 2);
+    let attestor = resolution.attestors[0];
+    let attestations = resolution.attestations(&[attestor, attestor]);
+
+    assert_error(resolution.resolve(&attestations), DesciplineError::DuplicateAttestation);
+}
+
+#[test]
+fn rejects_attestations_of_different_resolutions() {
+    let mut resolution = resolution(MAX_ATTESTORS, 2);
+    let mut attestations = resolution.attestations(&resolution.attestors[..1]);
+    let other = data(&resolution.accounts.challenge.key(), [8; 32], b"uri");
+    attestations.push(resolution.attestation(&resolution.attestors[1], &other));
+
+    assert_error(resolution.resolve(&attestations), DesciplineError::ResolutionMismatch);
+}
+
+#[test]
+fn rejects_signer_outside_the_credential() {
+    let mut resolution = resolution(2, 1);
+    let attestations = resolution.attestations(&[Pubkey::new_unique()]);
+
+    assert_error(resolution.resolve(&attestations), DesciplineError::UnauthorizedSigners);
+}
+
+#[test]
+fn rejects_long_winner_list_uri() {
+    let mut resolution = resolution(1, 1);
+    let data = data(&resolution.accounts.challenge.key(), [7; 32], &[b'a'; MAX_URI_LEN]);
+    let attestations = [resolution.attestation(&resolution.attestors[0], &data)];
+
+    // the stored uri keeps its length prefix
+    assert_error(resolution.resolve(&attestations), DesciplineError::InvalidWinnerListUri);
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use descipline::{
+    constants::MAX_FEE,
+    error::DesciplineError,
+    instructions::SetProtocolFee,
+    state::CredentialAuthority,
+};
+
+use common::*;
+
+fn authority(protocol_fee: u16) -> CredentialAuthority {
+    CredentialAuthority {
+        signer: Pubkey::new_unique(),
+        treasury: Pubkey::new_unique(),
+        protocol_fee,
+        challenge_count: 0,
+        bump: 255,
+    }
+}
+
+fn accounts() -> SetProtocolFee<'static> {
+    setup();
+    let signer = signer(wallet(0));
+    let authority = CredentialAuthority { signer: *signer.key, ..authority(0) };
+
+    SetProtocolFee {
+        signer: Signer::try_from(signer).unwrap(),
+        credential_authority: load(program_account(Pubkey::new_unique(), &authority)),
+    }
+}
+
+#[test]
+fn protocol_share_is_taken_out_of_the_fee() {
+    assert_eq!(authority(0).protocol_share(1_000), 0);
+    assert_eq!(authority(500).protocol_share(1_000), 50);
+    assert_eq!(authority(MAX_FEE).protocol_share(1_000), 100);
+    // rounds down in favor of the initiator
+    assert_eq!(authority(500).protocol_share(19), 0);
+    assert_eq!(authority(MAX_FEE).protocol_share(u64::MAX), u64::MAX / 10);
+}
+
+#[test]
+fn sets_treasury_and_protocol_fee() {
+    let mut accounts = accounts();
+    let treasury = Pubkey::new_unique();
+
+    accounts.set_protocol_fee(treasury, MAX_FEE).unwrap();
+
+    assert_eq!(accounts.credential_authority.treasury, treasury);
+    assert_eq!(accounts.credential_authority.protocol_fee, MAX_FEE);
+}
+
+#[test]
+fn rejects_protocol_fee_above_max_fee() {
+    let mut accounts = accounts();
+
+    let result = accounts.set_protocol_fee(Pubkey::new_unique(), MAX_FEE + 1);
+
+    assert_error(result, DesciplineError::InvalidFeePercentage);
+    assert_eq!(accounts.credential_authority.protocol_fee, 0);
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token, token::spl_token};
+use descipline::{
+    error::DesciplineError,
+    instructions::SettleNoWinners,
+    state::{Challenge, Resolution, ZeroWinnerPolicy},
+};
+
+use common::*;
+
+const STAKERS: usize = 3;
+
+fn settle(resolved: &Resolved, receipts: &[AccountInfo<'static>]) -> Result<()> {
+    let accounts = SettleNoWinners {
+        signer: Signer::try_from(signer(wallet(0))).unwrap(),
+        vault: None,
+        sol_vault: Some(system_account(resolved.sol_vault)),
+        challenge: load(resolved.challenge),
+        resolution: load(resolved.resolution),
+        claimed: load(resolved.claimed),
+        initiator_fee_ata: None,
+        beneficiary: None,
+        beneficiary_ata: None,
+        initiator: system_account(resolved.initiator),
+        attestor: system_account(resolved.attestor),
+        stake_mint: interface_mint(resolved.stake_mint),
+        associated_token_program: Program::try_from(program(associated_token::ID)).unwrap(),
+        token_program: token_program(spl_token::ID),
+        system_program: system(),
+    };
+    run(accounts, |accounts| accounts.settle_no_winners(receipts))
+}
+
+#[test]
+fn refund_policy_refunds_stakers_in_batches() {
+    let resolved = Resolved::new(STAKERS, 0);
+    let receipt_rent = resolved.receipts[0].lamports();
+
+    settle(&resolved, &resolved.payouts(&[0, 1])).unwrap();
+
+    assert_eq!(state::<Resolution>(resolved.resolution).swept_count, 2);
+    for index in [0, 1] {
+        assert_eq!(resolved.stakers[index].lamports(), STAKE + receipt_rent);
+        assert!(is_closed(resolved.receipts[index]));
+    }
+    assert_eq!(resolved.vault_balance(), STAKE);
+    assert!(!is_closed(resolved.challenge));
+}
+
+#[test]
+fn refund_of_the_last_staker_closes_the_challenge() {
+    let resolved = Resolved::new(STAKERS, 0);
+    let challenge_rent = resolved.challenge.lamports();
+    let attestor_rent = resolved.resolution.lamports() + resolved.claimed.lamports();
+
+    settle(&resolved, &resolved.payouts(&[0, 1])).unwrap();
+    // a receipt refunded by an earlier batch is closed already
+    assert_error(settle(&resolved, &resolved.payouts(&[1])), DesciplineError::InvalidReceipt);
+    settle(&resolved, &resolved.payouts(&[2])).unwrap();
+
+    for account in [resolved.challenge, resolved.resolution, resolved.claimed, resolved.sol_vault] {
+        assert!(is_closed(account));
+    }
+    assert!(resolved.stakers.iter().all(|staker| staker.lamports() > STAKE));
+    assert_eq!(resolved.initiator.lamports(), challenge_rent + rent_exempt(0));
+    assert_eq!(resolved.attestor.lamports(), attestor_rent);
+}
+
+#[test]
+fn initiator_policy_pays_the_vault_to_the_initiator() {
+    let resolved = Resolved::new(STAKERS, 0);
+    let challenge_rent = resolved.challenge.lamports();
+    update::<Challenge>(resolved.challenge, |challenge| {
+        challenge.zero_winner_policy = ZeroWinnerPolicy::Initiator;
+    });
+
+    settle(&resolved, &[]).unwrap();
+
+    assert!(is_closed(resolved.challenge));
+    assert!(is_closed(resolved.sol_vault));
+    assert_eq!(resolved.initiator.lamports(), STAKE * STAKERS as u64 + challenge_rent + rent_exempt(0));
+    // stakers keep their receipts for close_receipt
+    assert!(resolved.stakers.iter().all(|staker| staker.lamports() == 0));
+}
+
+#[test]
+fn rejects_resolution_with_winners() {
+    let resolved = Resolved::new(STAKERS, 1);
+
+    assert_error(settle(&resolved, &resolved.payouts(&[0])), DesciplineError::HasWinners);
+    assert_eq!(resolved.vault_balance(), STAKE * STAKERS as u64);
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token, token::spl_token};
+use descipline::{
+    error::DesciplineError,
+    instructions::Sweep,
+    state::{Challenge, ChallengeStatus, Receipt, Resolution, SweepMode},
+};
+
+use common::*;
+
+fn sweep(resolved: &Resolved, receipts: &[AccountInfo<'static>]) -> Result<()> {
+    let accounts = Sweep {
+        signer: Signer::try_from(signer(wallet(0))).unwrap(),
+        vault: None,
+        sol_vault: Some(system_account(resolved.sol_vault)),
+        challenge: load(resolved.challenge),
+        resolution: load(resolved.resolution),
+        claimed: load(resolved.claimed),
+        credential_authority: load(resolved.credential_authority),
+        initiator_fee_ata: None,
+        treasury_ata: None,
+        treasury: Some(UncheckedAccount::try_from(resolved.treasury)),
+        initiator: system_account(resolved.initiator),
+        attestor: system_account(resolved.attestor),
+        stake_mint: interface_mint(resolved.stake_mint),
+        associated_token_program: Program::try_from(program(associated_token::ID)).unwrap(),
+        token_program: token_program(spl_token::ID),
+        system_program: system(),
+    };
+    run(accounts, |accounts| accounts.sweep(receipts))
+}
+
+fn set_sweep_mode(resolved: &Resolved, sweep_mode: SweepMode) {
+    update::<Challenge>(resolved.challenge, |challenge| challenge.sweep_mode = sweep_mode);
+}
+
+// 2 winners out of 3 stakers, the first one claimed its 1_500 share and the other one never did.
+fn claimed_once() -> Resolved {
+    let resolved = Resolved::new(3, 2);
+    set_now(200);
+    resolved.claim(0).unwrap();
+    resolved
+}
+
+#[test]
+fn sweeps_only_after_claim_end() {
+    let resolved = claimed_once();
+
+    set_now(299);
+    assert_error(sweep(&resolved, &[]), DesciplineError::SweepNotStarted);
+    assert_eq!(state::<Challenge>(resolved.challenge).status, ChallengeStatus::Resolved);
+}
+
+#[test]
+fn open_dispute_blocks_the_sweep() {
+    let resolved = claimed_once();
+    update::<Resolution>(resolved.resolution, |resolution| resolution.open_disputes = 1);
+
+    set_now(300);
+    assert_error(sweep(&resolved, &[]), DesciplineError::DisputeOpen);
+    assert_eq!(resolved.vault_balance(), 1_500);
+}
+
+#[test]
+fn initiator_sweep_closes_the_challenge() {
+    let resolved = claimed_once();
+    let challenge_rent = resolved.challenge.lamports();
+    let attestor_rent = resolved.resolution.lamports() + resolved.claimed.lamports();
+
+    set_now(300);
+    sweep(&resolved, &[]).unwrap();
+
+    for account in [resolved.challenge, resolved.resolution, resolved.claimed, resolved.sol_vault] {
+        assert!(is_closed(account));
+    }
+    assert_eq!(resolved.initiator.lamports(), 1_500 + challenge_rent + rent_exempt(0));
+    assert_eq!(resolved.attestor.lamports(), attestor_rent);
+    assert_eq!(resolved.treasury.lamports(), 0);
+}
+
+#[test]
+fn treasury_sweep_takes_unclaimed_prizes() {
+    let resolved = claimed_once();
+    set_sweep_mode(&resolved, SweepMode::Treasury);
+    let challenge_rent = resolved.challenge.lamports();
+
+    set_now(300);
+    sweep(&resolved, &[]).unwrap();
+
+    assert_eq!(resolved.treasury.lamports(), 1_500);
+    assert_eq!(resolved.initiator.lamports(), challenge_rent + rent_exempt(0));
+}
+
+#[test]
+fn winners_sweep_pays_claimers_pro_rata() {
+    // 3_500 of the 4_000 staked allocated, the third winner never claims its 500
+    let resolved = Resolved::with_amounts(4, &[2_000, 1_000, 500]);
+    set_sweep_mode(&resolved, SweepMode::Winners);
+    let receipt_rent = rent_exempt(8 + Receipt::INIT_SPACE);
+    let challenge_rent = resolved.challenge.lamports();
+    set_now(200);
+    resolved.claim(0).unwrap();
+    resolved.claim(1).unwrap();
+
+    // claimers may be paid over several sweeps, the last one closes the challenge
+    set_now(300);
+    sweep(&resolved, &resolved.payouts(&[0])).unwrap();
+    assert_eq!(resolved.stakers[0].lamports(), 2_000 + 333 + receipt_rent);
+    assert_eq!(state::<Resolution>(resolved.resolution).swept_count, 1);
+    assert!(!is_closed(resolved.challenge));
+
+    sweep(&resolved, &resolved.payouts(&[1])).unwrap();
+    assert_eq!(resolved.stakers[1].lamports(), 1_000 + 166 + receipt_rent);
+    assert!(is_closed(resolved.challenge));
+    // unallocated stakes and the dust stay with the initiator
+    assert_eq!(resolved.initiator.lamports(), 500 + 1 + challenge_rent + rent_exempt(0));
+}
+
+#[test]
+fn winners_sweep_rejects_receipt_without_claim() {
+    let resolved = claimed_once();
+    set_sweep_mode(&resolved, SweepMode::Winners);
+
+    set_now(300);
+    assert_error(sweep(&resolved, &resolved.payouts(&[1])), DesciplineError::InvalidReceipt);
+}
@@ -0,0 +1,132 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::{
+        self,
+        instructions::{self as sysvar_instructions, construct_instructions_data, store_current_index, BorrowedInstruction},
+    },
+};
+use descipline::{
+    error::DesciplineError,
+    instructions::{tip_consent_message, verify_tip_consent},
+};
+
+use common::*;
+
+const TIP: u64 = 25;
+
+// Offsets of a single signature ed25519 instruction with its key, signature and message inline.
+const PUBLIC_KEY_OFFSET: usize = 16;
+const SIGNATURE_OFFSET: usize = PUBLIC_KEY_OFFSET + 32;
+const MESSAGE_OFFSET: usize = SIGNATURE_OFFSET + 64;
+
+// The signature itself is checked by the ed25519 program before the claim runs.
+fn ed25519_instruction(signer: &Pubkey, message: &[u8], instruction_index: u16) -> Vec<u8> {
+    let mut data = vec![1, 0];
+    for offset in [
+        SIGNATURE_OFFSET as u16,
+        instruction_index,
+        PUBLIC_KEY_OFFSET as u16,
+        instruction_index,
+        MESSAGE_OFFSET as u16,
+        message.len() as u16,
+        instruction_index,
+    ] {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(&[7; 64]);
+    data.extend_from_slice(message);
+    data
+}
+
+// The instructions sysvar of a transaction running its last instruction, the claim.
+fn instructions(preceding: &[(Pubkey, Vec<u8>)]) -> &'static AccountInfo<'static> {
+    let mut borrowed: Vec<BorrowedInstruction> = preceding
+        .iter()
+        .map(|(program_id, data)| BorrowedInstruction { program_id, accounts: vec![], data })
+        .collect();
+    borrowed.push(BorrowedInstruction { program_id: &descipline::ID, accounts: vec![], data: &[] });
+
+    let mut data = construct_instructions_data(&borrowed);
+    store_current_index(&mut data, preceding.len() as u16);
+    account(sysvar_instructions::ID, sysvar::ID, 1, data)
+}
+
+struct Claim {
+    challenge: Pubkey,
+    relayer: Pubkey,
+    winner: Pubkey,
+}
+
+impl Claim {
+    fn new() -> Self {
+        Self { challenge: Pubkey::new_unique(), relayer: Pubkey::new_unique(), winner: Pubkey::new_unique() }
+    }
+
+    fn message(&self) -> Vec<u8> {
+        tip_consent_message(&self.challenge, &self.relayer, TIP)
+    }
+
+    fn verify(&self, instructions: &AccountInfo) -> Result<()> {
+        verify_tip_consent(instructions, &self.winner, &self.message())
+    }
+}
+
+#[test]
+fn accepts_winner_signature_over_the_tip() {
+    let claim = Claim::new();
+    let consent = ed25519_instruction(&claim.winner, &claim.message(), u16::MAX);
+
+    claim.verify(instructions(&[(ed25519_program::ID, consent)])).unwrap();
+}
+
+#[test]
+fn rejects_tip_without_consent_instruction() {
+    let claim = Claim::new();
+
+    assert_error(claim.verify(instructions(&[])), DesciplineError::MissingTipConsent);
+    assert_error(
+        claim.verify(instructions(&[(Pubkey::new_unique(), vec![])])),
+        DesciplineError::MissingTipConsent,
+    );
+}
+
+#[test]
+fn rejects_consent_signed_by_someone_else() {
+    let claim = Claim::new();
+    let consent = ed25519_instruction(&claim.relayer, &claim.message(), u16::MAX);
+
+    assert_error(
+        claim.verify(instructions(&[(ed25519_program::ID, consent)])),
+        DesciplineError::InvalidTipConsent,
+    );
+}
+
+#[test]
+fn rejects_consent_to_another_tip_or_relayer() {
+    let claim = Claim::new();
+    let bigger_tip = tip_consent_message(&claim.challenge, &claim.relayer, TIP + 1);
+    let other_relayer = tip_consent_message(&claim.challenge, &Pubkey::new_unique(), TIP);
+
+    for message in [bigger_tip, other_relayer] {
+        let consent = ed25519_instruction(&claim.winner, &message, u16::MAX);
+        assert_error(
+            claim.verify(instructions(&[(ed25519_program::ID, consent)])),
+            DesciplineError::InvalidTipConsent,
+        );
+    }
+}
+
+#[test]
+fn rejects_consent_verifying_data_of_another_instruction() {
+    let claim = Claim::new();
+    let consent = ed25519_instruction(&claim.winner, &claim.message(), 0);
+
+    assert_error(
+        claim.verify(instructions(&[(ed25519_program::ID, consent)])),
+        DesciplineError::InvalidTipConsent,
+    );
+}
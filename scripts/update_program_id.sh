@@ -1,7 +1,7 @@
 #!/bin/bash
 
 # NEW_PROGRAM_ID="descip1111111111111111111111111111111111111"
-NEW_PROGRAM_ID="2s3weLk9GXoqkbw377YbiQpfQX1eQ6DxUsfHi82WRZLF"
+NEW_PROGRAM_ID="59cH5dmYsEMCJh8vBsBtfNJjWrsotZBQoGZELbmqQ1tz"
 PROGRAM_NAME="descipline"
 LIB_RS_PATH="programs/$PROGRAM_NAME/src/lib.rs"
 ANCHOR_TOML_PATH="Anchor.toml"
@@ -4,13 +4,15 @@ import assert from "node:assert";
 import { bs58 } from "@coral-xyz/anchor/dist/cjs/utils/bytes";
 import { 
   getInitAuthorityInstruction,
+  getAddAllowedMintInstruction,
   getCreateChallengeInstruction,
   getStakeInstruction,
   DESCIPLINE_PROGRAM_ADDRESS,
-  TokenAllowed,
+  PayoutMode,
+  SweepMode,
+  zeroWinnerPolicy,
   getResolveInstruction,
-  getClaimInstruction,
-  getClaimAndCloseInstruction
+  getClaimInstruction
 } from "../descipline-lib";
 
 import { 
@@ -38,11 +40,12 @@ import {
   createSolanaClient,
   createTransaction,
   SolanaClient,
-  KeyPairSigner
+  KeyPairSigner,
+  AccountRole
 } from "gill";
 
 import { PublicKey } from "@solana/web3.js";
-import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { createKeyPairSignerFromBytes } from '@solana/signers';
 import { resolve } from 'path';
 
@@ -88,6 +91,15 @@ async function airdrop(address: Address, client: SolanaClient, enabled: boolean
   console.log(`    - Airdrop completed: ${tx}`);
 }
 
+// Waits until the cluster clock, which may lag behind ours by a little, has passed `timestamp`.
+async function sleepUntil(timestamp: bigint, margin = 5n) {
+  const ms = Number(timestamp + margin) * 1000 - Date.now();
+  if (ms > 0) {
+    console.log(`    - Waiting ${Math.ceil(ms / 1000)}s`);
+    await new Promise((done) => setTimeout(done, ms));
+  }
+}
+
 async function sendAndConfirmInstructions(
   client: SolanaClient,
   payer: TransactionSigner,
@@ -120,6 +132,8 @@ const SCHEMA_PDA = new PublicKey("6qbJyzaoy7CBgr75gyiLRvPWQ5MTXgxFwNofELXpWgGA")
 const USDC_MINT = new PublicKey("4NQMuSBhVrqTh8FMv5AbHvADVwHSnxrHNERPdAFu5B8p");
 const PROGRAM_ID = new PublicKey(DESCIPLINE_PROGRAM_ADDRESS);
 const CHALLENGE_NAME = "DESCIP-TEST-CHALLENGE5";
+// long enough for the three stakes to land
+const STAKE_WINDOW_SECONDS = 30n;
 
 const CONFIG = {
   CLUSTER_OR_RPC: 'devnet',
@@ -146,12 +160,10 @@ async function main() {
   // const attestation = await fetchAttestation(client.rpc, ATTESTATION_PDA.toString() as Address);
   // console.log("attestation is :", attestation);
   const stakeMint = USDC_MINT;
+  const tokenProgram = TOKEN_PROGRAM_ID.toString() as Address;
   
   const stakeAmount = 50n * 10n ** 6n;
   const fee = 0;
-  const now = BigInt(Math.floor(Date.now() / 1000));
-  const stakeEndAt = now + 15n * 3600n;
-  const claimStartFrom = stakeEndAt + 1n;
 
   
   const { payer, authority, attestor, initiator, winner1, winner2, loser } = await setupWallets(client);
@@ -168,6 +180,11 @@ async function main() {
     PROGRAM_ID
   )[0].toString() as Address;
 
+  let allowedMint = PublicKey.findProgramAddressSync(
+    [Buffer.from("allowed_mint"), USDC_MINT.toBytes()],
+    PROGRAM_ID
+  )[0].toString() as Address;
+
   let challenge = PublicKey.findProgramAddressSync(
     [Buffer.from("challenge"), bs58.decode(initiator.address), Buffer.from(CHALLENGE_NAME)],
     PROGRAM_ID
@@ -180,6 +197,11 @@ async function main() {
     PROGRAM_ID
   )[0].toString() as Address;
 
+  let claimed = PublicKey.findProgramAddressSync(
+    [Buffer.from("claimed"), bs58.decode(challenge)],
+    PROGRAM_ID
+  )[0].toString() as Address;
+
   let receipt_winner1 = PublicKey.findProgramAddressSync(
     [Buffer.from("receipt"), bs58.decode(challenge), bs58.decode(winner1.address)],
     PROGRAM_ID
@@ -231,7 +253,27 @@ async function main() {
   // let credential_pda = await fetchCredential(client.rpc, CREDENTIAL_PDA.toString() as Address);
   // console.log("sas credential data", credential_pda);
 
-  console.log("\n1. Create Challenge...");
+  console.log("\n1. Allow Stake Mint...");
+  const addAllowedMintInstruction = getAddAllowedMintInstruction({
+    signer: authority,
+    credentialAuthority,
+    allowedMint,
+    stakeMint: stakeMint.toString() as Address,
+    minStake: 1n * 10n ** 6n,
+    maxStake: 1_000n * 10n ** 6n
+  });
+
+  await sendAndConfirmInstructions(client, authority, [addAllowedMintInstruction], "Stake mint allowed");
+  console.log(`    - allowed_mint PDA: ${allowedMint}`);
+
+  // the script waits for each window to pass, so they are kept short
+  const now = BigInt(Math.floor(Date.now() / 1000));
+  const stakeEndAt = now + STAKE_WINDOW_SECONDS;
+  const claimStartFrom = stakeEndAt + 1n;
+  const resolveDeadline = stakeEndAt + 3600n;
+  const claimEndAt = resolveDeadline + 7n * 24n * 3600n;
+
+  console.log("\n2. Create Challenge...");
   const createChallengeInstruction = getCreateChallengeInstruction({
     initiator,
     vault,
@@ -239,26 +281,40 @@ async function main() {
     schema: SCHEMA_PDA.toString() as Address,
     credential: CREDENTIAL_PDA.toString() as Address,
     credentialAuthority,
+    allowedMint,
     stakeMint: stakeMint.toString() as Address,
+    tokenProgram,
     name: CHALLENGE_NAME,
-    tokenAllowed: TokenAllowed.USDC,
     stakeAmount,
+    allowTopUp: false,
+    payoutMode: PayoutMode.Equal,
     fee,
     stakeEndAt,
-    claimStartFrom
+    claimStartFrom,
+    claimEndAt,
+    sweepMode: SweepMode.Initiator,
+    zeroWinnerPolicy: zeroWinnerPolicy('Refund'),
+    resolveDeadline,
+    disputeWindow: 0,
+    disputeBond: 0,
+    maxRelayerTip: 0,
+    threshold: 1,
+    // positions of the resolution values in CONFIG.SCHEMA_FIELDS
+    resolutionFields: { challenge: 0, rootHash: 1, winnerCount: 2, winnerListUri: 3, prizeAllocated: null }
   });
 
   await sendAndConfirmInstructions(client, initiator, [createChallengeInstruction], "Challenge created");
   console.log(`    - Challenge PDA: ${challenge}`);
 
-  console.log("\n2. Stake...");
+  console.log("\n3. Stake...");
   const stakeInstruction1 = getStakeInstruction({
     challenger: winner1,
     stakeMint: stakeMint.toString() as Address,
     challenge,
     receipt: receipt_winner1.toString() as Address,
     vault,
-    challengerAta: winner1Ata
+    challengerAta: winner1Ata,
+    tokenProgram
   });
 
   await sendAndConfirmInstructions(client, winner1, [stakeInstruction1], "Winner1 staked");
@@ -269,7 +325,8 @@ async function main() {
     challenge,
     receipt: receipt_winner2.toString() as Address,
     vault,
-    challengerAta: winner2Ata
+    challengerAta: winner2Ata,
+    tokenProgram
   });
 
   await sendAndConfirmInstructions(client, winner2, [stakeInstruction2], "Winner2 staked");
@@ -280,13 +337,14 @@ async function main() {
     challenge,
     receipt: receipt_loser.toString() as Address,
     vault,
-    challengerAta: loserAta
+    challengerAta: loserAta,
+    tokenProgram
   });
 
   await sendAndConfirmInstructions(client, loser, [stakeInstruction3], "Loser staked");
 
   // create merkle tree proof ans store data
-  console.log("\n3. Creating Attestation...");
+  console.log("\n4. Creating Attestation...");
   const nonce = (await generateKeyPairSigner()).address
   const [attestationPda] = await deriveAttestationPda({
       credential: CREDENTIAL_PDA.toString() as Address,
@@ -313,50 +371,74 @@ async function main() {
 
   const attestation = await fetchAttestation(client.rpc, attestationPda);
 
-  console.log("\n4. Resolve...");
+  console.log("\n5. Resolve...");
+  await sleepUntil(stakeEndAt);
   const resolveInstruction = getResolveInstruction({
     attestor,
     challenge,
+    schema: SCHEMA_PDA.toString() as Address,
     resolution,
-    attestation: attestation.address,
+    claimed,
+    vault,
+    stakeMint: stakeMint.toString() as Address,
+    tokenProgram,
+    winnerCount: attestation_data.winner_count,
   });
-
-  await sendAndConfirmInstructions(client, attestor, [resolveInstruction], "Attestation resolved");
-
-  console.log("\n5. Claim...");
+  // attestations are passed as remaining accounts, one per attestor
+  const resolveWithAttestation = {
+    ...resolveInstruction,
+    accounts: [
+      ...resolveInstruction.accounts,
+      { address: attestation.address, role: AccountRole.READONLY },
+    ],
+  };
+
+  await sendAndConfirmInstructions(client, attestor, [resolveWithAttestation], "Attestation resolved");
+
+  console.log("\n6. Claim...");
+  await sleepUntil(claimStartFrom);
   const claimInstruction = getClaimInstruction({
     winner: winner1,
     winnerAta: winner1Ata,
     vault,
     challenge,
     resolution,
+    claimed,
     receipt: receipt_winner1,
+    credentialAuthority,
     stakeMint: stakeMint.toString() as Address,
+    tokenProgram,
     proof: Buffer.from(winner1_proof),
-    index: winner1_index
+    index: winner1_index,
+    amount: null
   });
 
   await sendAndConfirmInstructions(client, winner1, [claimInstruction], "winner1 claimed");
   
-  console.log("\n6. Claim and close...");
-  const claimAndCloseInstruction = getClaimAndCloseInstruction({
+  // the last claim closes the challenge, rent goes back to initiator and attestor
+  console.log("\n7. Last claim...");
+  const lastClaimInstruction = getClaimInstruction({
     winner: winner2,
     winnerAta: winner2Ata,
     vault,
     challenge,
     resolution,
+    claimed,
+    receipt: receipt_winner2,
+    credentialAuthority,
     initiator: initiator.address,
     attestor: attestor.address,
-    receipt: receipt_winner2,
     stakeMint: stakeMint.toString() as Address,
+    tokenProgram,
     proof: Buffer.from(winner2_proof),
-    index: winner2_index
+    index: winner2_index,
+    amount: null
   });
 
-  await sendAndConfirmInstructions(client, winner2, [claimAndCloseInstruction], "winner2 claimed");
+  await sendAndConfirmInstructions(client, winner2, [lastClaimInstruction], "winner2 claimed");
   
   // Close Attestation
-  console.log("\n7. Closing Attestation...");
+  console.log("\n8. Closing Attestation...");
 
   const eventAuthority = await deriveEventAuthorityAddress();
   const closeAttestationInstruction = getCloseAttestationInstruction({